@@ -180,7 +180,7 @@ export async function POST(req: NextRequest) {
     // Build settle_epoch instruction
     // Note: The smart contract only requires pool, factory, protocol_authority, and settler
     const settleEpochIx = await program.methods
-      .settleEpoch(bdScore)
+      .settleEpoch(bdScore, false, false, null, null) // multiplicative, cooldown enforced, no q guard
       .accounts({
         pool: poolPda,
         factory: factoryPda,
@@ -369,7 +369,7 @@ export async function POST(
 
     // Build settle_epoch instruction
     const settleEpochIx = await program.methods
-      .settleEpoch(bdScoreMillionths)
+      .settleEpoch(bdScoreMillionths, false, false, null, null) // multiplicative, cooldown enforced, no q guard
       .accounts({
         pool: poolPda,
         factory: factoryPda,
@@ -1,5 +1,10 @@
+//! ICBS bonding curve (F=1, β=0.5) - the only curve used by ContentPool
+//!
+//! The earlier quadratic curve (k_quadratic / reserve_cap / ProtocolConfig) has been removed.
+
 use anchor_lang::prelude::*;
 use super::errors::ContentPoolError;
+use super::math::{isqrt_u128, mul_div_u128};
 use super::state::{TokenSide, Q64};
 
 /// UNIT CONTRACT
@@ -24,34 +29,6 @@ pub const Q96: u128 = 1 << 96;
 /// Example: 100 USDC = 100_000_000 lamports → 100 scaled units
 pub const SUPPLY_SCALE: u64 = 1_000_000;
 
-/// GCD helper for overflow-safe multiplication and division
-#[inline]
-fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
-    while b != 0 {
-        let t = a % b;
-        a = b;
-        b = t;
-    }
-    a
-}
-
-/// Overflow-safe mul_div: computes (a * b) / den with GCD reduction
-/// Reduces b/den first to avoid overflow in a*b
-#[inline]
-fn mul_div_u128(a: u128, b: u128, den: u128) -> Result<u128> {
-    if den == 0 {
-        return err!(ContentPoolError::DivisionByZero);
-    }
-    // Reduce b/den first to avoid overflow in a*b
-    let g = gcd_u128(b, den);
-    let (b_r, den_r) = (b / g, den / g);
-
-    Ok(a.checked_mul(b_r)
-        .ok_or(ContentPoolError::NumericalOverflow)?
-        .checked_div(den_r)
-        .ok_or(ContentPoolError::NumericalOverflow)?)
-}
-
 impl ICBSCurve {
     /// Calculate the cost function C(s_L, s_S)
     ///
@@ -281,14 +258,17 @@ impl ICBSCurve {
 
         // Solve: norm_after = (usdc_in / λ) + norm_before
         // usdc_in / λ = (usdc_in * Q96) / lambda_x96
-        // Use GCD-reduced mul_div to avoid overflow
-        let delta_norm = mul_div_u128(usdc_in as u128, Q96, lambda_x96)?;
-        let norm_after = norm_before
-            .checked_add(delta_norm)
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-
-        // Guard against overflow in squaring: if norm_after > u64::MAX, squaring will overflow u128
-        require!(norm_after <= u64::MAX as u128, ContentPoolError::NumericalOverflow);
+        // 256-bit intermediate, so only the u64 norm bound below limits the buy size
+        //
+        // Guard against overflow in squaring: if norm_after > u64::MAX, squaring will overflow u128.
+        // trade logs the gross amount that fits (max_buy_usdc grossed up, as get_max_buy returns).
+        let norm_after = mul_div_u128(usdc_in as u128, Q96, lambda_x96)
+            .ok()
+            .and_then(|delta_norm| norm_before.checked_add(delta_norm))
+            .filter(|&n| n <= u64::MAX as u128);
+        let Some(norm_after) = norm_after else {
+            return err!(ContentPoolError::TradeTooLargeForCurve);
+        };
 
         // Now: norm_after² = (current_s + Δs)² + s_other²
         // So: (current_s + Δs)² = norm_after² - s_other²
@@ -334,6 +314,26 @@ impl ICBSCurve {
         Ok((result, final_sqrt_price))
     }
 
+    /// Largest usdc_in calculate_buy accepts before the post-trade norm leaves u64
+    ///
+    /// Inverts Δnorm = usdc_in / λ at the u64 headroom, rounding down so the result is
+    /// itself executable. Symmetric in the two virtual supplies.
+    pub fn max_buy_usdc(current_s: u64, s_other: u64, lambda_q96: u128) -> Result<u64> {
+        let sum_sq = (current_s as u128 * current_s as u128)
+            .checked_add(s_other as u128 * s_other as u128)
+            .ok_or(ContentPoolError::NumericalOverflow)?;
+        let headroom = (u64::MAX as u128).saturating_sub(integer_sqrt(sum_sq)?);
+        let max_usdc = mul_div_u128(headroom, lambda_q96, Q96)?;
+        Ok(max_usdc.min(u64::MAX as u128) as u64)
+    }
+
+    /// Maximum display tokens that can be sold without dropping supply below `min_floor`
+    ///
+    /// Keeps the ICBS curve away from zero supply, where the norm and λ degenerate.
+    pub fn max_sellable(current_s: u64, min_floor: u64) -> u64 {
+        current_s.saturating_sub(min_floor)
+    }
+
     /// Calculate USDC received for a sell trade using direct cost function
     /// Uses ΔC = C(s_before) - C(s_after) to get exact USDC out
     /// Operates on VIRTUAL supplies and returns DISPLAY price via sigma scaling
@@ -466,7 +466,7 @@ impl ICBSCurve {
     }
 
     /// Calculate market prediction q from supplies and sqrt prices
-    /// q = r_long / (r_long + r_short)
+    /// q = r_long / (r_long + r_short), in millionths (same precision as settle_epoch)
     pub fn market_prediction(
         s_long: u64,
         s_short: u64,
@@ -481,11 +481,10 @@ impl ICBSCurve {
             return Ok(500_000); // 0.5 in micro-units
         }
 
-        // q = r_long / (r_long + r_short) in basis points
-        let q_bps = ((r_long as u128 * 10000) / ((r_long + r_short) as u128)) as u64;
-
-        // Convert to micro-units (6 decimals)
-        Ok(q_bps * 100)
+        // q = r_long / (r_long + r_short) directly in micro-units (6 decimals);
+        // sum in u128 so two large reserves cannot overflow u64
+        let total = r_long as u128 + r_short as u128;
+        Ok((r_long as u128 * 1_000_000 / total) as u64)
     }
 }
 
@@ -565,27 +564,34 @@ fn mul_div(a: u128, b: u128, c: u128) -> Result<u128> {
     Ok(result)
 }
 
-/// Integer square root using Newton's method
+/// Integer square root (floor), bounded to MAX_SQRT_ITERATIONS Newton steps
 pub fn integer_sqrt(n: u128) -> Result<u128> {
-    if n == 0 {
-        return Ok(0);
-    }
-
-    let mut x = n;
-    let mut y = (x + 1) / 2;
-
-    while y < x {
-        x = y;
-        y = (x + n / x) / 2;
-    }
-
-    Ok(x)
+    isqrt_u128(n)
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::content_pool::state::MIN_POOL_LIQUIDITY;
+
+    #[test]
+    fn test_oversized_buy_reports_executable_max() {
+        // λ = 1e-6 µUSDC per unit of norm: a u64::MAX buy would push the norm far past u64
+        let lambda_q96 = Q96 / 1_000_000;
+        let (s, other) = (1_000_000u64, 1_000_000u64);
+        let buy = |usdc| ICBSCurve::calculate_buy(s, usdc, lambda_q96, other, 1, 1, 2, true, Q64, Q64);
+
+        assert_eq!(buy(u64::MAX).unwrap_err(), ContentPoolError::TradeTooLargeForCurve.into());
+
+        let max = ICBSCurve::max_buy_usdc(s, other, lambda_q96).unwrap();
+        assert!(max > 0 && max < u64::MAX);
+        let (delta, _) = buy(max).unwrap();
+        assert!(delta > 0);
+
+        // Anything meaningfully past the max still gets the specific error
+        assert_eq!(buy(max + 1_000_000).unwrap_err(), ContentPoolError::TradeTooLargeForCurve.into());
+    }
 
 
     #[test]
@@ -781,4 +787,45 @@ mod tests {
         ).unwrap();
         assert!(tokens > 0, "Should mint tokens for minimum trade");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_max_sellable_at_floor_boundary() {
+        // Well above the floor: everything but the floor is sellable
+        assert_eq!(ICBSCurve::max_sellable(10_000, MIN_POOL_LIQUIDITY), 9_000);
+        // One token above the floor: exactly one token sellable
+        assert_eq!(ICBSCurve::max_sellable(MIN_POOL_LIQUIDITY + 1, MIN_POOL_LIQUIDITY), 1);
+        // At or below the floor: nothing sellable
+        assert_eq!(ICBSCurve::max_sellable(MIN_POOL_LIQUIDITY, MIN_POOL_LIQUIDITY), 0);
+        assert_eq!(ICBSCurve::max_sellable(MIN_POOL_LIQUIDITY - 1, MIN_POOL_LIQUIDITY), 0);
+    }
+
+    #[test]
+    fn test_sell_max_sellable_leaves_floor() {
+        // Selling exactly max_sellable succeeds and leaves the floor with nonzero price
+        let s_l = 5_000u64;
+        let s_s = 5_000u64;
+        let max = ICBSCurve::max_sellable(s_l, MIN_POOL_LIQUIDITY);
+        let (usdc_out, price_after) = ICBSCurve::calculate_sell(
+            s_l, max, Q96, s_s, 1, 1, 2, true, Q64, Q64
+        ).unwrap();
+        assert_eq!(s_l - max, MIN_POOL_LIQUIDITY);
+        assert!(usdc_out > 0, "Should receive USDC");
+        assert!(price_after > 0, "Price at the floor should stay positive");
+    }
+
+    #[test]
+    fn test_market_prediction_millionths_precision() {
+        // sqrt_price = Q96 → price_q96 = Q96 → reserve equals supply
+        let (r_long, r_short) = (1u64, 2u64);
+        let q = ICBSCurve::market_prediction(r_long, r_short, Q96, Q96).unwrap();
+        // 1/3 in millionths; the old bps path gave 333_300
+        assert_eq!(q, 333_333);
+
+        // 1/7 does not divide evenly into bps either
+        let q = ICBSCurve::market_prediction(1, 6, Q96, Q96).unwrap();
+        assert_eq!(q, 142_857);
+
+        // Empty reserves default to 50%
+        assert_eq!(ICBSCurve::market_prediction(0, 0, Q96, Q96).unwrap(), 500_000);
+    }
+}
@@ -95,4 +95,48 @@ pub enum ContentPoolError {
     VirtualSupplyOverflow,
     #[msg("Trade amount too small after rounding - increase trade size")]
     TooSmallAfterRounding,
-}
\ No newline at end of file
+
+    // Added after initial deployment: append only, so existing codes keep their values
+    #[msg("Market already deployed by this deployer (earlier attempt succeeded)")]
+    MarketAlreadyDeployedBySelf,
+    #[msg("Invalid batch: 1-4 deployments with 6 remaining accounts each")]
+    InvalidBatchSize,
+    #[msg("Pool account is not the PDA for its content_id")]
+    PdaMismatch,
+    #[msg("Pool belongs to a different factory")]
+    WrongFactory,
+    #[msg("Factory default_p0 must be nonzero")]
+    InvalidP0,
+    #[msg("LONG allocation must be less than the initial deposit")]
+    LongAllocationExceedsDeposit,
+    #[msg("initial_q is already within the clamp (nothing to repair)")]
+    InitialQNotDegenerate,
+    #[msg("Buy exceeds the curve's range; retry at or below get_max_buy's usdc_amount")]
+    TradeTooLargeForCurve,
+    #[msg("SPL mint supply exceeds pool display supply")]
+    SupplyAccountingMismatch,
+    #[msg("Vault token balance is below pool vault_balance")]
+    VaultBalanceMismatch,
+    #[msg("Market q moved further than max_q_deviation_bps from expected_q_bps")]
+    QMovedError,
+    #[msg("Stake vault is not owned by the factory's custodian")]
+    StakeVaultCustodianMismatch,
+    #[msg("Vault received a different amount than was transferred (fee-on-transfer mint?)")]
+    UnexpectedTransferAmount,
+    #[msg("Position token account still holds tokens")]
+    PositionNotEmpty,
+    #[msg("Pool was emergency-closed")]
+    PoolClosed,
+    #[msg("Pool is winding down: buys are disabled")]
+    PoolWindingDown,
+    #[msg("Pool is not winding down")]
+    NotWindingDown,
+    #[msg("Invalid fee override - fee above its cap or split above 10000 basis points")]
+    InvalidFeeOverride,
+    #[msg("Invalid protocol treasury - does not match factory")]
+    InvalidProtocolTreasury,
+    #[msg("Hold record required while this pool charges an early-exit fee")]
+    HoldRecordRequired,
+    #[msg("Skim beneficiary must be a wallet (system-owned account)")]
+    InvalidSkimBeneficiary,
+}
@@ -29,6 +29,7 @@ pub struct PoolInitializedEvent {
 pub struct MarketDeployedEvent {
     pub pool: Pubkey,
     pub deployer: Pubkey,
+    pub token_recipient: Pubkey,    // Holder of the initial tokens (deployer unless overridden)
     pub initial_deposit: u64,
     pub long_allocation: u64,
     pub short_allocation: u64,
@@ -42,14 +43,18 @@ pub struct MarketDeployedEvent {
 pub struct TradeEvent {
     pub pool: Pubkey,
     pub trader: Pubkey,
+    pub trade_seq: u64,             // Pool's trade_seq after this trade; consecutive per pool, gaps = missed events
     pub side: TokenSide,
     pub trade_type: TradeType,
+    pub skim_beneficiary: Pubkey,   // Credited with usdc_to_stake (trader unless overridden; default on sells)
 
     // Trade amounts
     pub usdc_amount: u64,           // Total USDC (including skim)
     pub usdc_to_trade: u64,         // After skim
     pub usdc_to_stake: u64,         // Skim amount
+    pub usdc_to_pol: u64,           // Retained in vault as protocol-owned liquidity (buys only)
     pub tokens_traded: u64,         // Tokens bought or sold
+    pub effective_price_micro: u64, // All-in µUSDC per display token (buy: paid incl. skim/fees, sell: net received)
 
     // ICBS State Snapshots (BEFORE trade)
     pub s_long_before: u64,
@@ -78,16 +83,45 @@ pub struct SettlementEvent {
     pub epoch: u64,                 // Pool's current epoch after settlement
     pub bd_score: u32,              // Millionths format [0, 1_000_000]
     pub market_prediction_q: u128,  // Q64.64
+    pub zero_sum: bool,             // Reserves set directly from BD score (no factor scaling)
+    pub forced: bool,               // Cooldown skipped by protocol authority override
     pub f_long: u128,               // Q64.64
     pub f_short: u128,              // Q64.64
     pub r_long_before: u128,
     pub r_short_before: u128,
+    pub r_long_pre_recouple: u128,       // r × f before the recouple to vault_balance (may exceed it)
+    pub r_short_pre_recouple: u128,      // r × f before the recouple to vault_balance (may exceed it)
     pub r_long_after: u128,
     pub r_short_after: u128,
-    pub s_scale_long_before: u128,  // NEW: Sigma scale before settlement
-    pub s_scale_long_after: u128,   // NEW: Sigma scale after settlement
-    pub s_scale_short_before: u128, // NEW: Sigma scale before settlement
-    pub s_scale_short_after: u128,  // NEW: Sigma scale after settlement
+    // σ transforms, in order: before → ÷√f (pre_renorm) → renormalize_scales (after)
+    pub s_scale_long_before: u128,       // NEW: Sigma scale before settlement
+    pub s_scale_long_pre_renorm: u128,   // Sigma after the factor step, before renormalization
+    pub s_scale_long_after: u128,        // NEW: Sigma scale after settlement (post-renormalization)
+    pub s_scale_short_before: u128,      // NEW: Sigma scale before settlement
+    pub s_scale_short_pre_renorm: u128,  // Sigma after the factor step, before renormalization
+    pub s_scale_short_after: u128,       // NEW: Sigma scale after settlement (post-renormalization)
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeSettledToPoolEvent {
+    pub pool: Pubkey,
+    pub custodian: Pubkey,
+    pub amount: u64,
+    pub new_vault_balance: u64,
+    pub new_r_long: u64,
+    pub new_r_short: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionTransferredEvent {
+    pub pool: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub long_tokens: u64,           // display units
+    pub short_tokens: u64,          // display units
     pub timestamp: i64,
 }
 
@@ -99,6 +133,16 @@ pub struct PoolClosedEvent {
     pub timestamp: i64,
 }
 
+/// Closure of a wound-down pool once every holder has exited
+#[event]
+pub struct EmergencyCloseEvent {
+    pub pool: Pubkey,
+    pub swept_usdc: u64,              // Residual vault balance sent to the protocol treasury
+    pub protocol_treasury_usdc: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TradeFeeEvent {
     pub pool: Pubkey,
@@ -108,7 +152,68 @@ pub struct TradeFeeEvent {
     pub total_fee_micro_usdc: u64,
     pub creator_fee_micro_usdc: u64,
     pub protocol_fee_micro_usdc: u64,
+    pub total_fee_bps: u16,         // Effective fee (pool override or factory)
+    pub creator_split_bps: u16,     // Effective creator split (pool override or factory)
+    pub early_exit_fee_micro_usdc: u64, // Sell penalty within the holding period (in protocol_fee)
     pub post_creator: Pubkey,
     pub protocol_treasury: Pubkey,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+#[event]
+pub struct FeeOverrideUpdatedEvent {
+    pub pool: Pubkey,
+    pub fee_bps_override: Option<u16>,        // None = use factory
+    pub creator_split_override: Option<u16>,  // None = use factory
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WindDownUpdatedEvent {
+    pub pool: Pubkey,
+    pub wind_down: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EarlyExitFeeUpdatedEvent {
+    pub pool: Pubkey,
+    pub early_exit_fee_bps: u16,
+    pub min_hold_secs: u32,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CurveParamsUpdatedEvent {
+    pub pool: Pubkey,
+    pub f: u16,
+    pub beta_num: u16,
+    pub beta_den: u16,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultResyncedEvent {
+    pub pool: Pubkey,
+    pub vault_balance_before: u64,  // pool.vault_balance before the resync
+    pub vault_balance_after: u64,   // live vault.amount
+    pub r_long_before: u64,
+    pub r_short_before: u64,
+    pub r_long_after: u64,
+    pub r_short_after: u64,
+    pub lambda_q96_after: u128,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InitialQRecomputedEvent {
+    pub pool: Pubkey,
+    pub initial_q_before: u64,      // Degenerate stored value (Q32.32)
+    pub initial_q_after: u64,       // Clamped value from current reserves (Q32.32)
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
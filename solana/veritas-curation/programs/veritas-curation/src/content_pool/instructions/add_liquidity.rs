@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, MintTo};
 
+use crate::pool_factory::state::PoolFactory;
+
 use crate::content_pool::state::*;
 use crate::content_pool::errors::ContentPoolError;
 use crate::content_pool::events::LiquidityAdded;
@@ -13,10 +15,16 @@ pub struct AddLiquidity<'info> {
     #[account(
         mut,
         seeds = [b"content_pool", pool.content_id.as_ref()],
-        bump = pool.bump
+        bump = pool.bump,
+        constraint = !pool.closed @ ContentPoolError::PoolClosed
     )]
     pub pool: Account<'info, ContentPool>,
 
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
     #[account(
         mut,
         seeds = [b"long_mint", pool.content_id.as_ref()],
@@ -56,14 +64,9 @@ pub struct AddLiquidity<'info> {
 pub fn handler(ctx: Context<AddLiquidity>, usdc_amount: u64) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
 
-    // Basic sanity
-    require!(usdc_amount > 0, ContentPoolError::InvalidTradeAmount);
-
-    // Ensure market is deployed
-    require!(
-        pool.market_deployer != Pubkey::default(),
-        ContentPoolError::MarketNotDeployed
-    );
+    // Deployment and liquidity checks come first: an undeployed pool has σ = 0,
+    // so any virtual-supply math below would divide by zero before these fire
+    check_can_add_liquidity(pool.market_deployer, pool.r_long, pool.r_short, usdc_amount)?;
 
     // 1) Ensure sigma is valid for the *current* supplies
     {
@@ -86,22 +89,15 @@ pub fn handler(ctx: Context<AddLiquidity>, usdc_amount: u64) -> Result<()> {
         0
     };
 
-    // Current market prediction q = R_L / (R_L + R_S)
-    let total_reserves = (pool.r_long as u128)
-        .checked_add(pool.r_short as u128)
-        .ok_or(ContentPoolError::NumericalOverflow)?;
-    require!(total_reserves > 0, ContentPoolError::NoLiquidity);
-
-    // Split USDC by current reserve ratio to avoid moving price:
-    // Long gets q * amount, Short gets (1-q) * amount
-    // We compute q in 1e6 micro-units to keep integer math tight.
-    let q_micro = ((pool.r_long as u128) * 1_000_000)
-        .checked_div(total_reserves)
-        .ok_or(ContentPoolError::NumericalOverflow)?;
-    let long_usdc = ((usdc_amount as u128) * q_micro / 1_000_000) as u64;
-    let short_usdc = usdc_amount
-        .checked_sub(long_usdc)
-        .ok_or(ContentPoolError::NumericalOverflow)?;
+    // Split USDC by current reserve ratio to avoid moving price.
+    // A side with zero reserve or zero supply has no meaningful price, so it gets nothing.
+    let (long_usdc, short_usdc) = split_liquidity(
+        usdc_amount,
+        pool.r_long,
+        pool.r_short,
+        pool.s_long,
+        pool.s_short,
+    )?;
 
     // 3) IMPORTANT: move the USDC transfer *before* deriving λ, so vault reflects new funds
     token::transfer(
@@ -119,54 +115,21 @@ pub fn handler(ctx: Context<AddLiquidity>, usdc_amount: u64) -> Result<()> {
     // 4) Derive λ (Q96) from the *updated* vault + (current) virtual norm
     let lambda_q96 = super::trade::derive_lambda(&ctx.accounts.pool_reserve, &pool)?;
 
-    // 5) Get display prices from λ + virtuals (no stored price dependency)
-    // For side i: p_v = (λ * s_i_v) / ||ŝ||, then p_display = p_v / σ_i
-    fn price_display_q96(
-        s_self_v: u128,
-        s_other_v: u128,
-        sigma_self_q64: u128,
-        lambda_q96: u128,
-    ) -> Result<u128> {
-        use crate::content_pool::errors::ContentPoolError;
-        use crate::content_pool::math::mul_div_u128;
-
-        let norm_v_sq = s_self_v
-            .checked_mul(s_self_v)
-            .and_then(|x| x.checked_add(s_other_v.checked_mul(s_other_v)?))
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-        let norm_v = integer_sqrt(norm_v_sq)?.max(1);
-
-        let p_v_q96 = mul_div_u128(lambda_q96, s_self_v, norm_v)?;
-        // divide by sigma to get display price
-        let p_d_q96 = mul_div_u128(p_v_q96, Q64, sigma_self_q64)?;
-        Ok(p_d_q96)
-    }
-
-    let p_long_d_q96 = if s_long_v > 0 {
-        price_display_q96(s_long_v, s_short_v, pool.s_scale_long_q64, lambda_q96)?
-    } else {
-        // if zero, approximate initial price as λ / σ_L (norm≈1 for bootstrap)
-        mul_div_u128(lambda_q96, Q64, pool.s_scale_long_q64)?
-    };
-
-    let p_short_d_q96 = if s_short_v > 0 {
-        price_display_q96(s_short_v, s_long_v, pool.s_scale_short_q64, lambda_q96)?
-    } else {
-        mul_div_u128(lambda_q96, Q64, pool.s_scale_short_q64)?
-    };
-
-    // 6) Tokens (DISPLAY) = floor( (usdc<<96) / p_display_q96 )
-    let to_display_tokens = |usdc: u64, p_d_q96: u128| -> Result<u64> {
-        require!(p_d_q96 > 0, ContentPoolError::NumericalOverflow);
-        let hi = (usdc as u128) >> 32;
-        let lo = (usdc as u128) << 96;
-        let t = div_256_by_128(hi, lo, p_d_q96)?;
-        require!(t <= u64::MAX as u128, ContentPoolError::NumericalOverflow);
-        Ok(t as u64)
-    };
-
-    let long_tokens_display = to_display_tokens(long_usdc, p_long_d_q96)?;
-    let short_tokens_display = to_display_tokens(short_usdc, p_short_d_q96)?;
+    // 5-6) Tokens (DISPLAY) per side at the current display price (0 for a skipped side)
+    let long_tokens_display = liquidity_tokens_for_side(
+        long_usdc,
+        s_long_v,
+        s_short_v,
+        pool.s_scale_long_q64,
+        lambda_q96,
+    )?;
+    let short_tokens_display = liquidity_tokens_for_side(
+        short_usdc,
+        s_short_v,
+        s_long_v,
+        pool.s_scale_short_q64,
+        lambda_q96,
+    )?;
 
     // 7) Mint in atomic units (currently a bug fix)
     let long_tokens_atomic = long_tokens_display
@@ -179,31 +142,35 @@ pub fn handler(ctx: Context<AddLiquidity>, usdc_amount: u64) -> Result<()> {
     let seeds = &[b"content_pool", pool.content_id.as_ref(), &[pool.bump]];
     let signer = &[&seeds[..]];
 
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.long_token_mint.to_account_info(),
-                to: ctx.accounts.user_long_account.to_account_info(),
-                authority: pool.to_account_info(),
-            },
-            signer,
-        ),
-        long_tokens_atomic,
-    )?;
+    if long_tokens_atomic > 0 {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.long_token_mint.to_account_info(),
+                    to: ctx.accounts.user_long_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                signer,
+            ),
+            long_tokens_atomic,
+        )?;
+    }
 
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.short_token_mint.to_account_info(),
-                to: ctx.accounts.user_short_account.to_account_info(),
-                authority: pool.to_account_info(),
-            },
-            signer,
-        ),
-        short_tokens_atomic,
-    )?;
+    if short_tokens_atomic > 0 {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.short_token_mint.to_account_info(),
+                    to: ctx.accounts.user_short_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                signer,
+            ),
+            short_tokens_atomic,
+        )?;
+    }
 
     // 8) Update supplies in display units
     pool.s_long = pool
@@ -261,6 +228,10 @@ pub fn handler(ctx: Context<AddLiquidity>, usdc_amount: u64) -> Result<()> {
     pool.r_long = r_long_calc.min(pool.vault_balance);
     pool.r_short = pool.vault_balance.saturating_sub(pool.r_long);
 
+    // Keep the deprecated telemetry fields in step with the derived λ
+    pool.lambda_long_q96 = lambda_q96_after;
+    pool.lambda_short_q96 = lambda_q96_after;
+
     emit!(LiquidityAdded {
         pool: pool.key(),
         user: ctx.accounts.user.key(),
@@ -275,3 +246,161 @@ pub fn handler(ctx: Context<AddLiquidity>, usdc_amount: u64) -> Result<()> {
 
     Ok(())
 }
+
+/// Pre-checks for add_liquidity, in order: deployed, non-zero amount, live reserves
+fn check_can_add_liquidity(
+    market_deployer: Pubkey,
+    r_long: u64,
+    r_short: u64,
+    usdc_amount: u64,
+) -> Result<()> {
+    require!(
+        market_deployer != Pubkey::default(),
+        ContentPoolError::MarketNotDeployed
+    );
+    require!(usdc_amount > 0, ContentPoolError::InvalidTradeAmount);
+    require!(
+        r_long as u128 + r_short as u128 > 0,
+        ContentPoolError::NoLiquidity
+    );
+    Ok(())
+}
+
+/// Split a liquidity deposit across sides by the current reserve ratio
+/// Long gets q * amount, Short gets (1-q) * amount, with q in 1e6 micro-units.
+///
+/// A side with zero reserve or zero supply is skipped entirely: its display price
+/// would otherwise fall back to a rough λ/σ estimate and mint mispriced tokens.
+fn split_liquidity(
+    usdc_amount: u64,
+    r_long: u64,
+    r_short: u64,
+    s_long: u64,
+    s_short: u64,
+) -> Result<(u64, u64)> {
+    let long_live = r_long > 0 && s_long > 0;
+    let short_live = r_short > 0 && s_short > 0;
+    require!(long_live || short_live, ContentPoolError::NoLiquidity);
+
+    if !long_live {
+        return Ok((0, usdc_amount));
+    }
+    if !short_live {
+        return Ok((usdc_amount, 0));
+    }
+
+    // Current market prediction q = R_L / (R_L + R_S)
+    let total_reserves = (r_long as u128)
+        .checked_add(r_short as u128)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let q_micro = ((r_long as u128) * 1_000_000)
+        .checked_div(total_reserves)
+        .ok_or(ContentPoolError::DivisionByZero)?;
+    let long_usdc = ((usdc_amount as u128) * q_micro / 1_000_000) as u64;
+    let short_usdc = usdc_amount
+        .checked_sub(long_usdc)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+
+    Ok((long_usdc, short_usdc))
+}
+
+/// Display tokens minted for `usdc` on one side
+/// p_v = (λ * s_i_v) / ||ŝ||, p_display = p_v / σ_i, tokens = floor((usdc<<96) / p_display_q96)
+fn liquidity_tokens_for_side(
+    usdc: u64,
+    s_self_v: u128,
+    s_other_v: u128,
+    sigma_self_q64: u128,
+    lambda_q96: u128,
+) -> Result<u64> {
+    if usdc == 0 {
+        return Ok(0);
+    }
+    require!(s_self_v > 0, ContentPoolError::DivisionByZero);
+
+    let norm_v_sq = s_self_v
+        .checked_mul(s_self_v)
+        .and_then(|x| x.checked_add(s_other_v.checked_mul(s_other_v)?))
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let norm_v = integer_sqrt(norm_v_sq)?.max(1);
+
+    let p_v_q96 = mul_div_u128(lambda_q96, s_self_v, norm_v)?;
+    // divide by sigma to get display price
+    let p_d_q96 = mul_div_u128(p_v_q96, Q64, sigma_self_q64)?;
+    require!(p_d_q96 > 0, ContentPoolError::DivisionByZero);
+
+    let hi = (usdc as u128) >> 32;
+    let lo = (usdc as u128) << 96;
+    let t = div_256_by_128(hi, lo, p_d_q96)?;
+    require!(t <= u64::MAX as u128, ContentPoolError::NumericalOverflow);
+    Ok(t as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::curve::Q96;
+
+    #[test]
+    fn test_undeployed_pool_rejected_before_sigma_math() {
+        // Created-but-undeployed pool: no deployer, zero reserves (and σ = 0)
+        assert_eq!(
+            check_can_add_liquidity(Pubkey::default(), 0, 0, 10_000_000).unwrap_err(),
+            ContentPoolError::MarketNotDeployed.into()
+        );
+        // Deployment is reported ahead of the amount check
+        assert_eq!(
+            check_can_add_liquidity(Pubkey::default(), 0, 0, 0).unwrap_err(),
+            ContentPoolError::MarketNotDeployed.into()
+        );
+
+        let deployer = Pubkey::new_unique();
+        assert_eq!(
+            check_can_add_liquidity(deployer, 0, 0, 10_000_000).unwrap_err(),
+            ContentPoolError::NoLiquidity.into()
+        );
+        assert!(check_can_add_liquidity(deployer, 60_000_000, 40_000_000, 10_000_000).is_ok());
+    }
+
+    #[test]
+    fn test_split_skips_zero_reserve_side() {
+        // LONG reserve decayed to zero after an extreme settlement
+        assert_eq!(split_liquidity(10_000_000, 0, 100_000_000, 50, 50).unwrap(), (0, 10_000_000));
+        assert_eq!(split_liquidity(10_000_000, 100_000_000, 0, 50, 50).unwrap(), (10_000_000, 0));
+        // Zero supply on a side is skipped too
+        assert_eq!(split_liquidity(10_000_000, 60_000_000, 40_000_000, 0, 50).unwrap(), (0, 10_000_000));
+        // Both sides dead
+        assert!(split_liquidity(10_000_000, 0, 0, 50, 50).is_err());
+    }
+
+    #[test]
+    fn test_split_follows_reserve_ratio() {
+        let (long_usdc, short_usdc) = split_liquidity(10_000_000, 60_000_000, 40_000_000, 50, 50).unwrap();
+        assert_eq!(long_usdc, 6_000_000);
+        assert_eq!(short_usdc, 4_000_000);
+    }
+
+    #[test]
+    fn test_add_liquidity_with_zero_reserve_side_is_sane() {
+        // s = (50, 50) at σ = 1, r_long = 0, vault = 100 USDC + 10 USDC deposit
+        let (s_long_v, s_short_v) = (50u128, 50u128);
+        let usdc_amount = 10_000_000u64;
+        let vault_after = 110_000_000u128;
+
+        let (long_usdc, short_usdc) = split_liquidity(usdc_amount, 0, 100_000_000, 50, 50).unwrap();
+
+        let norm = integer_sqrt(s_long_v * s_long_v + s_short_v * s_short_v).unwrap();
+        let lambda_q96 = vault_after * Q96 / norm;
+
+        let long_tokens = liquidity_tokens_for_side(long_usdc, s_long_v, s_short_v, Q64, lambda_q96).unwrap();
+        let short_tokens = liquidity_tokens_for_side(short_usdc, s_short_v, s_long_v, Q64, lambda_q96).unwrap();
+
+        assert_eq!(long_tokens, 0, "zero-reserve side must not mint");
+
+        // SHORT tokens bracket the deposit at the marginal display price
+        let p_short = (vault_after * s_short_v / (norm * norm)) as u64;
+        assert!(short_tokens > 0);
+        assert!(short_tokens * p_short <= usdc_amount);
+        assert!((short_tokens + 1) * p_short > usdc_amount);
+    }
+}
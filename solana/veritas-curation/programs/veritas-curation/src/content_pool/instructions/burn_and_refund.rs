@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::content_pool::{
+    state::{TokenSide, TradeType},
+    errors::ContentPoolError,
+};
+use super::trade::{self, *};
+
+/// Fee-free exit from a pool flagged for wind-down
+///
+/// Sells `amount` atomic tokens through the unmodified `trade` sell path, which waives
+/// trading and early-exit fees while `wind_down` is set, so the holder receives the full
+/// curve proceeds. TradeEvent and TradeFeeEvent are emitted as usual, with zero fees.
+/// Takes the same accounts as `trade`.
+pub fn handler(
+    ctx: Context<Trade>,
+    side: TokenSide,
+    amount: u64,
+    min_usdc_out: u64,
+) -> Result<()> {
+    require!(ctx.accounts.pool.wind_down, ContentPoolError::NotWindingDown);
+
+    trade::handler(
+        ctx,
+        side,
+        TradeType::Sell,
+        amount,
+        0,
+        MinTokensOut::Atomic(0),
+        min_usdc_out,
+    )
+}
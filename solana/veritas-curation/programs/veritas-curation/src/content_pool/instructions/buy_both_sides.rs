@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::content_pool::{
+    state::{TokenSide, TradeType},
+    errors::ContentPoolError,
+    math::mul_div_u128,
+};
+use super::trade::{self, *};
+
+/// A `Trade` account group for the LONG leg, followed by the SHORT mint and the
+/// trader's SHORT token account for the second leg. The programs are passed again
+/// because the SHORT account may need creating.
+#[derive(Accounts)]
+pub struct BuyBothSides<'info> {
+    #[account(
+        constraint = trade.token_mint.key() == trade.pool.long_mint @ ContentPoolError::InvalidMint
+    )]
+    pub trade: Trade<'info>,
+
+    #[account(
+        mut,
+        constraint = short_mint.key() == trade.pool.short_mint @ ContentPoolError::InvalidMint
+    )]
+    pub short_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = trade.payer,
+        associated_token::mint = short_mint,
+        associated_token::authority = trade.trader
+    )]
+    pub trader_short_tokens: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy LONG and SHORT in one transaction, splitting `amount` by the current reserve ratio
+///
+/// Each side is an ordinary `trade` buy (skim, fees, POL, TradeEvent), so the position
+/// costs the same as two separate buys. The skim is split in the same ratio.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyBothSides<'info>>,
+    amount: u64,
+    stake_skim: u64,
+    min_long_tokens_out: u64,
+    min_short_tokens_out: u64,
+) -> Result<()> {
+    let program_id = ctx.program_id;
+    let accounts = ctx.accounts;
+    // Both legs share the trader's hold record
+    let hold_record_bump = ctx.bumps.trade.hold_record;
+
+    let pool = &accounts.trade.pool;
+    let (long_amount, short_amount) = split_by_reserves(amount, pool.r_long, pool.r_short)?;
+    let (long_skim, short_skim) = split_by_reserves(stake_skim, long_amount, short_amount)?;
+
+    run_buy(program_id, &mut accounts.trade, hold_record_bump, TokenSide::Long, long_amount, long_skim, min_long_tokens_out)?;
+
+    // Point the trade accounts at the SHORT side. Pool and factory carry over in memory;
+    // the USDC accounts were read before the LONG buy moved funds, so refresh them.
+    std::mem::swap(&mut accounts.trade.token_mint, &mut accounts.short_mint);
+    std::mem::swap(&mut accounts.trade.trader_tokens, &mut accounts.trader_short_tokens);
+    accounts.trade.trader_usdc.reload()?;
+    accounts.trade.vault.reload()?;
+    accounts.trade.stake_vault.reload()?;
+
+    let result = run_buy(program_id, &mut accounts.trade, hold_record_bump, TokenSide::Short, short_amount, short_skim, min_short_tokens_out);
+
+    std::mem::swap(&mut accounts.trade.token_mint, &mut accounts.short_mint);
+    std::mem::swap(&mut accounts.trade.trader_tokens, &mut accounts.trader_short_tokens);
+    result
+}
+
+fn run_buy<'info>(
+    program_id: &Pubkey,
+    accounts: &mut Trade<'info>,
+    hold_record_bump: Option<u8>,
+    side: TokenSide,
+    amount: u64,
+    stake_skim: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    trade::handler(
+        Context::new(
+            program_id,
+            accounts,
+            &[],
+            TradeBumps { hold_record: hold_record_bump },
+        ),
+        side,
+        TradeType::Buy,
+        amount,
+        stake_skim,
+        MinTokensOut::Atomic(min_tokens_out),
+        0,
+    )
+}
+
+/// Split `amount` as r_long : r_short, returning (long, short)
+///
+/// The LONG share rounds down and SHORT takes the remainder; an empty pool splits 50/50.
+pub(crate) fn split_by_reserves(amount: u64, r_long: u64, r_short: u64) -> Result<(u64, u64)> {
+    let total = r_long as u128 + r_short as u128;
+    let long = if total == 0 {
+        amount / 2
+    } else {
+        mul_div_u128(amount as u128, r_long as u128, total)? as u64
+    };
+    Ok((long, amount - long))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_by_reserves() {
+        assert_eq!(split_by_reserves(10_000_000, 50, 50).unwrap(), (5_000_000, 5_000_000));
+        assert_eq!(split_by_reserves(10_000_000, 600, 400).unwrap(), (6_000_000, 4_000_000));
+        assert_eq!(split_by_reserves(10_000_001, 1, 2).unwrap(), (3_333_333, 6_666_668));
+        assert_eq!(split_by_reserves(7, 0, 0).unwrap(), (3, 4));
+
+        // Reserves near u64::MAX do not overflow the sum
+        assert_eq!(split_by_reserves(100, u64::MAX, u64::MAX).unwrap(), (50, 50));
+    }
+}
@@ -0,0 +1,240 @@
+//! View-only instruction: Pre-flight check for whether a trade would pass the pool's guards
+//!
+//! Does NOT mutate on-chain state - runs the cheap guards from `trade` (deployment, size
+//! bounds, supply cap, and for sells the factory's token cap, liquidity floor and dust
+//! floor) without curve math, so it is lighter than a quote.
+//! Used by: trade form (disable the button before the user signs)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::content_pool::{
+    errors::ContentPoolError,
+    state::*,
+};
+use crate::pool_factory::state::PoolFactory;
+use super::trade::{check_sell_limits, TOKEN_SCALE};
+
+#[derive(Accounts)]
+pub struct CanTrade<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    /// The seller's LONG or SHORT token account, as trade would see it
+    /// Without it a sell is checked as partial, so a full exit worth less than the dust
+    /// floor reports BelowMinSellValue although trade would let it through.
+    pub trader_tokens: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(
+    ctx: Context<CanTrade>,
+    side: TokenSide,
+    trade_type: TradeType,
+    amount: u64,
+) -> Result<TradeCheck> {
+    // Same rule as trade: less than one display token left counts as closing
+    let closes_position = ctx
+        .accounts
+        .trader_tokens
+        .as_ref()
+        .is_some_and(|tokens| tokens.amount.saturating_sub(amount) < TOKEN_SCALE);
+    let reason = check_trade(
+        &ctx.accounts.factory,
+        &ctx.accounts.pool,
+        side,
+        trade_type,
+        amount,
+        closes_position,
+    );
+    Ok(TradeCheck {
+        ok: reason == TradeBlockReason::None,
+        reason,
+    })
+}
+
+/// First guard a trade would fail, or `None` if all cheap guards pass
+///
+/// Trading is never paused (creation_paused only blocks create_pool/deploy_market) and
+/// pools do not expire, so neither is checked. Buys that would cross S_DISPLAY_CAP
+/// part-way, or mint more than max_tokens_per_trade, depend on the curve; only a side
+/// already at the cap is caught here. Sells run trade's own check_sell_limits.
+// Modulo rather than is_multiple_of, which the Solana platform toolchain predates
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn check_trade(
+    factory: &PoolFactory,
+    pool: &ContentPool,
+    side: TokenSide,
+    trade_type: TradeType,
+    amount: u64,
+    closes_position: bool,
+) -> TradeBlockReason {
+    if pool.market_deployer == Pubkey::default() {
+        return TradeBlockReason::MarketNotDeployed;
+    }
+    if pool.closed {
+        return TradeBlockReason::PoolClosed;
+    }
+
+    let s_self = match side {
+        TokenSide::Long => pool.s_long,
+        TokenSide::Short => pool.s_short,
+    };
+
+    match trade_type {
+        TradeType::Buy => {
+            if pool.wind_down {
+                TradeBlockReason::WindingDown
+            } else if amount < MIN_TRADE_SIZE {
+                TradeBlockReason::TooSmall
+            } else if amount > MAX_TRADE_SIZE {
+                TradeBlockReason::TooLarge
+            } else if s_self >= S_DISPLAY_CAP {
+                TradeBlockReason::SupplyCap
+            } else {
+                TradeBlockReason::None
+            }
+        }
+        TradeType::Sell => {
+            if amount % TOKEN_SCALE != 0 {
+                TradeBlockReason::NotWholeTokens
+            } else if amount / TOKEN_SCALE < MIN_TOKEN_TRADE_SIZE {
+                TradeBlockReason::TooSmall
+            } else {
+                match check_sell_limits(factory, pool, side, amount / TOKEN_SCALE, closes_position) {
+                    Ok(()) => TradeBlockReason::None,
+                    Err(e) if e == ContentPoolError::TradeTooLarge.into() => TradeBlockReason::TokenCap,
+                    Err(e) if e == ContentPoolError::TradeTooSmall.into() => {
+                        TradeBlockReason::BelowMinSellValue
+                    }
+                    // NoLiquidity, or overflow valuing the sell against a drained side
+                    Err(_) => TradeBlockReason::LiquidityFloor,
+                }
+            }
+        }
+    }
+}
+
+/// Why a trade would be rejected (`None` = it passes the cheap guards)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum TradeBlockReason {
+    None,
+    /// Market not deployed yet (MarketNotDeployed)
+    MarketNotDeployed,
+    /// Below MIN_TRADE_SIZE for buys or MIN_TOKEN_TRADE_SIZE for sells (InvalidTradeAmount)
+    TooSmall,
+    /// Above MAX_TRADE_SIZE (InvalidTradeAmount)
+    TooLarge,
+    /// Sell amount is not a whole number of tokens (InvalidTradeAmount)
+    NotWholeTokens,
+    /// Side supply already at S_DISPLAY_CAP (SupplyOverflow)
+    SupplyCap,
+    /// Sell would leave either side below MIN_POOL_LIQUIDITY (NoLiquidity)
+    LiquidityFloor,
+    /// Pool was emergency-closed (PoolClosed)
+    PoolClosed,
+    /// Buy on a pool flagged for wind-down; sells still pass (PoolWindingDown)
+    WindingDown,
+    /// Sell burns more than the factory's max_tokens_per_trade (TradeTooLarge)
+    TokenCap,
+    /// Partial sell worth less than the factory's min_sell_value_micro (TradeTooSmall)
+    BelowMinSellValue,
+}
+
+/// Return type for can_trade view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TradeCheck {
+    /// True when no guard would reject the trade
+    pub ok: bool,
+    /// First failing guard, or None
+    pub reason: TradeBlockReason,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::curve::ICBSCurve;
+    use crate::content_pool::test_utils::{zeroed_factory, zeroed_pool};
+
+    fn deployed_pool(s_long: u64, s_short: u64) -> ContentPool {
+        let mut pool = zeroed_pool();
+        pool.market_deployer = Pubkey::new_unique();
+        pool.s_long = s_long;
+        pool.s_short = s_short;
+        pool
+    }
+
+    #[test]
+    fn test_check_trade_buy_guards() {
+        let factory = zeroed_factory();
+        let pool = deployed_pool(10_000, 10_000);
+        let buy = |pool: &ContentPool, amount| {
+            check_trade(&factory, pool, TokenSide::Long, TradeType::Buy, amount, false)
+        };
+
+        assert_eq!(buy(&pool, MIN_TRADE_SIZE), TradeBlockReason::None);
+        assert_eq!(buy(&pool, MIN_TRADE_SIZE - 1), TradeBlockReason::TooSmall);
+        assert_eq!(buy(&pool, MAX_TRADE_SIZE + 1), TradeBlockReason::TooLarge);
+        assert_eq!(buy(&deployed_pool(S_DISPLAY_CAP, 10_000), MIN_TRADE_SIZE), TradeBlockReason::SupplyCap);
+
+        let mut undeployed = pool.clone();
+        undeployed.market_deployer = Pubkey::default();
+        assert_eq!(buy(&undeployed, MIN_TRADE_SIZE), TradeBlockReason::MarketNotDeployed);
+
+        let mut closed = pool.clone();
+        closed.closed = true;
+        assert_eq!(buy(&closed, MIN_TRADE_SIZE), TradeBlockReason::PoolClosed);
+
+        let mut winding_down = pool.clone();
+        winding_down.wind_down = true;
+        assert_eq!(buy(&winding_down, MIN_TRADE_SIZE), TradeBlockReason::WindingDown);
+        assert_eq!(
+            check_trade(&factory, &winding_down, TokenSide::Long, TradeType::Sell, TOKEN_SCALE, false),
+            TradeBlockReason::None
+        );
+    }
+
+    #[test]
+    fn test_check_trade_sell_guards() {
+        let factory = zeroed_factory();
+        let pool = deployed_pool(10_000, 10_000);
+        let sell = |pool: &ContentPool, amount| {
+            check_trade(&factory, pool, TokenSide::Long, TradeType::Sell, amount, false)
+        };
+        let max = ICBSCurve::max_sellable(10_000, MIN_POOL_LIQUIDITY);
+
+        assert_eq!(sell(&pool, max * TOKEN_SCALE), TradeBlockReason::None);
+        assert_eq!(sell(&pool, (max + 1) * TOKEN_SCALE), TradeBlockReason::LiquidityFloor);
+        assert_eq!(sell(&pool, TOKEN_SCALE + 1), TradeBlockReason::NotWholeTokens);
+        assert_eq!(sell(&pool, 0), TradeBlockReason::TooSmall);
+
+        // The other side below the floor blocks sells too
+        assert_eq!(sell(&deployed_pool(10_000, MIN_POOL_LIQUIDITY - 1), TOKEN_SCALE), TradeBlockReason::LiquidityFloor);
+    }
+
+    #[test]
+    fn test_check_trade_applies_factory_sell_limits() {
+        use crate::pool_factory::state::DEFAULT_MIN_SELL_VALUE_MICRO;
+
+        let mut factory = zeroed_factory();
+        factory.max_tokens_per_trade = 100;
+        factory.min_sell_value_micro = DEFAULT_MIN_SELL_VALUE_MICRO;
+        let mut pool = deployed_pool(2_000, 2_000);
+        pool.r_long = 10_000_000; // 0.005 USDC per LONG token
+        let sell = |pool: &ContentPool, tokens: u64, closes| {
+            check_trade(&factory, pool, TokenSide::Long, TradeType::Sell, tokens * TOKEN_SCALE, closes)
+        };
+
+        assert_eq!(sell(&pool, 2, false), TradeBlockReason::None);
+        assert_eq!(sell(&pool, 101, false), TradeBlockReason::TokenCap);
+        assert_eq!(sell(&pool, 1, false), TradeBlockReason::BelowMinSellValue);
+
+        // As in trade: a closing sell skips the dust floor, wind-down skips all three
+        assert_eq!(sell(&pool, 1, true), TradeBlockReason::None);
+        pool.wind_down = true;
+        assert_eq!(sell(&pool, 101, false), TradeBlockReason::None);
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, CloseAccount};
+use crate::content_pool::{
+    state::ContentPool,
+    errors::ContentPoolError,
+};
+
+#[derive(Accounts)]
+pub struct CloseEmptyPosition<'info> {
+    #[account(
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    /// LONG or SHORT mint of this pool
+    #[account(
+        constraint = (
+            token_mint.key() == pool.long_mint ||
+            token_mint.key() == pool.short_mint
+        ) @ ContentPoolError::InvalidMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// User's position ATA - must be empty
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+        constraint = user_tokens.amount == 0 @ ContentPoolError::PositionNotEmpty
+    )]
+    pub user_tokens: Account<'info, TokenAccount>,
+
+    /// Position owner - receives the reclaimed rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Close a user's empty LONG/SHORT token ATA and return its rent to the user
+pub fn handler(ctx: Context<CloseEmptyPosition>) -> Result<()> {
+    token::close_account(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.user_tokens.to_account_info(),
+                destination: ctx.accounts.user.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+    )?;
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer, CloseAccount, SetAuthority};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
 use crate::pool_factory::state::PoolFactory;
 use crate::content_pool::{
     state::ContentPool,
@@ -28,6 +29,20 @@ pub struct ClosePool<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
 
+    /// Required once the market is deployed (checked in handler)
+    #[account(
+        mut,
+        constraint = long_mint.key() == pool.long_mint @ ContentPoolError::InvalidMint
+    )]
+    pub long_mint: Option<Account<'info, Mint>>,
+
+    /// Required once the market is deployed (checked in handler)
+    #[account(
+        mut,
+        constraint = short_mint.key() == pool.short_mint @ ContentPoolError::InvalidMint
+    )]
+    pub short_mint: Option<Account<'info, Mint>>,
+
     #[account(mut)]
     pub receiver_usdc: Account<'info, TokenAccount>,
 
@@ -52,7 +67,6 @@ pub fn handler(ctx: Context<ClosePool>) -> Result<()> {
     let clock = Clock::get()?;
 
     // Can only close if no tokens are in circulation
-    // This is simplified - in production you'd check total supply
     require!(
         pool.s_long == 0 && pool.s_short == 0,
         ContentPoolError::PositionsStillOpen
@@ -64,6 +78,30 @@ pub fn handler(ctx: Context<ClosePool>) -> Result<()> {
         &[pool.bump],
     ];
 
+    // Retire the SPL mints while the pool PDA can still sign. SPL Token mints cannot be
+    // closed (only Token-2022 with a close authority can), so their rent stays locked;
+    // dropping the mint authority at least leaves them permanently inert.
+    if pool.market_deployer != Pubkey::default() {
+        for mint in [&ctx.accounts.long_mint, &ctx.accounts.short_mint] {
+            let mint = mint.as_ref().ok_or(ContentPoolError::InvalidMint)?;
+            require!(mint.supply == 0, ContentPoolError::PositionsStillOpen);
+            if mint.mint_authority.is_some() {
+                token::set_authority(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        SetAuthority {
+                            current_authority: pool.to_account_info(),
+                            account_or_mint: mint.to_account_info(),
+                        },
+                        &[pool_seeds],
+                    ),
+                    AuthorityType::MintTokens,
+                    None,
+                )?;
+            }
+        }
+    }
+
     // Transfer any remaining USDC to receiver
     let remaining_usdc = ctx.accounts.vault.amount;
     if remaining_usdc > 0 {
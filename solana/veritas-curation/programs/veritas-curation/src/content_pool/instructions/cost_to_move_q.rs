@@ -0,0 +1,186 @@
+//! View-only instruction: Quotes the buy that moves relevance q to a target
+//!
+//! Does NOT mutate on-chain state - solves the curve for the supply that gives the
+//! target reserve ratio, then prices that buy like quote_buy_exact_out (no stake skim).
+//! Used by: market makers ("how much to move 50% → 60%?")
+
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::*,
+    errors::ContentPoolError,
+    curve::integer_sqrt,
+    math::{renormalize_scales, ceil_div},
+};
+use super::trade::{calc_fees, calc_pol, effective_fee_config, virtual_supplies, lambda_from_virtual};
+use super::quote_buy_exact_out::{curve_cost_for_exact_out, gross_up_for_fees};
+
+#[derive(Accounts)]
+pub struct CostToMoveQ<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn handler(ctx: Context<CostToMoveQ>, target_q_bps: u16) -> Result<MoveQCost> {
+    let factory = &ctx.accounts.factory;
+    let mut pool = ContentPool::clone(&ctx.accounts.pool);
+
+    require!(pool.market_deployer != Pubkey::default(), ContentPoolError::MarketNotDeployed);
+    require!(
+        (MIN_PREDICTION_BPS..=MAX_PREDICTION_BPS).contains(&target_q_bps),
+        ContentPoolError::InvalidParameter
+    );
+
+    // Same pre-curve state a buy sees: σ renormalized, then λ from vault + virtual supplies
+    renormalize_scales(
+        &mut pool.s_scale_long_q64,
+        &mut pool.s_scale_short_q64,
+        pool.s_long,
+        pool.s_short,
+    );
+    let (s_long_virtual, s_short_virtual) = virtual_supplies(&pool)?;
+    let lambda_q96 = lambda_from_virtual(pool.vault_balance, s_long_virtual, s_short_virtual)?;
+
+    let current_q_bps = q_bps_from_virtual(s_long_virtual, s_short_virtual);
+
+    // Raising q means buying LONG, lowering it means buying SHORT
+    let side = if target_q_bps >= current_q_bps { TokenSide::Long } else { TokenSide::Short };
+    let (s_side_virtual, s_other_virtual, sigma_side_q64) = match side {
+        TokenSide::Long => (s_long_virtual, s_short_virtual, pool.s_scale_long_q64),
+        TokenSide::Short => (s_short_virtual, s_long_virtual, pool.s_scale_short_q64),
+    };
+
+    let s_target_virtual = target_side_supply(s_other_virtual, target_q_bps, side)?;
+    let delta_virtual = s_target_virtual.saturating_sub(s_side_virtual);
+    if delta_virtual == 0 {
+        return Ok(MoveQCost {
+            side,
+            usdc_amount: 0,
+            total_fee: 0,
+            usdc_to_pol: 0,
+            usdc_to_curve: 0,
+            tokens_display: 0,
+            current_q_bps,
+        });
+    }
+
+    // Whole display tokens covering the virtual delta, then the virtual delta they buy
+    let tokens_display = u64::try_from(ceil_div(delta_virtual * sigma_side_q64, Q64))
+        .map_err(|_| ContentPoolError::NumericalOverflow)?;
+    let s_side = match side {
+        TokenSide::Long => pool.s_long,
+        TokenSide::Short => pool.s_short,
+    };
+    require!(
+        s_side.checked_add(tokens_display).is_some_and(|s| s <= S_DISPLAY_CAP),
+        ContentPoolError::SupplyOverflow
+    );
+    let delta_virtual = ceil_div(tokens_display as u128 * Q64, sigma_side_q64);
+
+    let usdc_to_curve = curve_cost_for_exact_out(
+        s_side_virtual,
+        s_other_virtual,
+        delta_virtual,
+        lambda_q96,
+    )?;
+
+    let (total_fee_bps, _) = effective_fee_config(&pool, factory);
+    let usdc_amount = gross_up_for_fees(usdc_to_curve, total_fee_bps, factory.pol_bps)?;
+    let (total_fee, _, _) = calc_fees(usdc_amount, total_fee_bps, 0)?;
+    let usdc_to_pol = calc_pol(usdc_amount, factory.pol_bps)?;
+
+    Ok(MoveQCost {
+        side,
+        usdc_amount,
+        total_fee,
+        usdc_to_pol,
+        usdc_to_curve,
+        tokens_display,
+        current_q_bps,
+    })
+}
+
+/// q in bps from virtual supplies
+///
+/// For F=1, β=0.5 each reserve is r_i = λ·ŝ_i²/||ŝ||, so q = ŝ_L² / (ŝ_L² + ŝ_S²);
+/// this matches r_long / (r_long + r_short) up to rounding. 50% for an empty pool.
+pub(crate) fn q_bps_from_virtual(s_long_v: u128, s_short_v: u128) -> u16 {
+    let long_sq = s_long_v.saturating_mul(s_long_v);
+    let total_sq = long_sq.saturating_add(s_short_v.saturating_mul(s_short_v));
+    long_sq
+        .saturating_mul(10_000)
+        .checked_div(total_sq)
+        .map_or(5_000, |q| q as u16)
+}
+
+/// Smallest virtual supply on `side` that brings q to `target_q_bps` against `s_other_v`
+///
+/// Buying LONG: ŝ_L = ŝ_S·√(q / (1 − q)). Buying SHORT: ŝ_S = ŝ_L·√((1 − q) / q).
+/// Rounded up so the resulting q reaches the target.
+pub(crate) fn target_side_supply(s_other_v: u128, target_q_bps: u16, side: TokenSide) -> Result<u128> {
+    let q = target_q_bps as u128;
+    let (num, den) = match side {
+        TokenSide::Long => (q, 10_000 - q),
+        TokenSide::Short => (10_000 - q, q),
+    };
+    let target_sq = s_other_v
+        .checked_mul(s_other_v)
+        .and_then(|sq| sq.checked_mul(num))
+        .map(|x| ceil_div(x, den))
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let mut s = integer_sqrt(target_sq)?;
+    if s * s < target_sq {
+        s += 1;
+    }
+    Ok(s)
+}
+
+/// Return type for cost_to_move_q view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MoveQCost {
+    /// Side to buy (LONG raises q, SHORT lowers it)
+    pub side: TokenSide,
+    /// USDC to pass as `amount` to `trade` with zero stake skim (µUSDC, includes fees)
+    pub usdc_amount: u64,
+    /// Trading fee taken from usdc_amount (µUSDC)
+    pub total_fee: u64,
+    /// Protocol-owned liquidity share (µUSDC, not priced by the curve)
+    pub usdc_to_pol: u64,
+    /// USDC the curve prices (µUSDC)
+    pub usdc_to_curve: u64,
+    /// Display tokens the buy mints
+    pub tokens_display: u64,
+    /// q before the buy in bps, from the same virtual supplies the quote uses
+    pub current_q_bps: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_side_supply_reaches_target() {
+        for (s_other, target) in [(1_000u128, 6_000u16), (1_000, 9_900), (2_500, 5_001), (40_000, 7_321)] {
+            let s = target_side_supply(s_other, target, TokenSide::Long).unwrap();
+            assert!(q_bps_from_virtual(s, s_other) >= target);
+            assert!(q_bps_from_virtual(s - 1, s_other) < target);
+        }
+
+        // Symmetric on the SHORT side
+        let s = target_side_supply(1_000, 4_000, TokenSide::Short).unwrap();
+        assert_eq!(s, target_side_supply(1_000, 6_000, TokenSide::Long).unwrap());
+        assert!(q_bps_from_virtual(1_000, s) <= 4_000);
+    }
+
+    #[test]
+    fn test_q_bps_from_virtual() {
+        assert_eq!(q_bps_from_virtual(1_000, 1_000), 5_000);
+        assert_eq!(q_bps_from_virtual(0, 0), 5_000);
+        assert_eq!(q_bps_from_virtual(3, 1), 9_000);
+    }
+}
@@ -11,10 +11,11 @@ use crate::content_pool::{
     state::*,
     events::MarketDeployedEvent,
     errors::ContentPoolError,
-    curve::Q96,
+    curve::{Q96, integer_sqrt},
     math::{mul_div_u128, mul_shift_right_96},
 };
-use crate::pool_factory::state::PoolFactory;
+use crate::pool_factory::{state::PoolFactory, errors::FactoryError};
+use super::trade::check_transfer_delta;
 
 /// Helper to decode SPL token account
 fn read_token_account(ai: &AccountInfo) -> Result<SplAccount> {
@@ -41,7 +42,7 @@ pub struct DeployMarket<'info> {
     pub factory: Account<'info, PoolFactory>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         mint::decimals = TOKEN_DECIMALS,
         mint::authority = pool,
@@ -51,7 +52,7 @@ pub struct DeployMarket<'info> {
     pub long_mint: Account<'info, Mint>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         mint::decimals = TOKEN_DECIMALS,
         mint::authority = pool,
@@ -61,7 +62,7 @@ pub struct DeployMarket<'info> {
     pub short_mint: Account<'info, Mint>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         token::mint = usdc_mint,
         token::authority = pool,
@@ -74,11 +75,11 @@ pub struct DeployMarket<'info> {
     #[account(mut)]
     pub deployer_usdc: UncheckedAccount<'info>,
 
-    /// CHECK: PDA, will be created manually if needed
+    /// CHECK: Token recipient's LONG ATA, will be created manually if needed
     #[account(mut)]
     pub deployer_long: UncheckedAccount<'info>,
 
-    /// CHECK: PDA, will be created manually if needed
+    /// CHECK: Token recipient's SHORT ATA, will be created manually if needed
     #[account(mut)]
     pub deployer_short: UncheckedAccount<'info>,
 
@@ -93,262 +94,223 @@ pub struct DeployMarket<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    /// CHECK: Wallet that receives the initial LONG/SHORT tokens; omit to use the deployer.
+    /// The deployer still funds the deposit and is recorded as market_deployer.
+    pub token_recipient: Option<UncheckedAccount<'info>>,
+}
+
+/// deploy_market arguments, bundled so new optional fields are appended rather than
+/// inserted positionally. Only `initial_deposit` is required.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DeployParams {
+    /// Total USDC deposited (µUSDC)
+    pub initial_deposit: u64,
+    /// µUSDC of the deposit backing LONG; None splits the deposit evenly
+    pub long_allocation: Option<u64>,
+}
+
+impl DeployParams {
+    /// Params with only the required deposit set (even LONG/SHORT split)
+    pub fn new(initial_deposit: u64) -> Self {
+        Self { initial_deposit, long_allocation: None }
+    }
+
+    /// LONG allocation with the even-split default applied
+    pub fn long_allocation(&self) -> u64 {
+        self.long_allocation.unwrap_or(self.initial_deposit / 2)
+    }
+}
+
+pub fn handler(ctx: Context<DeployMarket>, params: DeployParams) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let token_recipient = match &accounts.token_recipient {
+        Some(recipient) => recipient.to_account_info(),
+        None => accounts.deployer.to_account_info(),
+    };
+    let infos = DeployInfos {
+        long_mint: accounts.long_mint.to_account_info(),
+        short_mint: accounts.short_mint.to_account_info(),
+        vault: accounts.vault.to_account_info(),
+        deployer_usdc: accounts.deployer_usdc.to_account_info(),
+        deployer_long: accounts.deployer_long.to_account_info(),
+        deployer_short: accounts.deployer_short.to_account_info(),
+        usdc_mint: accounts.usdc_mint.to_account_info(),
+        deployer: accounts.deployer.to_account_info(),
+        token_recipient,
+        payer: accounts.payer.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        associated_token_program: accounts.associated_token_program.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+    };
+    deploy(
+        &mut accounts.pool,
+        &accounts.factory,
+        &infos,
+        ctx.program_id,
+        params.initial_deposit,
+        params.long_allocation(),
+    )
+}
+
+/// Accounts a single deployment touches besides the pool and factory
+///
+/// Mints and vault must already exist at their PDAs (created by Anchor in
+/// deploy_market, or manually in deploy_market_batch).
+pub(super) struct DeployInfos<'info> {
+    pub long_mint: AccountInfo<'info>,
+    pub short_mint: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub deployer_usdc: AccountInfo<'info>,
+    /// token_recipient's LONG ATA
+    pub deployer_long: AccountInfo<'info>,
+    /// token_recipient's SHORT ATA
+    pub deployer_short: AccountInfo<'info>,
+    pub usdc_mint: AccountInfo<'info>,
+    pub deployer: AccountInfo<'info>,
+    /// Owner of the initial LONG/SHORT tokens (the deployer unless overridden)
+    pub token_recipient: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
 }
 
-pub fn handler(
-    ctx: Context<DeployMarket>,
+/// Deployment logic shared by deploy_market and deploy_market_batch
+pub(super) fn deploy<'info>(
+    pool: &mut Account<'info, ContentPool>,
+    factory: &Account<'info, PoolFactory>,
+    infos: &DeployInfos<'info>,
+    program_id: &Pubkey,
     initial_deposit: u64,
     long_allocation: u64,
 ) -> Result<()> {
     // Validate pool PDA
     let expected_pool = Pubkey::find_program_address(
-        &[b"content_pool", ctx.accounts.pool.content_id.as_ref()],
-        ctx.program_id,
+        &[b"content_pool", pool.content_id.as_ref()],
+        program_id,
     ).0;
     require!(
-        expected_pool == ctx.accounts.pool.key(),
-        ContentPoolError::InvalidParameter
+        expected_pool == pool.key(),
+        ContentPoolError::PdaMismatch
     );
 
     // Validate pool state
+    check_not_deployed(pool.market_deployer, infos.deployer.key())?;
     require!(
-        ctx.accounts.pool.market_deployer == Pubkey::default(),
-        ContentPoolError::MarketAlreadyDeployed
+        pool.factory == factory.key(),
+        ContentPoolError::WrongFactory
     );
     require!(
-        ctx.accounts.pool.factory == ctx.accounts.factory.key(),
-        ContentPoolError::InvalidParameter
+        !factory.creation_paused,
+        FactoryError::CreationPaused
     );
 
     // Validate deployer USDC account
-    let deployer_usdc_acc = read_token_account(&ctx.accounts.deployer_usdc.to_account_info())?;
+    let deployer_usdc_acc = read_token_account(&infos.deployer_usdc)?;
     require!(
-        deployer_usdc_acc.owner == ctx.accounts.deployer.key(),
-        ContentPoolError::InvalidParameter
+        deployer_usdc_acc.owner == infos.deployer.key(),
+        ContentPoolError::InvalidOwner
     );
     require!(
-        deployer_usdc_acc.mint == ctx.accounts.usdc_mint.key(),
-        ContentPoolError::InvalidParameter
+        deployer_usdc_acc.mint == infos.usdc_mint.key(),
+        ContentPoolError::InvalidMint
     );
 
     // Validate USDC mint
-    let usdc_mint_acc = read_mint(&ctx.accounts.usdc_mint.to_account_info())?;
+    // Decimals alone don't rule out a fee-on-transfer mint; the deposit below is
+    // checked against the vault's balance change instead
+    let usdc_mint_acc = read_mint(&infos.usdc_mint)?;
     require!(
         usdc_mint_acc.decimals == 6,
-        ContentPoolError::InvalidParameter
+        ContentPoolError::InvalidMint
     );
 
     // Validate deposit against factory minimum
     require!(
-        initial_deposit >= ctx.accounts.factory.min_initial_deposit,
+        initial_deposit >= factory.min_initial_deposit,
         ContentPoolError::BelowMinimumDeposit
     );
 
     // Validate allocation
-    let short_allocation = initial_deposit
-        .checked_sub(long_allocation)
-        .ok_or(ContentPoolError::InvalidAllocation)?;
-
-    require!(
-        long_allocation > 0 && short_allocation > 0,
-        ContentPoolError::InvalidAllocation
-    );
+    let short_allocation = split_allocation(initial_deposit, long_allocation)?;
 
     // Get p0 from factory (used for supply calculation later)
-    let p0 = ctx.accounts.factory.default_p0;
+    let p0 = factory.default_p0;
 
-    // Create deployer's LONG token account if needed
-    if ctx.accounts.deployer_long.data_is_empty() {
+    // Create recipient's LONG token account if needed
+    if infos.deployer_long.data_is_empty() {
         anchor_spl::associated_token::create(
             CpiContext::new(
-                ctx.accounts.associated_token_program.to_account_info(),
+                infos.associated_token_program.clone(),
                 anchor_spl::associated_token::Create {
-                    payer: ctx.accounts.payer.to_account_info(),
-                    associated_token: ctx.accounts.deployer_long.to_account_info(),
-                    authority: ctx.accounts.deployer.to_account_info(),
-                    mint: ctx.accounts.long_mint.to_account_info(),
-                    system_program: ctx.accounts.system_program.to_account_info(),
-                    token_program: ctx.accounts.token_program.to_account_info(),
+                    payer: infos.payer.clone(),
+                    associated_token: infos.deployer_long.clone(),
+                    authority: infos.token_recipient.clone(),
+                    mint: infos.long_mint.clone(),
+                    system_program: infos.system_program.clone(),
+                    token_program: infos.token_program.clone(),
                 },
             ),
         )?;
     }
 
-    // Create deployer's SHORT token account if needed
-    if ctx.accounts.deployer_short.data_is_empty() {
+    // Create recipient's SHORT token account if needed
+    if infos.deployer_short.data_is_empty() {
         anchor_spl::associated_token::create(
             CpiContext::new(
-                ctx.accounts.associated_token_program.to_account_info(),
+                infos.associated_token_program.clone(),
                 anchor_spl::associated_token::Create {
-                    payer: ctx.accounts.payer.to_account_info(),
-                    associated_token: ctx.accounts.deployer_short.to_account_info(),
-                    authority: ctx.accounts.deployer.to_account_info(),
-                    mint: ctx.accounts.short_mint.to_account_info(),
-                    system_program: ctx.accounts.system_program.to_account_info(),
-                    token_program: ctx.accounts.token_program.to_account_info(),
+                    payer: infos.payer.clone(),
+                    associated_token: infos.deployer_short.clone(),
+                    authority: infos.token_recipient.clone(),
+                    mint: infos.short_mint.clone(),
+                    system_program: infos.system_program.clone(),
+                    token_program: infos.token_program.clone(),
                 },
             ),
         )?;
     }
 
+    // Pre-existing token accounts must belong to the recipient
+    require!(
+        read_token_account(&infos.deployer_long)?.owner == infos.token_recipient.key()
+            && read_token_account(&infos.deployer_short)?.owner == infos.token_recipient.key(),
+        ContentPoolError::InvalidOwner
+    );
+
+    // Base supplies are checked against S_DISPLAY_CAP before any funds move
+    let (s_l0, s_s0) = base_supplies(long_allocation, short_allocation, p0)?;
+
     // Transfer USDC to vault
     let cpi_accounts = Transfer {
-        from: ctx.accounts.deployer_usdc.to_account_info(),
-        to: ctx.accounts.vault.to_account_info(),
-        authority: ctx.accounts.deployer.to_account_info(),
+        from: infos.deployer_usdc.clone(),
+        to: infos.vault.clone(),
+        authority: infos.deployer.clone(),
     };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    let vault_amount_before = read_token_account(&infos.vault)?.amount;
+    let cpi_ctx = CpiContext::new(infos.token_program.clone(), cpi_accounts);
     token::transfer(cpi_ctx, initial_deposit)?;
+    check_transfer_delta(vault_amount_before, read_token_account(&infos.vault)?.amount, initial_deposit)?;
 
     // === ON-MANIFOLD DEPLOYMENT (√allocation + candidate search) ===
     // For F=1, β=0.5: C(s_L, s_S) = ||s|| and p_i = λ·s_i/||s||
     // We pick integer supplies (s_L, s_S) to match the allocation ratio,
     // then set λ = D/||s|| to hit the deposit exactly (staying on-manifold).
 
-    require!(
-        p0 > 0,
-        ContentPoolError::InvalidParameter
-    );
-
     let a_l: u128 = long_allocation as u128;
     let a_s: u128 = short_allocation as u128;
-    let a_ref: u128 = a_l.max(a_s);
-
-    // Base supplies from √allocation (floor)
-    let s_l0 = integer_sqrt(
-        a_l.checked_mul(a_ref)
-            .ok_or(ContentPoolError::NumericalOverflow)?
-    )?.checked_div(p0 as u128)
-        .ok_or(ContentPoolError::InvalidParameter)?;
-
-    let s_s0 = integer_sqrt(
-        a_s.checked_mul(a_ref)
-            .ok_or(ContentPoolError::NumericalOverflow)?
-    )?.checked_div(p0 as u128)
-        .ok_or(ContentPoolError::InvalidParameter)?;
-
-    require!(
-        s_l0 > 0 && s_s0 > 0,
-        ContentPoolError::InvalidAllocation
-    );
 
     // Candidate search: try {s_l0, s_l0+1} × {s_s0, s_s0+1} to fix floor rounding
     // Pick the candidate that minimizes reserve ratio error
-    struct Candidate {
-        s_long: u64,
-        s_short: u64,
-        lambda_q96: u128,  // λ in Q96 format (NOT sqrt!)
-        sqrt_price_long_x96: u128,
-        sqrt_price_short_x96: u128,
-        r_long: u64,
-        r_short: u64,
-        ratio_error: u128,
-    }
-
-    let mut best: Option<Candidate> = None;
-    // Only try base + bump smaller side by +1 (2 candidates to save CUs)
-    let candidates = if s_l0 >= s_s0 {
-        [(s_l0, s_s0), (s_l0, s_s0 + 1)]
-    } else {
-        [(s_l0, s_s0), (s_l0 + 1, s_s0)]
-    };
-
-    for &(s_l_cand, s_s_cand) in &candidates {
-        let s_l_u64 = s_l_cand as u64;
-        let s_s_u64 = s_s_cand as u64;
-
-        // ---------- OPTION A: exact deploy prices from deposit identity ----------
-        // Geometry (F=1, β=0.5):
-        //   p_i = D * s_i / (s_L^2 + s_S^2)
-        // Do it fully in Q96 without any sqrt(||s||) so we avoid floor bias.
-
-        let n2 = s_l_cand
-            .checked_mul(s_l_cand)
-            .ok_or(ContentPoolError::NumericalOverflow)?
-            .checked_add(
-                s_s_cand
-                    .checked_mul(s_s_cand)
-                    .ok_or(ContentPoolError::NumericalOverflow)?,
-            )
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-
-        // Q96 scale: d_over_n2_q96 = (D * Q96) / (s_L^2 + s_S^2)
-        let d_over_n2_q96 = mul_div_u128(initial_deposit as u128, Q96, n2)?;
-
-        // p_i in Q96: p_i = d_over_n2_q96 * s_i
-        // Use checked_mul to keep 256-bit intermediate and avoid u128 overflow.
-        let p_long_q96 = d_over_n2_q96
-            .checked_mul(s_l_cand as u128)
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-        let p_short_q96 = d_over_n2_q96
-            .checked_mul(s_s_cand as u128)
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-
-        // sqrt_price_i_x96 = sqrt(p_i_q96) << 48   (so that (sqrt_price >>48)^2 is Q96)
-        let sqrt_price_long_x96 = integer_sqrt(p_long_q96)?
-            .checked_shl(48)
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-        let sqrt_price_short_x96 = integer_sqrt(p_short_q96)?
-            .checked_shl(48)
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-
-        // Make λ consistent with curve: p_i = λ * s_i / ||s|| with *your* integer ||s||.
-        // We compute ||s|| as integer sqrt (same as the runtime curve will do).
-        let s_norm_int = integer_sqrt(n2)?.max(1);
-
-        msg!("DEBUG: s_norm_int={}, s_l_cand={}, s_s_cand={}", s_norm_int, s_l_cand, s_s_cand);
-        msg!("DEBUG: p_long_q96={}, p_short_q96={}", p_long_q96, p_short_q96);
-
-        // λ in Q96 from each side, then take max to cover any ulp asymmetry.
-        let lambda_q96_from_long  = mul_div_u128(p_long_q96,  s_norm_int, s_l_cand)?;
-        let lambda_q96_from_short = mul_div_u128(p_short_q96, s_norm_int, s_s_cand)?;
-        let lambda_x96 = lambda_q96_from_long.max(lambda_q96_from_short);
-
-        msg!("DEBUG: lambda_q96_from_long={}", lambda_q96_from_long);
-        msg!("DEBUG: lambda_q96_from_short={}", lambda_q96_from_short);
-        msg!("DEBUG: lambda_x96={}", lambda_x96);
-
-        // Reserves: r_i = (p_i_q96 * s_i) >> 96
-        let r_long  = mul_shift_right_96(p_long_q96,  s_l_cand)?  as u64;
-        let r_short = mul_shift_right_96(p_short_q96, s_s_cand)? as u64;
-        // ---------- end OPTION A block ----------
-
-        // Score by reserve ratio error: minimize |r_long * A_S - r_short * A_L|
-        let cross_l = (r_long as u128).checked_mul(a_s)
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-        let cross_s = (r_short as u128).checked_mul(a_l)
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-        let ratio_error = if cross_l > cross_s {
-            cross_l - cross_s
-        } else {
-            cross_s - cross_l
-        };
-
-        let candidate = Candidate {
-            s_long: s_l_u64,
-            s_short: s_s_u64,
-            lambda_q96: lambda_x96,
-            sqrt_price_long_x96,
-            sqrt_price_short_x96,
-            r_long,
-            r_short,
-            ratio_error,
-        };
-
-        if best.is_none() || ratio_error < best.as_ref().unwrap().ratio_error {
-            best = Some(candidate);
-        }
-    }
-
-    let chosen = best.ok_or(ContentPoolError::InvalidParameter)?;
+    let chosen = select_candidate(s_l0, s_s0, initial_deposit, a_l, a_s)?;
 
     msg!("deploy_market: chosen s_long={}, s_short={}, ratio_error={}",
          chosen.s_long, chosen.s_short, chosen.ratio_error);
     msg!("deploy_market: r_long={}, r_short={}, r_sum={}",
          chosen.r_long, chosen.r_short, chosen.r_long as u128 + chosen.r_short as u128);
 
-    // Mint tokens to deployer
-    let pool = &ctx.accounts.pool;
+    // Mint tokens to the recipient
     let seeds: &[&[&[u8]]] = &[&[
         b"content_pool",
         pool.content_id.as_ref(),
@@ -357,12 +319,12 @@ pub fn handler(
 
     // Mint LONG tokens
     let mint_long_accounts = MintTo {
-        mint: ctx.accounts.long_mint.to_account_info(),
-        to: ctx.accounts.deployer_long.to_account_info(),
+        mint: infos.long_mint.clone(),
+        to: infos.deployer_long.clone(),
         authority: pool.to_account_info(),
     };
     let mint_long_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
+        infos.token_program.clone(),
         mint_long_accounts,
         seeds,
     );
@@ -375,12 +337,12 @@ pub fn handler(
 
     // Mint SHORT tokens
     let mint_short_accounts = MintTo {
-        mint: ctx.accounts.short_mint.to_account_info(),
-        to: ctx.accounts.deployer_short.to_account_info(),
+        mint: infos.short_mint.clone(),
+        to: infos.deployer_short.clone(),
         authority: pool.to_account_info(),
     };
     let mint_short_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
+        infos.token_program.clone(),
         mint_short_accounts,
         seeds,
     );
@@ -392,16 +354,14 @@ pub fn handler(
     // Use the chosen values for pool state
     let s_long = chosen.s_long;
     let s_short = chosen.s_short;
-    let r_long = chosen.r_long;
-    let r_short = chosen.r_short;
+    let (r_long, r_short) = couple_to_deposit(chosen.r_long, initial_deposit);
     let lambda_q96 = chosen.lambda_q96;
     let sqrt_price_long_x96 = chosen.sqrt_price_long_x96;
     let sqrt_price_short_x96 = chosen.sqrt_price_short_x96;
 
-    // Verify reserves are close to initial deposit (within 0.01%)
-    // We accept small rounding errors rather than adjusting reserves,
-    // which would violate the r_i = s_i × p_i invariant
-    let r_sum = (r_long as u128).checked_add(r_short as u128)
+    // Verify the candidate's on-manifold reserves are close to the deposit (within 0.01%)
+    // before SHORT absorbs the rounding dust
+    let r_sum = (chosen.r_long as u128).checked_add(chosen.r_short as u128)
         .ok_or(ContentPoolError::NumericalOverflow)?;
     let deposit_u128 = initial_deposit as u128;
 
@@ -422,11 +382,10 @@ pub fn handler(
          r_sum, deposit_u128, diff);
 
     // Update pool state
-    let pool = &mut ctx.accounts.pool;
-    pool.market_deployer = ctx.accounts.deployer.key();
-    pool.long_mint = ctx.accounts.long_mint.key();
-    pool.short_mint = ctx.accounts.short_mint.key();
-    pool.vault = ctx.accounts.vault.key();
+    pool.market_deployer = infos.deployer.key();
+    pool.long_mint = infos.long_mint.key();
+    pool.short_mint = infos.short_mint.key();
+    pool.vault = infos.vault.key();
     pool.s_long = s_long;
     pool.s_short = s_short;
     pool.r_long = r_long;
@@ -444,48 +403,486 @@ pub fn handler(
     pool.sqrt_price_short_x96 = sqrt_price_short_x96;
 
     // initial q from reserves (on-manifold), not from USDC split
-    // Use actual r_sum for consistency (may differ from initial_deposit by a few µUSDC)
-    let initial_q_bps = if r_sum > 0 {
-        ((r_long as u128) * 10_000u128 / r_sum) as u64
-    } else {
-        5_000
-    };
-    pool.initial_q = ((initial_q_bps as u128) * (Q32_ONE as u128) / 10_000u128) as u64;
+    pool.initial_q = initial_q_from_reserves(r_long as u128, initial_deposit as u128);
 
-    // Store the actual sum of reserves as vault_balance for consistency
-    // This may differ from initial_deposit by a few µUSDC due to rounding
-    pool.vault_balance = r_sum as u64;
+    // vault_balance is what the vault holds: the full deposit, which the reserves now sum to
+    pool.vault_balance = initial_deposit;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    pool.deployed_at = timestamp;
 
     // Emit event
     emit!(MarketDeployedEvent {
         pool: pool.key(),
-        deployer: ctx.accounts.deployer.key(),
+        deployer: infos.deployer.key(),
+        token_recipient: infos.token_recipient.key(),
         initial_deposit,
         long_allocation,
         short_allocation,
         initial_q: pool.initial_q,
         long_tokens: s_long,
         short_tokens: s_short,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp,
     });
 
     Ok(())
 }
 
-/// Integer square root using Newton's method
-fn integer_sqrt(n: u128) -> Result<u128> {
-    if n == 0 {
-        return Ok(0);
+/// Reserves coupled to the deposit the vault holds: (r_long, deposit - r_long)
+///
+/// The candidate's reserves are floored per side and miss the deposit by a few µUSDC on
+/// most splits. SHORT absorbs the dust, as trade does, so r_long + r_short ==
+/// vault_balance == vault.amount and settle_epoch's vault check passes from the start.
+fn couple_to_deposit(r_long: u64, initial_deposit: u64) -> (u64, u64) {
+    let r_long = r_long.min(initial_deposit);
+    (r_long, initial_deposit - r_long)
+}
+
+/// Base supplies (floor) from √allocation: s_i0 = √(a_i · a_ref) / p0
+///
+/// Both must be non-zero and leave room for the +1 candidate under S_DISPLAY_CAP;
+/// a tiny p0 with a large deposit would otherwise mint past the cap.
+fn base_supplies(long_allocation: u64, short_allocation: u64, p0: u64) -> Result<(u128, u128)> {
+    require!(
+        p0 > 0,
+        ContentPoolError::InvalidP0
+    );
+
+    let a_l: u128 = long_allocation as u128;
+    let a_s: u128 = short_allocation as u128;
+    let a_ref: u128 = a_l.max(a_s);
+
+    let s_l0 = integer_sqrt(
+        a_l.checked_mul(a_ref)
+            .ok_or(ContentPoolError::NumericalOverflow)?
+    )? / p0 as u128;
+
+    let s_s0 = integer_sqrt(
+        a_s.checked_mul(a_ref)
+            .ok_or(ContentPoolError::NumericalOverflow)?
+    )? / p0 as u128;
+
+    require!(
+        s_l0 > 0 && s_s0 > 0,
+        ContentPoolError::InvalidAllocation
+    );
+
+    if s_l0.max(s_s0) >= S_DISPLAY_CAP as u128 {
+        msg!(
+            "deploy_market: base supplies s_long={}, s_short={} exceed S_DISPLAY_CAP={} (p0={})",
+            s_l0, s_s0, S_DISPLAY_CAP, p0
+        );
+        return err!(ContentPoolError::SupplyOverflow);
+    }
+
+    Ok((s_l0, s_s0))
+}
+
+/// SHORT allocation for a deposit split, rejecting an empty side
+///
+/// LONG at or above the deposit is checked first so deployers get a precise error
+/// for the common mistake of passing the total as the LONG share.
+fn split_allocation(initial_deposit: u64, long_allocation: u64) -> Result<u64> {
+    require!(
+        long_allocation < initial_deposit,
+        ContentPoolError::LongAllocationExceedsDeposit
+    );
+    require!(
+        long_allocation > 0,
+        ContentPoolError::InvalidAllocation
+    );
+    Ok(initial_deposit - long_allocation)
+}
+
+/// Reject a second deployment, telling a retried success apart from a competing deployer
+///
+/// Mints and vault are init_if_needed so a resubmitted deploy reaches this check
+/// instead of failing on account creation.
+fn check_not_deployed(market_deployer: Pubkey, deployer: Pubkey) -> Result<()> {
+    if market_deployer == Pubkey::default() {
+        return Ok(());
+    }
+    msg!("Market already deployed by {}", market_deployer);
+    if market_deployer == deployer {
+        return err!(ContentPoolError::MarketAlreadyDeployedBySelf);
+    }
+    err!(ContentPoolError::MarketAlreadyDeployed)
+}
+
+/// Initial q (Q32.32) from deployed reserves
+///
+/// Clamped to [0.1%, 99.9%] so extreme allocations never start a pool at exactly
+/// 0 or Q32_ONE, which downstream readers treat as degenerate.
+pub(super) fn initial_q_from_reserves(r_long: u128, r_sum: u128) -> u64 {
+    let initial_q_bps = if r_sum > 0 {
+        (r_long * 10_000u128 / r_sum) as u64
+    } else {
+        5_000
+    };
+    let q = ((initial_q_bps as u128) * (Q32_ONE as u128) / 10_000u128) as u64;
+    q.clamp(Q32_MIN_INITIAL_Q, Q32_MAX_INITIAL_Q)
+}
+
+/// Deployment candidate: integer supplies with on-manifold prices and reserves
+#[derive(Debug, Clone, PartialEq)]
+struct Candidate {
+    s_long: u64,
+    s_short: u64,
+    lambda_q96: u128,  // λ in Q96 format (NOT sqrt!)
+    sqrt_price_long_x96: u128,
+    sqrt_price_short_x96: u128,
+    r_long: u64,
+    r_short: u64,
+    ratio_error: u128,
+}
+
+impl Candidate {
+    /// Deterministic ordering: lower ratio error, then lower total supply
+    /// (closest to the √allocation base), then lower s_long.
+    fn better_than(&self, other: &Candidate) -> bool {
+        let key = |c: &Candidate| (c.ratio_error, c.s_long as u128 + c.s_short as u128, c.s_long);
+        key(self) < key(other)
     }
+}
+
+/// Evaluate one (s_L, s_S) candidate: exact deploy prices, λ, reserves and ratio error
+fn evaluate_candidate(
+    s_l_cand: u128,
+    s_s_cand: u128,
+    initial_deposit: u64,
+    a_l: u128,
+    a_s: u128,
+) -> Result<Candidate> {
+    let s_l_u64 = s_l_cand as u64;
+    let s_s_u64 = s_s_cand as u64;
+
+    // ---------- OPTION A: exact deploy prices from deposit identity ----------
+    // Geometry (F=1, β=0.5):
+    //   p_i = D * s_i / (s_L^2 + s_S^2)
+    // Do it fully in Q96 without any sqrt(||s||) so we avoid floor bias.
+
+    let n2 = s_l_cand
+        .checked_mul(s_l_cand)
+        .ok_or(ContentPoolError::NumericalOverflow)?
+        .checked_add(
+            s_s_cand
+                .checked_mul(s_s_cand)
+                .ok_or(ContentPoolError::NumericalOverflow)?,
+        )
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+
+    // Q96 scale: d_over_n2_q96 = (D * Q96) / (s_L^2 + s_S^2)
+    let d_over_n2_q96 = mul_div_u128(initial_deposit as u128, Q96, n2)?;
+
+    // p_i in Q96: p_i = d_over_n2_q96 * s_i
+    // Use checked_mul to keep 256-bit intermediate and avoid u128 overflow.
+    let p_long_q96 = d_over_n2_q96
+        .checked_mul(s_l_cand)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let p_short_q96 = d_over_n2_q96
+        .checked_mul(s_s_cand)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+
+    // sqrt_price_i_x96 = sqrt(p_i_q96) << 48   (so that (sqrt_price >>48)^2 is Q96)
+    let sqrt_price_long_x96 = integer_sqrt(p_long_q96)?
+        .checked_shl(48)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let sqrt_price_short_x96 = integer_sqrt(p_short_q96)?
+        .checked_shl(48)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+
+    // Make λ consistent with curve: p_i = λ * s_i / ||s|| with *your* integer ||s||.
+    // We compute ||s|| as integer sqrt (same as the runtime curve will do).
+    let s_norm_int = integer_sqrt(n2)?.max(1);
+
+    // λ in Q96 from each side, then take max to cover any ulp asymmetry.
+    let lambda_q96_from_long  = mul_div_u128(p_long_q96,  s_norm_int, s_l_cand)?;
+    let lambda_q96_from_short = mul_div_u128(p_short_q96, s_norm_int, s_s_cand)?;
+    let lambda_x96 = lambda_q96_from_long.max(lambda_q96_from_short);
+
+    // Reserves: r_i = (p_i_q96 * s_i) >> 96
+    let r_long  = mul_shift_right_96(p_long_q96,  s_l_cand)?  as u64;
+    let r_short = mul_shift_right_96(p_short_q96, s_s_cand)? as u64;
+    // ---------- end OPTION A block ----------
+
+    // Score by reserve ratio error: minimize |r_long * A_S - r_short * A_L|
+    let cross_l = (r_long as u128).checked_mul(a_s)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let cross_s = (r_short as u128).checked_mul(a_l)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let ratio_error = if cross_l > cross_s {
+        cross_l - cross_s
+    } else {
+        cross_s - cross_l
+    };
+
+    Ok(Candidate {
+        s_long: s_l_u64,
+        s_short: s_s_u64,
+        lambda_q96: lambda_x96,
+        sqrt_price_long_x96,
+        sqrt_price_short_x96,
+        r_long,
+        r_short,
+        ratio_error,
+    })
+}
+
+/// Candidate search over the base supplies and the smaller side bumped by +1
+///
+/// The winner is picked by `Candidate::better_than`, so ties on ratio_error go to
+/// the lower total supply regardless of evaluation order.
+///
+/// Exits early when the base candidate already hits the allocation ratio exactly
+/// (ratio_error == 0): the bumped candidate has a larger total supply and cannot
+/// win, so the result is identical and the second evaluation's CUs are saved.
+// map_or rather than is_none_or, which the Solana platform toolchain predates
+#[allow(clippy::unnecessary_map_or)]
+fn select_candidate(
+    s_l0: u128,
+    s_s0: u128,
+    initial_deposit: u64,
+    a_l: u128,
+    a_s: u128,
+) -> Result<Candidate> {
+    let mut best: Option<Candidate> = None;
+    // Only try base + bump smaller side by +1 (2 candidates to save CUs)
+    let candidates = if s_l0 >= s_s0 {
+        [(s_l0, s_s0), (s_l0, s_s0 + 1)]
+    } else {
+        [(s_l0, s_s0), (s_l0 + 1, s_s0)]
+    };
 
-    let mut x = n;
-    let mut y = (x + 1) >> 1;
+    for &(s_l_cand, s_s_cand) in &candidates {
+        let candidate = evaluate_candidate(s_l_cand, s_s_cand, initial_deposit, a_l, a_s)?;
+        let is_exact = candidate.ratio_error == 0;
 
-    while y < x {
-        x = y;
-        y = (x + n / x) >> 1;
+        if best.as_ref().map_or(true, |b| candidate.better_than(b)) {
+            best = Some(candidate);
+        }
+
+        if is_exact {
+            break;
+        }
     }
 
-    Ok(x)
+    best.ok_or(ContentPoolError::InvalidParameter.into())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Full search without the early exit (reference behaviour)
+    #[allow(clippy::unnecessary_map_or)]
+    fn select_candidate_exhaustive(
+        s_l0: u128,
+        s_s0: u128,
+        initial_deposit: u64,
+        a_l: u128,
+        a_s: u128,
+    ) -> Candidate {
+        let candidates = if s_l0 >= s_s0 {
+            [(s_l0, s_s0), (s_l0, s_s0 + 1)]
+        } else {
+            [(s_l0, s_s0), (s_l0 + 1, s_s0)]
+        };
+        let mut best: Option<Candidate> = None;
+        for &(s_l, s_s) in &candidates {
+            let c = evaluate_candidate(s_l, s_s, initial_deposit, a_l, a_s).unwrap();
+            if best.as_ref().map_or(true, |b| c.better_than(b)) {
+                best = Some(c);
+            }
+        }
+        best.unwrap()
+    }
+
+    #[test]
+    fn test_deploy_params_with_only_required_fields() {
+        let params = DeployParams::new(100_000_000);
+        assert_eq!(params.long_allocation, None);
+        assert_eq!(params.long_allocation(), 50_000_000);
+
+        // Same supplies as passing the even split explicitly
+        let short = split_allocation(params.initial_deposit, params.long_allocation()).unwrap();
+        assert_eq!(short, 50_000_000);
+        let explicit = DeployParams { long_allocation: Some(50_000_000), ..params };
+        assert_eq!(explicit.long_allocation(), params.long_allocation());
+        assert_eq!(base_supplies(params.long_allocation(), short, 1_000_000).unwrap(), (50, 50));
+
+        // An odd deposit rounds LONG down; SHORT takes the remainder
+        let odd = DeployParams::new(100_000_001);
+        assert_eq!(split_allocation(odd.initial_deposit, odd.long_allocation()).unwrap(), 50_000_001);
+    }
+
+    #[test]
+    fn test_asymmetric_deploy_is_settleable() {
+        use crate::content_pool::settlement::{market_prediction_q, settlement_factors, scaled_reserves};
+
+        // The settlement suite's pool: 200 USDC, 80 USDC LONG at p0 = 1 USDC
+        let deposit = 200_000_000u64;
+        let (a_l, a_s) = (80_000_000u64, 120_000_000u64);
+        let (s_l0, s_s0) = base_supplies(a_l, a_s, 1_000_000).unwrap();
+        let chosen = select_candidate(s_l0, s_s0, deposit, a_l as u128, a_s as u128).unwrap();
+        assert_ne!(chosen.r_long + chosen.r_short, deposit, "expected floor dust on this split");
+
+        // Stored reserves sum to the deposit, which is vault_balance and vault.amount
+        let (r_long, r_short) = couple_to_deposit(chosen.r_long, deposit);
+        assert_eq!(r_long, chosen.r_long);
+        assert_eq!(r_long + r_short, deposit);
+
+        // settle_epoch: vault.amount == vault_balance holds, and settling keeps the coupling
+        let vault_balance = deposit;
+        let q = market_prediction_q(r_long, r_short);
+        let (f_long, f_short) = settlement_factors(600_000, q);
+        let (r_long, r_short) = scaled_reserves(r_long, r_short, vault_balance, 600_000, f_long, f_short).unwrap();
+        assert_eq!(r_long + r_short, deposit);
+        assert!(market_prediction_q(r_long, r_short).abs_diff(600_000) <= 1);
+
+        // Odd deposit at 50/50
+        let (s_l0, s_s0) = base_supplies(50_000_000, 50_000_001, 1_000_000).unwrap();
+        let chosen = select_candidate(s_l0, s_s0, 100_000_001, 50_000_000, 50_000_001).unwrap();
+        let (r_long, r_short) = couple_to_deposit(chosen.r_long, 100_000_001);
+        assert_eq!(r_long + r_short, 100_000_001);
+    }
+
+    #[test]
+    fn test_early_exit_matches_full_search_on_exact_allocation() {
+        // 100 USDC split 50/50 at p0 = 1 USDC → s = (50, 50), reserves exactly 50/50
+        let deposit = 100_000_000u64;
+        let (a_l, a_s) = (50_000_000u128, 50_000_000u128);
+        let (s_l0, s_s0) = (50u128, 50u128);
+
+        let base = evaluate_candidate(s_l0, s_s0, deposit, a_l, a_s).unwrap();
+        assert_eq!(base.ratio_error, 0, "allocation should be exactly divisible");
+
+        let early = select_candidate(s_l0, s_s0, deposit, a_l, a_s).unwrap();
+        let full = select_candidate_exhaustive(s_l0, s_s0, deposit, a_l, a_s);
+        assert_eq!(early, full);
+        assert_eq!(early, base);
+        assert_eq!(early.r_long + early.r_short, deposit);
+    }
+
+    #[test]
+    fn test_search_matches_full_search_on_inexact_allocation() {
+        // 60/40 split does not land exactly on integer supplies → both candidates evaluated
+        let deposit = 100_000_000u64;
+        let (a_l, a_s) = (60_000_000u128, 40_000_000u128);
+        let a_ref = a_l.max(a_s);
+        let s_l0 = integer_sqrt(a_l * a_ref).unwrap() / 1_000_000;
+        let s_s0 = integer_sqrt(a_s * a_ref).unwrap() / 1_000_000;
+
+        let searched = select_candidate(s_l0, s_s0, deposit, a_l, a_s).unwrap();
+        let full = select_candidate_exhaustive(s_l0, s_s0, deposit, a_l, a_s);
+        assert_eq!(searched, full);
+    }
+
+    #[test]
+    fn test_tie_on_ratio_error_prefers_lower_total_supply() {
+        // Base (60, 40) and bumped (60, 41) at D = 100 USDC. Choosing A_L:A_S as the
+        // sum of both candidates' reserves puts them on opposite sides of the target
+        // ratio at exactly the same distance.
+        let deposit = 100_000_000u64;
+        let (s_l0, s_s0) = (60u128, 40u128);
+        let base_r = evaluate_candidate(s_l0, s_s0, deposit, 1, 1).unwrap();
+        let bump_r = evaluate_candidate(s_l0, s_s0 + 1, deposit, 1, 1).unwrap();
+        let a_l = (base_r.r_long + bump_r.r_long) as u128;
+        let a_s = (base_r.r_short + bump_r.r_short) as u128;
+
+        let base = evaluate_candidate(s_l0, s_s0, deposit, a_l, a_s).unwrap();
+        let bumped = evaluate_candidate(s_l0, s_s0 + 1, deposit, a_l, a_s).unwrap();
+        assert_eq!(base.ratio_error, bumped.ratio_error);
+        assert!(base.ratio_error > 0, "tie must not short-circuit on an exact hit");
+
+        // Winner is independent of evaluation order
+        assert!(base.better_than(&bumped));
+        assert!(!bumped.better_than(&base));
+
+        let chosen = select_candidate(s_l0, s_s0, deposit, a_l, a_s).unwrap();
+        assert_eq!(chosen, base);
+        assert_eq!(chosen, select_candidate_exhaustive(s_l0, s_s0, deposit, a_l, a_s));
+    }
+
+    #[test]
+    fn test_initial_q_clamped_on_extreme_allocation() {
+        // 99.9% LONG allocation: q lands at or inside the upper bound
+        let deposit = 100_000_000u64;
+        let (a_l, a_s) = (99_900_000u128, 100_000u128);
+        let a_ref = a_l.max(a_s);
+        let s_l0 = integer_sqrt(a_l * a_ref).unwrap() / 1_000_000;
+        let s_s0 = integer_sqrt(a_s * a_ref).unwrap() / 1_000_000;
+        let chosen = select_candidate(s_l0, s_s0, deposit, a_l, a_s).unwrap();
+        let r_sum = chosen.r_long as u128 + chosen.r_short as u128;
+        let q = initial_q_from_reserves(chosen.r_long as u128, r_sum);
+        assert!((Q32_MIN_INITIAL_Q..=Q32_MAX_INITIAL_Q).contains(&q), "q = {}", q);
+
+        // All reserves on one side: clamped away from exactly Q32_ONE / 0
+        assert_eq!(initial_q_from_reserves(1_000, 1_000), Q32_MAX_INITIAL_Q);
+        assert_eq!(initial_q_from_reserves(0, 1_000), Q32_MIN_INITIAL_Q);
+        // 99.95% rounds past the bound and is clamped
+        assert_eq!(initial_q_from_reserves(9_995, 10_000), Q32_MAX_INITIAL_Q);
+
+        // Balanced pools are unaffected
+        assert_eq!(initial_q_from_reserves(500, 1_000), Q32_ONE / 2);
+        assert_eq!(initial_q_from_reserves(0, 0), Q32_ONE / 2);
+    }
+
+    #[test]
+    fn test_base_supplies_rejected_above_display_cap() {
+        // p0 = 1 µUSDC with a 4M USDC deposit: √(2e12 · 2e12) / 1 = 2e12 > S_DISPLAY_CAP
+        let half = 2_000_000_000_000u64;
+        assert_eq!(
+            base_supplies(half, half, 1).unwrap_err(),
+            ContentPoolError::SupplyOverflow.into()
+        );
+
+        // Exactly at the cap leaves no room for the +1 candidate
+        let at_cap = S_DISPLAY_CAP;
+        assert!(base_supplies(at_cap, at_cap, 1).is_err());
+
+        // Default p0 keeps the same deposit far below the cap
+        let (s_l0, s_s0) = base_supplies(half, half, 1_000_000).unwrap();
+        assert_eq!((s_l0, s_s0), (2_000_000, 2_000_000));
+    }
+
+    #[test]
+    fn test_base_supplies_zero_p0() {
+        assert_eq!(
+            base_supplies(50_000_000, 50_000_000, 0).unwrap_err(),
+            ContentPoolError::InvalidP0.into()
+        );
+    }
+
+    #[test]
+    fn test_split_allocation() {
+        assert_eq!(split_allocation(100_000_000, 40_000_000).unwrap(), 60_000_000);
+        assert_eq!(
+            split_allocation(100_000_000, 100_000_001).unwrap_err(),
+            ContentPoolError::LongAllocationExceedsDeposit.into()
+        );
+        assert_eq!(
+            split_allocation(100_000_000, 100_000_000).unwrap_err(),
+            ContentPoolError::LongAllocationExceedsDeposit.into()
+        );
+        assert_eq!(
+            split_allocation(100_000_000, 0).unwrap_err(),
+            ContentPoolError::InvalidAllocation.into()
+        );
+    }
+
+    #[test]
+    fn test_redeploy_distinguishes_self_from_competitor() {
+        let me = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        assert!(check_not_deployed(Pubkey::default(), me).is_ok());
+        assert_eq!(
+            check_not_deployed(me, me).unwrap_err(),
+            ContentPoolError::MarketAlreadyDeployedBySelf.into()
+        );
+        assert_eq!(
+            check_not_deployed(other, me).unwrap_err(),
+            ContentPoolError::MarketAlreadyDeployed.into()
+        );
+    }
+}
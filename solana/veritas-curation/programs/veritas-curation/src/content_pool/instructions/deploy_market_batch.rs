@@ -0,0 +1,232 @@
+//! Batch deployment for seeding a catalog of markets at genesis
+//!
+//! Runs deploy_market's logic for up to MAX_BATCH_DEPLOY pools in one transaction.
+//! Each deployment creates two mints and a vault (PDAs, created here), up to two
+//! deployer ATAs, one USDC transfer and two mint_to CPIs - roughly the cost of a
+//! standalone deploy_market. Request the 1.4M CU maximum for a full batch and
+//! simulate first; the per-transaction account limit usually binds before compute.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Token, InitializeMint2, InitializeAccount3},
+};
+use anchor_spl::token::spl_token::{
+    state::{Account as SplAccount, Mint as SplMint},
+    solana_program::program_pack::Pack,
+};
+use crate::content_pool::{
+    state::*,
+    errors::ContentPoolError,
+};
+use crate::pool_factory::state::PoolFactory;
+use super::deploy_market::{deploy, DeployInfos, DeployParams};
+
+/// Shared accounts; per-pool accounts come through remaining_accounts as
+/// [pool, long_mint, short_mint, vault, deployer_long, deployer_short] per entry
+#[derive(Accounts)]
+pub struct DeployMarketBatch<'info> {
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    /// CHECK: Validated in deploy
+    #[account(mut)]
+    pub deployer_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in deploy
+    pub usdc_mint: UncheckedAccount<'info>,
+
+    pub deployer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeployMarketBatch<'info>>,
+    entries: Vec<DeployParams>,
+) -> Result<()> {
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_BATCH_DEPLOY,
+        ContentPoolError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * BATCH_DEPLOY_ACCOUNTS,
+        ContentPoolError::InvalidBatchSize
+    );
+
+    let accounts = &mut *ctx.accounts;
+    let rent = Rent::get()?;
+
+    for (entry, chunk) in entries
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(BATCH_DEPLOY_ACCOUNTS))
+    {
+        let [pool_info, long_mint, short_mint, vault, deployer_long, deployer_short] = chunk else {
+            return err!(ContentPoolError::InvalidBatchSize);
+        };
+
+        let mut pool: Account<'info, ContentPool> = Account::try_from(pool_info)?;
+        let content_id = pool.content_id;
+
+        // Mints and vault are PDAs of this program; create any that don't exist yet
+        // (a deployed pool keeps its accounts and is rejected inside deploy)
+        for (info, prefix) in [(long_mint, b"long_mint".as_ref()), (short_mint, b"short_mint".as_ref())] {
+            let bump = expect_pda(info, prefix, &content_id, ctx.program_id)?;
+            if info.data_is_empty() {
+                create_pda_account(
+                    &accounts.payer.to_account_info(),
+                    info,
+                    &accounts.system_program.to_account_info(),
+                    &rent,
+                    SplMint::LEN,
+                    &[prefix, content_id.as_ref(), &[bump]],
+                )?;
+                token::initialize_mint2(
+                    CpiContext::new(
+                        accounts.token_program.to_account_info(),
+                        InitializeMint2 { mint: info.clone() },
+                    ),
+                    TOKEN_DECIMALS,
+                    &pool.key(),
+                    None,
+                )?;
+            }
+        }
+
+        let vault_bump = expect_pda(vault, b"vault", &content_id, ctx.program_id)?;
+        if vault.data_is_empty() {
+            create_pda_account(
+                &accounts.payer.to_account_info(),
+                vault,
+                &accounts.system_program.to_account_info(),
+                &rent,
+                SplAccount::LEN,
+                &[b"vault", content_id.as_ref(), &[vault_bump]],
+            )?;
+            token::initialize_account3(CpiContext::new(
+                accounts.token_program.to_account_info(),
+                InitializeAccount3 {
+                    account: vault.clone(),
+                    mint: accounts.usdc_mint.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+            ))?;
+        }
+
+        let infos = DeployInfos {
+            long_mint: long_mint.clone(),
+            short_mint: short_mint.clone(),
+            vault: vault.clone(),
+            deployer_usdc: accounts.deployer_usdc.to_account_info(),
+            deployer_long: deployer_long.clone(),
+            deployer_short: deployer_short.clone(),
+            usdc_mint: accounts.usdc_mint.to_account_info(),
+            deployer: accounts.deployer.to_account_info(),
+            token_recipient: accounts.deployer.to_account_info(),
+            payer: accounts.payer.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+            associated_token_program: accounts.associated_token_program.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+        };
+        deploy(
+            &mut pool,
+            &accounts.factory,
+            &infos,
+            ctx.program_id,
+            entry.initial_deposit,
+            entry.long_allocation(),
+        )?;
+
+        // remaining_accounts are not serialized by Anchor
+        pool.exit(ctx.program_id)?;
+    }
+
+    Ok(())
+}
+
+/// Check `info` is the [prefix, content_id] PDA and return its bump
+fn expect_pda(
+    info: &AccountInfo,
+    prefix: &[u8],
+    content_id: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(&[prefix, content_id.as_ref()], program_id);
+    require!(info.key() == expected, ContentPoolError::InvalidParameter);
+    Ok(bump)
+}
+
+/// Create a token-program-owned PDA account, rent-exempt, signed with its seeds
+///
+/// Anyone can send lamports to a PDA before it exists, which makes create_account
+/// fail; an already-funded address is topped up, allocated and assigned instead,
+/// as Anchor's `init` does.
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    new_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &Rent,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let required = rent.minimum_balance(space);
+    let current = new_account.lamports();
+    if current == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+                &[seeds],
+            ),
+            required,
+            space as u64,
+            &token::ID,
+        );
+    }
+
+    let shortfall = required.saturating_sub(current);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: new_account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: new_account.clone(),
+            },
+            &[seeds],
+        ),
+        &token::ID,
+    )
+}
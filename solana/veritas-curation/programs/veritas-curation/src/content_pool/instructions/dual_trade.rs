@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::content_pool::{
+    state::{TokenSide, TradeType},
+    errors::ContentPoolError,
+};
+use super::trade::{self, *};
+
+/// Parameters for one leg of a dual_trade (same meaning as the `trade` arguments)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct TradeLeg {
+    pub side: TokenSide,
+    pub trade_type: TradeType,
+    pub amount: u64,
+    pub stake_skim: u64,
+    /// Buy slippage limit in atomic SPL units (display × 1e6)
+    pub min_tokens_out: u64,
+    pub min_usdc_out: u64,
+}
+
+/// Two full `Trade` account groups, flattened in order: every `leg_a` account
+/// (pool, factory, trader_usdc, vault, stake_vault, trader_tokens, token_mint,
+/// usdc_mint, trader, protocol_authority, payer, post_creator_usdc_account,
+/// protocol_treasury_usdc_account, token_program, associated_token_program,
+/// system_program, hold_record, skim_beneficiary), then the same eighteen for `leg_b`.
+///
+/// Shared accounts (factory, trader, payer, programs) are simply passed twice.
+#[derive(Accounts)]
+pub struct DualTrade<'info> {
+    pub leg_a: Trade<'info>,
+
+    #[account(
+        constraint = leg_b.pool.key() != leg_a.pool.key() @ ContentPoolError::InvalidParameter
+    )]
+    pub leg_b: Trade<'info>,
+}
+
+/// Execute two trades on two different pools atomically
+///
+/// Each leg runs the unmodified `trade` handler, so fees, skims, slippage checks
+/// and TradeEvents are identical to two separate `trade` calls. Any failure reverts both.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DualTrade<'info>>,
+    leg_a: TradeLeg,
+    leg_b: TradeLeg,
+) -> Result<()> {
+    let program_id = ctx.program_id;
+    let accounts = ctx.accounts;
+    let DualTradeBumps { leg_a: bumps_a, leg_b: bumps_b } = ctx.bumps;
+
+    run_leg(program_id, &mut accounts.leg_a, bumps_a, leg_a)?;
+
+    // Leg B's trader_usdc was deserialized before leg A spent from it
+    accounts.leg_b.trader_usdc.reload()?;
+
+    run_leg(program_id, &mut accounts.leg_b, bumps_b, leg_b)
+}
+
+fn run_leg<'info>(
+    program_id: &Pubkey,
+    accounts: &mut Trade<'info>,
+    bumps: TradeBumps,
+    leg: TradeLeg,
+) -> Result<()> {
+    trade::handler(
+        Context::new(program_id, accounts, &[], bumps),
+        leg.side,
+        leg.trade_type,
+        leg.amount,
+        leg.stake_skim,
+        MinTokensOut::Atomic(leg.min_tokens_out),
+        leg.min_usdc_out,
+    )
+}
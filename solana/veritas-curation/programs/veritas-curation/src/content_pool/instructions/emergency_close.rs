@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::pool_factory::{state::PoolFactory, errors::FactoryError};
+use crate::content_pool::{
+    state::ContentPool,
+    events::EmergencyCloseEvent,
+    errors::ContentPoolError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+#[derive(Accounts)]
+pub struct EmergencyClose<'info> {
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump,
+        constraint = !pool.closed @ ContentPoolError::PoolClosed
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == pool.vault @ ContentPoolError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = protocol_treasury_usdc.owner == factory.protocol_treasury @ ContentPoolError::InvalidProtocolTreasury,
+        constraint = protocol_treasury_usdc.mint == vault.mint @ ContentPoolError::InvalidMint
+    )]
+    pub protocol_treasury_usdc: Account<'info, TokenAccount>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Final shutdown of a wound-down pool
+///
+/// Refuses while any LONG or SHORT supply is outstanding: holders exit first through
+/// burn_and_refund while `wind_down` is set. What is left in the vault (rounding dust and
+/// untracked donations) is swept to the protocol treasury and the pool is marked closed,
+/// so trades, liquidity and settlement revert from here on. The pool account and mints
+/// are kept as the record.
+/// Only callable by upgrade authority (governance)
+pub fn handler(ctx: Context<EmergencyClose>) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    // Sweeping with supply outstanding would take holders' funds
+    require!(
+        pool.s_long == 0 && pool.s_short == 0,
+        ContentPoolError::PositionsStillOpen
+    );
+
+    let content_id = pool.content_id;
+    let bump = pool.bump;
+    let pool_seeds = &[
+        b"content_pool",
+        content_id.as_ref(),
+        &[bump],
+    ];
+
+    // Sweep everything actually in the vault, including any untracked donations
+    let swept_usdc = ctx.accounts.vault.amount;
+    if swept_usdc > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.protocol_treasury_usdc.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                &[pool_seeds],
+            ),
+            swept_usdc,
+        )?;
+    }
+
+    pool.vault_balance = 0;
+    pool.closed = true;
+
+    emit!(EmergencyCloseEvent {
+        pool: pool.key(),
+        swept_usdc,
+        protocol_treasury_usdc: ctx.accounts.protocol_treasury_usdc.key(),
+        closed_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("emergency_close: pool closed, vault swept to treasury");
+
+    Ok(())
+}
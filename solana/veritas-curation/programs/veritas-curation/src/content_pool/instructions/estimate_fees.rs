@@ -0,0 +1,55 @@
+//! View-only instruction: Projects fees for a given trading volume
+//!
+//! Does NOT mutate on-chain state - applies the pool's effective fee config
+//! (per-pool override or factory default) with the same math trades use.
+//! Used by: protocol revenue forecasting, creator earnings estimates
+
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::state::ContentPool;
+use crate::content_pool::errors::ContentPoolError;
+use super::trade::{apply_creator_fee_floor, calc_fees, effective_fee_config};
+
+#[derive(Accounts)]
+pub struct EstimateFees<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn handler(ctx: Context<EstimateFees>, volume_usdc: u64) -> Result<FeeEstimate> {
+    let (total_fee_bps, creator_split_bps) =
+        effective_fee_config(&ctx.accounts.pool, &ctx.accounts.factory);
+    let (total_fee, creator_fee, protocol_fee) = apply_creator_fee_floor(
+        calc_fees(volume_usdc, total_fee_bps, creator_split_bps)?,
+        creator_split_bps,
+        ctx.accounts.factory.creator_fee_floor,
+    );
+
+    Ok(FeeEstimate {
+        total_fee,
+        creator_fee,
+        protocol_fee,
+        total_fee_bps,
+        creator_split_bps,
+    })
+}
+
+/// Return type for estimate_fees view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FeeEstimate {
+    /// Total fee on the volume (µUSDC)
+    pub total_fee: u64,
+    /// Share paid to the post creator (µUSDC)
+    pub creator_fee: u64,
+    /// Share paid to the protocol treasury (µUSDC)
+    pub protocol_fee: u64,
+    /// Effective total fee in bps (override or factory)
+    pub total_fee_bps: u16,
+    /// Effective creator split in bps (override or factory)
+    pub creator_split_bps: u16,
+}
@@ -0,0 +1,133 @@
+//! View-only instruction: Snapshot of everything an indexer replays events to rebuild
+//!
+//! Does NOT mutate on-chain state - aggregates supplies, reserves, prices, derived λ, σ,
+//! epoch and fee config in one call, stamped with the slot it was read at.
+//! Used by: indexer cold-start (bootstrap from this snapshot, then tail events after `slot`)
+
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::ContentPool,
+    errors::ContentPoolError,
+};
+use super::get_current_state::relevance_and_prices;
+use super::trade::{effective_fee_config, virtual_supplies, lambda_q96_unchecked};
+
+#[derive(Accounts)]
+pub struct GetAllEventsState<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn handler(ctx: Context<GetAllEventsState>) -> Result<PoolSnapshot> {
+    let pool = &ctx.accounts.pool;
+    let factory = &ctx.accounts.factory;
+    let clock = Clock::get()?;
+
+    let (q, price_long, price_short) =
+        relevance_and_prices(pool.r_long, pool.r_short, pool.s_long, pool.s_short)?;
+
+    // Same λ trades derive, from tracked vault_balance; 0 if it cannot be derived
+    let lambda_q96 = virtual_supplies(pool)
+        .and_then(|(s_l, s_s)| lambda_q96_unchecked(pool.vault_balance, s_l, s_s))
+        .unwrap_or(0);
+
+    let (total_fee_bps, creator_split_bps) = effective_fee_config(pool, factory);
+
+    Ok(PoolSnapshot {
+        pool: pool.key(),
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        market_deployed: pool.market_deployer != Pubkey::default(),
+        s_long: pool.s_long,
+        s_short: pool.s_short,
+        r_long: pool.r_long,
+        r_short: pool.r_short,
+        vault_balance: pool.vault_balance,
+        q,
+        price_long,
+        price_short,
+        sqrt_price_long_x96: pool.sqrt_price_long_x96,
+        sqrt_price_short_x96: pool.sqrt_price_short_x96,
+        lambda_q96,
+        s_scale_long_q64: pool.s_scale_long_q64,
+        s_scale_short_q64: pool.s_scale_short_q64,
+        current_epoch: pool.current_epoch,
+        trade_seq: pool.trade_seq,
+        last_settle_ts: pool.last_settle_ts,
+        min_settle_interval: pool.min_settle_interval,
+        total_fee_bps,
+        creator_split_bps,
+        early_exit_fee_bps: pool.early_exit_fee_bps,
+        min_hold_secs: pool.min_hold_secs,
+        expiration_timestamp: pool.expiration_timestamp,
+        last_decay_update: pool.last_decay_update,
+    })
+}
+
+/// Return type for get_all_events_state view function
+///
+/// There is no fees-accrued figure: fees are paid to the creator and treasury inside
+/// each trade and never accrue in the pool, so indexers sum TradeFeeEvents for history.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolSnapshot {
+    /// Pool account address
+    pub pool: Pubkey,
+    /// Slot the snapshot was read at (tail events after this)
+    pub slot: u64,
+    /// Unix timestamp the snapshot was read at
+    pub timestamp: i64,
+    /// True once deploy_market has run
+    pub market_deployed: bool,
+    /// LONG supply in display tokens
+    pub s_long: u64,
+    /// SHORT supply in display tokens
+    pub s_short: u64,
+    /// LONG reserve (µUSDC)
+    pub r_long: u64,
+    /// SHORT reserve (µUSDC)
+    pub r_short: u64,
+    /// USDC tracked in the vault (µUSDC)
+    pub vault_balance: u64,
+    /// Relevance score in Q32 format
+    pub q: u64,
+    /// LONG price in micro-USDC per token (reserve / supply)
+    pub price_long: u64,
+    /// SHORT price in micro-USDC per token (reserve / supply)
+    pub price_short: u64,
+    /// sqrt(LONG price) in X96
+    pub sqrt_price_long_x96: u128,
+    /// sqrt(SHORT price) in X96
+    pub sqrt_price_short_x96: u128,
+    /// λ derived from vault_balance and virtual supplies (Q96, 0 if underivable)
+    pub lambda_q96: u128,
+    /// σ_L in Q64.64
+    pub s_scale_long_q64: u128,
+    /// σ_S in Q64.64
+    pub s_scale_short_q64: u128,
+    /// Settlements applied so far
+    pub current_epoch: u64,
+    /// Trades executed so far; the next TradeEvent carries trade_seq + 1
+    pub trade_seq: u64,
+    /// Last settlement timestamp (0 = never settled)
+    pub last_settle_ts: i64,
+    /// Cooldown between settlements (seconds)
+    pub min_settle_interval: i64,
+    /// Effective total trading fee in bps (override or factory default)
+    pub total_fee_bps: u16,
+    /// Effective creator share of the fee in bps
+    pub creator_split_bps: u16,
+    /// Early-exit fee in bps (0 = none)
+    pub early_exit_fee_bps: u16,
+    /// Holding period the early-exit fee applies within (seconds)
+    pub min_hold_secs: u32,
+    /// Expiration timestamp (0 = never expires)
+    pub expiration_timestamp: i64,
+    /// Last on-chain decay update (decay is not implemented on-chain)
+    pub last_decay_update: i64,
+}
@@ -0,0 +1,133 @@
+//! View-only instruction: Returns every meaningful ContentPool field as a versioned DTO
+//!
+//! Does NOT mutate on-chain state - skips padding and labels deprecated fields so
+//! lightweight clients get a stable, self-describing structure even as the
+//! on-chain account layout evolves.
+//! Used by: integrators, debugging tools
+
+use anchor_lang::prelude::*;
+use crate::content_pool::state::ContentPool;
+
+/// Bump when fields are added, removed or change meaning
+pub const POOL_FIELDS_VERSION: u8 = 1;
+
+#[derive(Accounts)]
+pub struct GetAllPoolFields<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+}
+
+pub fn handler(ctx: Context<GetAllPoolFields>) -> Result<PoolFields> {
+    let pool = &ctx.accounts.pool;
+
+    Ok(PoolFields {
+        version: POOL_FIELDS_VERSION,
+        pool: pool.key(),
+        content_id: pool.content_id,
+        creator: pool.creator,
+        market_deployer: pool.market_deployer,
+        post_creator: pool.post_creator,
+        long_mint: pool.long_mint,
+        short_mint: pool.short_mint,
+        vault: pool.vault,
+        stake_vault: pool.stake_vault,
+        factory: pool.factory,
+        f: pool.f,
+        beta_num: pool.beta_num,
+        beta_den: pool.beta_den,
+        fee_bps_override: pool.fee_override_bps(),
+        creator_split_override: pool.creator_split_override_bps(),
+        s_long: pool.s_long,
+        s_short: pool.s_short,
+        r_long: pool.r_long,
+        r_short: pool.r_short,
+        sqrt_price_long_x96: pool.sqrt_price_long_x96,
+        sqrt_price_short_x96: pool.sqrt_price_short_x96,
+        s_scale_long_q64: pool.s_scale_long_q64,
+        s_scale_short_q64: pool.s_scale_short_q64,
+        deprecated_lambda_long_q96: pool.lambda_long_q96,
+        deprecated_lambda_short_q96: pool.lambda_short_q96,
+        last_settle_ts: pool.last_settle_ts,
+        min_settle_interval: pool.min_settle_interval,
+        current_epoch: pool.current_epoch,
+        expiration_timestamp: pool.expiration_timestamp,
+        last_decay_update: pool.last_decay_update,
+        vault_balance: pool.vault_balance,
+        initial_q: pool.initial_q,
+        bump: pool.bump,
+    })
+}
+
+/// Return type for get_all_pool_fields view function
+/// Field order is part of the versioned contract - append only, then bump POOL_FIELDS_VERSION
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolFields {
+    /// DTO version (POOL_FIELDS_VERSION)
+    pub version: u8,
+    /// Pool account address
+    pub pool: Pubkey,
+    /// Post/belief identifier
+    pub content_id: Pubkey,
+    /// Pool creator (who called create_pool)
+    pub creator: Pubkey,
+    /// First trader who deployed the market (default pubkey until deployed)
+    pub market_deployer: Pubkey,
+    /// Post author who receives creator fees
+    pub post_creator: Pubkey,
+    /// LONG token mint
+    pub long_mint: Pubkey,
+    /// SHORT token mint
+    pub short_mint: Pubkey,
+    /// Pool USDC vault
+    pub vault: Pubkey,
+    /// Custodian stake vault
+    pub stake_vault: Pubkey,
+    /// PoolFactory that created this pool
+    pub factory: Pubkey,
+    /// Growth exponent F
+    pub f: u16,
+    /// β numerator
+    pub beta_num: u16,
+    /// β denominator
+    pub beta_den: u16,
+    /// Per-pool total fee in bps (None = factory default)
+    pub fee_bps_override: Option<u16>,
+    /// Per-pool creator split in bps (None = factory default)
+    pub creator_split_override: Option<u16>,
+    /// LONG supply in display tokens
+    pub s_long: u64,
+    /// SHORT supply in display tokens
+    pub s_short: u64,
+    /// LONG reserve (µUSDC)
+    pub r_long: u64,
+    /// SHORT reserve (µUSDC)
+    pub r_short: u64,
+    /// sqrt(LONG price) in X96
+    pub sqrt_price_long_x96: u128,
+    /// sqrt(SHORT price) in X96
+    pub sqrt_price_short_x96: u128,
+    /// σ_L in Q64.64
+    pub s_scale_long_q64: u128,
+    /// σ_S in Q64.64
+    pub s_scale_short_q64: u128,
+    /// DEPRECATED: telemetry only - λ is derived from vault + sigma scales
+    pub deprecated_lambda_long_q96: u128,
+    /// DEPRECATED: telemetry only - λ is derived from vault + sigma scales
+    pub deprecated_lambda_short_q96: u128,
+    /// Last settlement timestamp (0 = never settled)
+    pub last_settle_ts: i64,
+    /// Cooldown between settlements (seconds)
+    pub min_settle_interval: i64,
+    /// Settlements applied so far
+    pub current_epoch: u64,
+    /// Expiration timestamp (0 = never expires)
+    pub expiration_timestamp: i64,
+    /// Last on-chain decay update (decay is not implemented on-chain)
+    pub last_decay_update: i64,
+    /// USDC tracked in the vault (µUSDC)
+    pub vault_balance: u64,
+    /// Initial q set at deployment (Q32.32)
+    pub initial_q: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
@@ -0,0 +1,68 @@
+//! View-only instruction: Returns a pool's lifetime creator fee earnings
+//!
+//! Does NOT mutate on-chain state - reads the cumulative_creator_fees accumulator so a
+//! dashboard need not sum TradeFeeEvents. Creator fees are transferred to the post
+//! creator inside each trade (there is no escrow), so everything earned is already paid
+//! out: claimed equals lifetime and claimable is always 0.
+//! Used by: creator dashboard
+
+use anchor_lang::prelude::*;
+use crate::content_pool::state::ContentPool;
+
+#[derive(Accounts)]
+pub struct GetCreatorEarnings<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+}
+
+pub fn handler(ctx: Context<GetCreatorEarnings>) -> Result<CreatorEarnings> {
+    Ok(creator_earnings(&ctx.accounts.pool))
+}
+
+fn creator_earnings(pool: &ContentPool) -> CreatorEarnings {
+    CreatorEarnings {
+        post_creator: pool.post_creator,
+        lifetime_creator_fees: pool.cumulative_creator_fees,
+        claimed: pool.cumulative_creator_fees,
+        claimable: 0,
+    }
+}
+
+/// Return type for get_creator_earnings view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreatorEarnings {
+    /// Account the creator fees are paid to
+    pub post_creator: Pubkey,
+    /// Creator fees paid by trades on this pool since v6 (µUSDC)
+    pub lifetime_creator_fees: u64,
+    /// Already in the creator's USDC account (µUSDC) - equal to lifetime_creator_fees
+    pub claimed: u64,
+    /// Held for the creator awaiting a claim (µUSDC) - 0 while fees are paid per trade
+    pub claimable: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::zeroed_pool;
+    use crate::content_pool::instructions::trade::record_creator_fee;
+
+    #[test]
+    fn test_creator_earnings_accumulate_per_trade() {
+        let mut pool = zeroed_pool();
+        pool.post_creator = Pubkey::new_unique();
+
+        for fee in [5_000, 0, 1, 12_345] {
+            record_creator_fee(&mut pool, fee);
+        }
+        let earnings = creator_earnings(&pool);
+        assert_eq!(earnings.post_creator, pool.post_creator);
+        assert_eq!(earnings.lifetime_creator_fees, 17_346);
+        assert_eq!(earnings.claimed, 17_346);
+        assert_eq!(earnings.claimable, 0);
+
+        // The counter saturates instead of failing a trade
+        record_creator_fee(&mut pool, u64::MAX);
+        assert_eq!(pool.cumulative_creator_fees, u64::MAX);
+    }
+}
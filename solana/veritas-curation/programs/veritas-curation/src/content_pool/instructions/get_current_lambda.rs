@@ -0,0 +1,49 @@
+//! View-only instruction: Returns the live λ derived from vault + σ
+//!
+//! Does NOT mutate on-chain state - replaces reads of the deprecated
+//! lambda_long_q96 / lambda_short_q96 fields with a freshly derived value.
+//! Used by: older clients that still price off λ / √λ
+
+use anchor_lang::prelude::*;
+use crate::content_pool::state::ContentPool;
+use crate::content_pool::settlement::isqrt_u128;
+use super::trade::{virtual_supplies, lambda_from_virtual};
+
+#[derive(Accounts)]
+pub struct GetCurrentLambda<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+}
+
+pub fn handler(ctx: Context<GetCurrentLambda>) -> Result<CurrentLambda> {
+    let pool = &ctx.accounts.pool;
+
+    // Same derivation as trades: λ = vault_balance / ||ŝ_v||
+    let (s_long_virtual, s_short_virtual) = virtual_supplies(pool)?;
+    let lambda_q96 = lambda_from_virtual(pool.vault_balance, s_long_virtual, s_short_virtual)?;
+
+    // √ of a Q96 value in Q96: isqrt(x_q96) << 48
+    let sqrt_lambda_x96 = isqrt_u128(lambda_q96)? << 48;
+
+    msg!("lambda_long_q96/lambda_short_q96 are deprecated telemetry; use get_current_lambda");
+
+    Ok(CurrentLambda {
+        lambda_q96,
+        sqrt_lambda_x96,
+        stored_lambda_q96: pool.lambda_long_q96,
+        stored_is_stale: pool.lambda_long_q96 != lambda_q96,
+    })
+}
+
+/// Return type for get_current_lambda view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CurrentLambda {
+    /// λ in Q96, derived from vault_balance and current virtual supplies
+    pub lambda_q96: u128,
+    /// √λ in X96 format
+    pub sqrt_lambda_x96: u128,
+    /// DEPRECATED: value of the stored lambda_long_q96 telemetry field
+    pub stored_lambda_q96: u128,
+    /// True if the stored field differs from the live λ
+    pub stored_is_stale: bool,
+}
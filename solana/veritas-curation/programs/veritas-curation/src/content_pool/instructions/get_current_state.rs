@@ -6,6 +6,8 @@
 use anchor_lang::prelude::*;
 use crate::content_pool::state::{ContentPool, Q32_ONE};
 use crate::content_pool::errors::ContentPoolError;
+use crate::content_pool::curve::Q96;
+use crate::content_pool::math::mul_div_u128;
 
 #[derive(Accounts)]
 pub struct GetCurrentState<'info> {
@@ -14,13 +16,86 @@ pub struct GetCurrentState<'info> {
 }
 
 pub fn handler(ctx: Context<GetCurrentState>) -> Result<CurrentPoolState> {
-    let pool = &ctx.accounts.pool;
-    let current_time = Clock::get()?.unix_timestamp;
+    current_state(&ctx.accounts.pool)
+}
+
+fn current_state(pool: &ContentPool) -> Result<CurrentPoolState> {
+    // An undeployed pool has no market: report zeros rather than the 1.0 USDC / 50%
+    // defaults relevance_and_prices falls back to
+    if pool.market_deployer == Pubkey::default() {
+        return Ok(CurrentPoolState {
+            is_deployed: false,
+            r_long: 0,
+            r_short: 0,
+            q: 0,
+            price_long: 0,
+            price_short: 0,
+            s_long: 0,
+            s_short: 0,
+            sqrt_price_long_x96: 0,
+            sqrt_price_short_x96: 0,
+            avg_price_long_micro: 0,
+            avg_price_short_micro: 0,
+            marginal_price_long_micro: 0,
+            marginal_price_short_micro: 0,
+            days_expired: 0,
+            days_since_last_update: 0,
+            decay_pending: false,
+            expiration_timestamp: pool.expiration_timestamp,
+            last_decay_update: pool.last_decay_update,
+            created_at: pool.created_at,
+            deployed_at: pool.deployed_at,
+        });
+    }
 
     // Use actual reserves (no decay calculation)
     let r_long = pool.r_long;
     let r_short = pool.r_short;
 
+    let (q, price_long, price_short) = relevance_and_prices(r_long, r_short, pool.s_long, pool.s_short)?;
+
+    // Decay fields unused (kept for backward compatibility)
+    // Decay is not implemented on-chain (no decay crank), so there is nothing to preview:
+    // these always report zero and the returned reserves are the live reserves.
+    let days_expired = 0;
+    let days_since_last_update = 0;
+    let decay_pending = false;
+
+    Ok(CurrentPoolState {
+        is_deployed: true,
+        r_long,
+        r_short,
+        q,
+        price_long,
+        price_short,
+        s_long: pool.s_long,
+        s_short: pool.s_short,
+        sqrt_price_long_x96: pool.sqrt_price_long_x96,
+        sqrt_price_short_x96: pool.sqrt_price_short_x96,
+        avg_price_long_micro: avg_price_micro(r_long, pool.s_long),
+        avg_price_short_micro: avg_price_micro(r_short, pool.s_short),
+        marginal_price_long_micro: marginal_price_micro(pool.sqrt_price_long_x96)?,
+        marginal_price_short_micro: marginal_price_micro(pool.sqrt_price_short_x96)?,
+        days_expired,
+        days_since_last_update,
+        decay_pending,
+        expiration_timestamp: pool.expiration_timestamp,
+        last_decay_update: pool.last_decay_update,
+        created_at: pool.created_at,
+        deployed_at: pool.deployed_at,
+    })
+}
+
+/// Relevance q (Q32) and display prices (µUSDC per token) for a pair of reserves
+///
+/// Shared with get_all_events_state, which reports the same figures per pool.
+/// Returns (q, price_long, price_short)
+pub(crate) fn relevance_and_prices(
+    r_long: u64,
+    r_short: u64,
+    s_long: u64,
+    s_short: u64,
+) -> Result<(u64, u64, u64)> {
     // Calculate total reserves
     let total = (r_long as u128)
         .checked_add(r_short as u128)
@@ -40,63 +115,62 @@ pub fn handler(ctx: Context<GetCurrentState>) -> Result<CurrentPoolState> {
 
     // Calculate human-readable prices (micro-USDC per token)
     // price = reserve / supply
-    let price_long = if pool.s_long > 0 {
+    let price_long = if s_long > 0 {
         let price_u128 = (r_long as u128)
             .checked_mul(1_000_000) // Convert to micro-USDC
             .ok_or(ContentPoolError::NumericalOverflow)?
-            .checked_div(pool.s_long as u128)
+            .checked_div(s_long as u128)
             .ok_or(ContentPoolError::NumericalOverflow)?;
         price_u128 as u64
     } else {
         1_000_000 // 1.0 USDC default
     };
 
-    let price_short = if pool.s_short > 0 {
+    let price_short = if s_short > 0 {
         let price_u128 = (r_short as u128)
             .checked_mul(1_000_000)
             .ok_or(ContentPoolError::NumericalOverflow)?
-            .checked_div(pool.s_short as u128)
+            .checked_div(s_short as u128)
             .ok_or(ContentPoolError::NumericalOverflow)?;
         price_u128 as u64
     } else {
         1_000_000 // 1.0 USDC default
     };
 
-    // Decay fields unused (kept for backward compatibility)
-    let days_expired = 0;
-    let days_since_last_update = 0;
-    let decay_pending = false;
+    Ok((q, price_long, price_short))
+}
 
-    Ok(CurrentPoolState {
-        r_long,
-        r_short,
-        q,
-        price_long,
-        price_short,
-        s_long: pool.s_long,
-        s_short: pool.s_short,
-        sqrt_price_long_x96: pool.sqrt_price_long_x96,
-        sqrt_price_short_x96: pool.sqrt_price_short_x96,
-        days_expired,
-        days_since_last_update,
-        decay_pending,
-        expiration_timestamp: pool.expiration_timestamp,
-        last_decay_update: pool.last_decay_update,
-    })
+/// Average price of a side's holdings: reserve / supply (µUSDC per display token, 0 if empty)
+///
+/// What the side's tokens are worth on average, not what the next token costs.
+pub(crate) fn avg_price_micro(reserve: u64, supply: u64) -> u64 {
+    reserve.checked_div(supply).unwrap_or(0)
+}
+
+/// Marginal price decoded from a stored sqrt price: (√p · 2^96)² / 2^192 (µUSDC per display token)
+///
+/// What the next token costs. On the F = 1, β = 0.5 curve a side's reserve is its supply
+/// times this price, so it matches avg_price_micro while the sqrt price is current; a gap
+/// means the stored sqrt price is stale.
+pub(crate) fn marginal_price_micro(sqrt_price_x96: u128) -> Result<u64> {
+    let price_q96 = mul_div_u128(sqrt_price_x96, sqrt_price_x96, Q96)?;
+    u64::try_from(price_q96 / Q96).map_err(|_| ContentPoolError::NumericalOverflow.into())
 }
 
 /// Return type for get_current_state view function
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct CurrentPoolState {
-    /// Decayed LONG reserves (micro-USDC)
+    /// False for a created-but-undeployed pool; every market field is then 0
+    pub is_deployed: bool,
+    /// LONG reserves (micro-USDC)
     pub r_long: u64,
-    /// Decayed SHORT reserves (micro-USDC)
+    /// SHORT reserves (micro-USDC)
     pub r_short: u64,
     /// Relevance score in Q32 format (use q / Q32_ONE to get 0.0-1.0 value)
     pub q: u64,
-    /// LONG price in micro-USDC per token
+    /// Legacy LONG price: reserve × 1e6 / supply. Prefer avg_price_long_micro / marginal_price_long_micro
     pub price_long: u64,
-    /// SHORT price in micro-USDC per token
+    /// Legacy SHORT price: reserve × 1e6 / supply. Prefer avg_price_short_micro / marginal_price_short_micro
     pub price_short: u64,
     /// LONG supply (unchanged by decay)
     pub s_long: u64,
@@ -106,14 +180,95 @@ pub struct CurrentPoolState {
     pub sqrt_price_long_x96: u128,
     /// Square root of SHORT price in X96 format
     pub sqrt_price_short_x96: u128,
+    /// Average LONG price: r_long / s_long (µUSDC per token) - value of holdings, not a quote
+    pub avg_price_long_micro: u64,
+    /// Average SHORT price: r_short / s_short (µUSDC per token) - value of holdings, not a quote
+    pub avg_price_short_micro: u64,
+    /// Marginal LONG price from sqrt_price_long_x96 (µUSDC per token) - cost of the next token
+    pub marginal_price_long_micro: u64,
+    /// Marginal SHORT price from sqrt_price_short_x96 (µUSDC per token) - cost of the next token
+    pub marginal_price_short_micro: u64,
     /// Days since expiration (0 if not expired)
     pub days_expired: i64,
     /// Days since last on-chain decay update
     pub days_since_last_update: i64,
-    /// True if decay will be applied on next trade
+    /// True if an on-chain decay would change reserves (always false from get_current_state)
     pub decay_pending: bool,
     /// Timestamp when decay starts
     pub expiration_timestamp: i64,
     /// Timestamp of last on-chain decay execution
     pub last_decay_update: i64,
+    /// When the pool was created (0 for pools created before v4)
+    pub created_at: i64,
+    /// When the market was deployed; age for ranking is now - deployed_at (0 = not deployed,
+    /// or deployed before v4)
+    pub deployed_at: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::zeroed_pool;
+    use crate::content_pool::curve::ICBSCurve;
+    use crate::content_pool::state::{TokenSide, Q64};
+    use crate::content_pool::instructions::trade::lambda_from_virtual;
+
+    #[test]
+    fn test_undeployed_pool_reports_no_market() {
+        let mut pool = zeroed_pool();
+        pool.created_at = 1_700_000_000;
+
+        let state = current_state(&pool).unwrap();
+        assert!(!state.is_deployed);
+        assert_eq!((state.q, state.price_long, state.price_short), (0, 0, 0));
+        assert_eq!(state.created_at, 1_700_000_000);
+        assert_eq!(state.deployed_at, 0);
+
+        // Deployed: real q and prices
+        pool.market_deployer = Pubkey::new_unique();
+        pool.s_long = 1_000;
+        pool.s_short = 1_000;
+        pool.r_long = 50_000_000;
+        pool.r_short = 50_000_000;
+        let state = current_state(&pool).unwrap();
+        assert!(state.is_deployed);
+        assert_eq!(state.q, Q32_ONE / 2);
+        assert_eq!(state.price_long, 50_000 * 1_000_000);
+    }
+
+    #[test]
+    fn test_avg_and_marginal_prices_on_imbalanced_pool() {
+        // 100 USDC behind 3,000 LONG / 1,000 SHORT at σ = 1, reserves and prices on-curve
+        let (s_long, s_short) = (3_000u64, 1_000u64);
+        let lambda = lambda_from_virtual(100_000_000, s_long as u128, s_short as u128).unwrap();
+        let r_long = ICBSCurve::reserve_from_lambda_and_virtual(s_long, s_short, lambda).unwrap();
+        let r_short = 100_000_000 - r_long;
+        let sqrt_price = |side| {
+            ICBSCurve::sqrt_marginal_price_from_virtual(s_long, s_short, side, lambda, Q64, Q64, 1, 1, 2).unwrap()
+        };
+        let sqrt_long = sqrt_price(TokenSide::Long);
+        let sqrt_short = sqrt_price(TokenSide::Short);
+
+        // The heavier side is priced higher: λ·ŝ/||ŝ|| = 30,000 vs 10,000 µUSDC
+        let marginal_long = marginal_price_micro(sqrt_long).unwrap();
+        let marginal_short = marginal_price_micro(sqrt_short).unwrap();
+        assert!(marginal_long.abs_diff(30_000) <= 10, "marginal long {}", marginal_long);
+        assert!(marginal_short.abs_diff(10_000) <= 10, "marginal short {}", marginal_short);
+
+        // r = s · p on this curve, so the averages match the marginals up to the integer
+        // ||ŝ|| rounding (well under 0.5%)
+        let close = |avg: u64, marginal: u64| avg.abs_diff(marginal) * 200 <= marginal;
+        assert!(close(avg_price_micro(r_long, s_long), marginal_long));
+        assert!(close(avg_price_micro(r_short, s_short), marginal_short));
+
+        // The legacy field is the same average scaled by a further 1e6
+        let (_, legacy_long, _) = relevance_and_prices(r_long, r_short, s_long, s_short).unwrap();
+        assert_eq!(legacy_long / 1_000_000, avg_price_micro(r_long, s_long));
+
+        // A stale sqrt price (here a 2,000 / 2,000 one, 25,000 µUSDC) shows up as a gap
+        let lambda_even = lambda_from_virtual(100_000_000, 2_000, 2_000).unwrap();
+        let stale = ICBSCurve::sqrt_marginal_price_from_virtual(2_000, 2_000, TokenSide::Long, lambda_even, Q64, Q64, 1, 1, 2).unwrap();
+        assert!(!close(avg_price_micro(r_long, s_long), marginal_price_micro(stale).unwrap()));
+        assert_eq!(avg_price_micro(r_long, 0), 0);
+    }
 }
@@ -0,0 +1,48 @@
+//! View-only instruction: Returns epoch and settlement cooldown status
+//!
+//! Does NOT mutate on-chain state - lets a settlement scheduler plan
+//! settle_epoch calls instead of catching SettlementCooldown.
+
+use anchor_lang::prelude::*;
+use crate::content_pool::state::ContentPool;
+use crate::content_pool::settlement::seconds_until_settle_eligible;
+
+#[derive(Accounts)]
+pub struct GetEpochInfo<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+}
+
+pub fn handler(ctx: Context<GetEpochInfo>) -> Result<EpochInfo> {
+    let pool = &ctx.accounts.pool;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let seconds_until_eligible = seconds_until_settle_eligible(
+        pool.last_settle_ts,
+        pool.min_settle_interval,
+        current_time,
+    );
+
+    Ok(EpochInfo {
+        current_epoch: pool.current_epoch,
+        last_settle_ts: pool.last_settle_ts,
+        min_settle_interval: pool.min_settle_interval,
+        seconds_until_eligible,
+        is_eligible: seconds_until_eligible == 0,
+    })
+}
+
+/// Return type for get_epoch_info view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EpochInfo {
+    /// Pool's settlement epoch counter
+    pub current_epoch: u64,
+    /// Last settlement timestamp (0 if never settled)
+    pub last_settle_ts: i64,
+    /// Cooldown between settlements in seconds
+    pub min_settle_interval: i64,
+    /// Seconds until settle_epoch will pass the cooldown check (0 if eligible)
+    pub seconds_until_eligible: i64,
+    /// True if settle_epoch can be called now
+    pub is_eligible: bool,
+}
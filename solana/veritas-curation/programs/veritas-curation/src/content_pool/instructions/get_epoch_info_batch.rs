@@ -0,0 +1,81 @@
+//! View-only instruction: Epoch and cooldown status for many pools at once
+//!
+//! Does NOT mutate on-chain state - the batch form of get_epoch_info, so a settlement
+//! crank can plan a whole round in one simulated call instead of one per pool.
+//! Pools come through remaining_accounts; the answer is capped by MAX_EPOCH_INFO_BATCH
+//! because return data is limited to 1024 bytes.
+//! Used by: settlement scheduler
+
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::{ContentPool, MAX_EPOCH_INFO_BATCH},
+    errors::ContentPoolError,
+    settlement::seconds_until_settle_eligible,
+};
+
+/// Pools to read come through remaining_accounts, one ContentPool each
+#[derive(Accounts)]
+pub struct GetEpochInfoBatch<'info> {
+    /// Factory whose test-mode cooldown override settle_epoch applies
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, GetEpochInfoBatch<'info>>,
+) -> Result<Vec<PoolEpochEntry>> {
+    let pools = ctx.remaining_accounts;
+    require!(
+        !pools.is_empty() && pools.len() <= MAX_EPOCH_INFO_BATCH,
+        ContentPoolError::InvalidBatchSize
+    );
+
+    let factory = &ctx.accounts.factory;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    pools
+        .iter()
+        .map(|info| {
+            let pool: Account<'info, ContentPool> = Account::try_from(info)?;
+            require!(pool.factory == factory.key(), ContentPoolError::InvalidFactory);
+
+            Ok(PoolEpochEntry {
+                pool: pool.key(),
+                current_epoch: pool.current_epoch,
+                seconds_until_eligible: seconds_until_settle_eligible(
+                    pool.last_settle_ts,
+                    factory.effective_settle_interval(pool.min_settle_interval),
+                    current_time,
+                ),
+            })
+        })
+        .collect()
+}
+
+/// One pool's entry in the get_epoch_info_batch return value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolEpochEntry {
+    /// ContentPool address
+    pub pool: Pubkey,
+    /// Pool's settlement epoch counter
+    pub current_epoch: u64,
+    /// Seconds until settle_epoch will pass the cooldown check (0 if eligible)
+    pub seconds_until_eligible: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program::MAX_RETURN_DATA;
+
+    #[test]
+    fn test_full_batch_fits_return_data() {
+        let entry = PoolEpochEntry {
+            pool: Pubkey::new_unique(),
+            current_epoch: u64::MAX,
+            seconds_until_eligible: i64::MAX,
+        };
+        let batch = vec![entry; MAX_EPOCH_INFO_BATCH];
+        assert!(batch.try_to_vec().unwrap().len() <= MAX_RETURN_DATA);
+    }
+}
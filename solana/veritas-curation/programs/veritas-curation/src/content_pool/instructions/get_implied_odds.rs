@@ -0,0 +1,43 @@
+//! View-only instruction: Returns relevance as an implied probability
+//!
+//! Does NOT mutate on-chain state - packages q with the capital backing it.
+//! Used by: prediction-market UI ("62%, backed by $X")
+
+use anchor_lang::prelude::*;
+use crate::content_pool::state::ContentPool;
+use crate::content_pool::settlement::MICRO;
+
+#[derive(Accounts)]
+pub struct GetImpliedOdds<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+}
+
+pub fn handler(ctx: Context<GetImpliedOdds>) -> Result<ImpliedOdds> {
+    let pool = &ctx.accounts.pool;
+
+    // Sum in u128 so two large reserves cannot overflow u64
+    let total_reserves = pool.r_long as u128 + pool.r_short as u128;
+
+    // Same millionths scale as settle_epoch's q; 50/50 for an empty pool
+    let q_millionths = (pool.r_long as u128 * MICRO as u128)
+        .checked_div(total_reserves)
+        .map_or(MICRO / 2, |q| q as u64);
+
+    Ok(ImpliedOdds {
+        q_millionths,
+        total_reserves,
+        balanced_backing: pool.r_long.min(pool.r_short),
+    })
+}
+
+/// Return type for get_implied_odds view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ImpliedOdds {
+    /// Implied probability of LONG in millionths (1_000_000 = 100%)
+    pub q_millionths: u64,
+    /// r_long + r_short (micro-USDC)
+    pub total_reserves: u128,
+    /// Confidence proxy: min(r_long, r_short), the capital on the thinner side (micro-USDC)
+    pub balanced_backing: u64,
+}
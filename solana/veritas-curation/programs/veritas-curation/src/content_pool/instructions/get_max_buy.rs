@@ -0,0 +1,119 @@
+//! View-only instruction: Largest `amount` a buy on one side can pass to `trade`
+//!
+//! Does NOT mutate on-chain state - inverts the buy path (skim → fees → POL → curve) at
+//! the curve's range limit, the bound behind TradeTooLargeForCurve. The result includes
+//! the stake skim and every fee, so it can be passed to `trade` as is.
+//! Used by: trade form (cap the amount input), clients retrying an oversized buy
+
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::*,
+    errors::ContentPoolError,
+    curve::ICBSCurve,
+    math::renormalize_scales,
+};
+use super::quote_buy_exact_out::{curve_share, gross_up_for_fees};
+use super::trade::{effective_fee_config, virtual_supplies, lambda_from_virtual};
+
+#[derive(Accounts)]
+pub struct GetMaxBuy<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn handler(ctx: Context<GetMaxBuy>, side: TokenSide, stake_skim: u64) -> Result<MaxBuy> {
+    let pool = &ctx.accounts.pool;
+    require!(pool.market_deployer != Pubkey::default(), ContentPoolError::MarketNotDeployed);
+    max_buy(pool, &ctx.accounts.factory, side, stake_skim)
+}
+
+/// Largest buy whose curve share ICBSCurve::calculate_buy accepts
+///
+/// Same pre-curve state a buy sees: σ renormalized, then λ from vault_balance + virtual
+/// supplies. The creator fee floor only ever lowers the curve share, so it is ignored.
+pub(crate) fn max_buy(
+    pool: &ContentPool,
+    factory: &PoolFactory,
+    side: TokenSide,
+    stake_skim: u64,
+) -> Result<MaxBuy> {
+    let mut pool = pool.clone();
+    renormalize_scales(
+        &mut pool.s_scale_long_q64,
+        &mut pool.s_scale_short_q64,
+        pool.s_long,
+        pool.s_short,
+    );
+    let (s_long_virtual, s_short_virtual) = virtual_supplies(&pool)?;
+    let lambda_q96 = lambda_from_virtual(pool.vault_balance, s_long_virtual, s_short_virtual)?;
+
+    let (s_side_virtual, s_other_virtual) = match side {
+        TokenSide::Long => (s_long_virtual, s_short_virtual),
+        TokenSide::Short => (s_short_virtual, s_long_virtual),
+    };
+    let max_usdc_to_curve =
+        ICBSCurve::max_buy_usdc(s_side_virtual as u64, s_other_virtual as u64, lambda_q96)?;
+
+    let (total_fee_bps, _) = effective_fee_config(&pool, factory);
+    let after_skim = max_after_skim(max_usdc_to_curve, total_fee_bps, factory.pol_bps)?;
+
+    Ok(MaxBuy {
+        usdc_amount: after_skim.saturating_add(stake_skim),
+        max_usdc_to_curve,
+    })
+}
+
+/// Largest after-skim amount whose curve share stays within `max_usdc_to_curve`
+///
+/// gross_up_for_fees gives the smallest amount reaching it; one less fits when that
+/// overshoots, and the floors can let a few larger amounts land on it exactly.
+fn max_after_skim(max_usdc_to_curve: u64, total_fee_bps: u16, pol_bps: u16) -> Result<u64> {
+    let mut after_skim = match gross_up_for_fees(max_usdc_to_curve, total_fee_bps, pol_bps) {
+        Ok(after_skim) => after_skim,
+        // Reaching the limit takes more than u64 holds: any amount fits
+        Err(e) if e == ContentPoolError::NumericalOverflow.into() => return Ok(u64::MAX),
+        Err(e) => return Err(e),
+    };
+
+    if curve_share(after_skim, total_fee_bps, pol_bps)? > max_usdc_to_curve {
+        return Ok(after_skim - 1);
+    }
+    while after_skim < u64::MAX
+        && curve_share(after_skim + 1, total_fee_bps, pol_bps)? <= max_usdc_to_curve
+    {
+        after_skim += 1;
+    }
+    Ok(after_skim)
+}
+
+/// Return type for get_max_buy view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MaxBuy {
+    /// Largest `amount` to pass to `trade` (µUSDC, includes skim and fees)
+    pub usdc_amount: u64,
+    /// Curve share of usdc_amount at the limit (µUSDC, after fees and POL)
+    pub max_usdc_to_curve: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_after_skim_is_largest_fitting_amount() {
+        for (max_curve, fee_bps, pol_bps) in [(10_000_000u64, 50u16, 0u16), (10_000_000, 100, 1000), (123_457, 33, 250), (1, 50, 0)] {
+            let a = max_after_skim(max_curve, fee_bps, pol_bps).unwrap();
+            assert!(curve_share(a, fee_bps, pol_bps).unwrap() <= max_curve);
+            assert!(curve_share(a + 1, fee_bps, pol_bps).unwrap() > max_curve);
+        }
+
+        // A limit past what any u64 amount reaches leaves buys uncapped
+        assert_eq!(max_after_skim(u64::MAX, 100, 1000).unwrap(), u64::MAX);
+    }
+}
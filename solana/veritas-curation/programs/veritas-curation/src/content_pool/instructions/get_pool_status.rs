@@ -0,0 +1,95 @@
+//! View-only instruction: Returns pool readiness flags
+//!
+//! Does NOT mutate on-chain state - single authoritative readiness check.
+//! Used by: UI trade buttons, settlement scheduler
+
+use anchor_lang::prelude::*;
+use crate::content_pool::state::{ContentPool, S_DISPLAY_CAP};
+
+#[derive(Accounts)]
+pub struct GetPoolStatus<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+}
+
+pub fn handler(ctx: Context<GetPoolStatus>) -> Result<PoolStatus> {
+    Ok(pool_status(&ctx.accounts.pool, Clock::get()?.unix_timestamp))
+}
+
+/// Readiness flags for `pool` at `current_time`
+fn pool_status(pool: &ContentPool, current_time: i64) -> PoolStatus {
+    let is_deployed = pool.market_deployer != Pubkey::default();
+
+    // Trades revert if the vault is empty (λ derivation); buys also revert once a
+    // supply is at the display cap or the pool is winding down
+    let is_sellable = is_deployed && !pool.closed && pool.vault_balance > 0;
+    let within_caps = pool.s_long < S_DISPLAY_CAP && pool.s_short < S_DISPLAY_CAP;
+    let is_tradeable = is_sellable && within_caps && !pool.wind_down;
+
+    // expiration_timestamp = 0 means the pool never expires
+    let is_expired = pool.expiration_timestamp > 0 && current_time >= pool.expiration_timestamp;
+
+    PoolStatus {
+        is_deployed,
+        is_tradeable,
+        is_expired,
+        epochs_settled: pool.current_epoch,
+        is_closed: pool.closed,
+        is_sellable,
+        is_winding_down: pool.wind_down,
+    }
+}
+
+/// Return type for get_pool_status view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolStatus {
+    /// True once deploy_market has run
+    pub is_deployed: bool,
+    /// True if a buy can currently succeed (deployed, open, funded, below supply cap,
+    /// not winding down)
+    pub is_tradeable: bool,
+    /// True if the pool is past its expiration timestamp
+    pub is_expired: bool,
+    /// Number of settlements applied to this pool
+    pub epochs_settled: u64,
+    /// True once emergency_close has run (claims frozen, trading disabled)
+    pub is_closed: bool,
+    /// True if holders can currently sell (deployed, open, funded)
+    pub is_sellable: bool,
+    /// True while set_wind_down is on: buys disabled, sells fee-free
+    pub is_winding_down: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::balanced_pool;
+
+    #[test]
+    fn test_wind_down_blocks_buys_not_sells() {
+        let mut pool = balanced_pool();
+        let status = pool_status(&pool, 0);
+        assert!(status.is_tradeable && status.is_sellable && !status.is_winding_down);
+
+        pool.wind_down = true;
+        let status = pool_status(&pool, 0);
+        assert!(!status.is_tradeable);
+        assert!(status.is_sellable && status.is_winding_down);
+    }
+
+    #[test]
+    fn test_supply_cap_blocks_buys_only() {
+        let mut pool = balanced_pool();
+        pool.s_long = S_DISPLAY_CAP;
+        let status = pool_status(&pool, 0);
+        assert!(!status.is_tradeable && status.is_sellable);
+    }
+
+    #[test]
+    fn test_closed_pool_blocks_both() {
+        let mut pool = balanced_pool();
+        pool.closed = true;
+        let status = pool_status(&pool, 0);
+        assert!(!status.is_tradeable && !status.is_sellable && status.is_closed);
+    }
+}
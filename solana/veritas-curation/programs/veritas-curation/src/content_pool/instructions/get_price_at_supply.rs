@@ -0,0 +1,120 @@
+//! View-only instruction: Marginal price the curve quotes at hypothetical state
+//!
+//! Does NOT read or mutate any pool - evaluates the ICBS price for caller-supplied
+//! supplies, σ scales and λ (F = 1, β = 0.5, the only supported configuration).
+//! Used by: off-chain model validation, curve calibration research
+
+use anchor_lang::prelude::*;
+use crate::content_pool::{
+    state::{TokenSide, Q64, SIGMA_MIN, SIGMA_MAX},
+    errors::ContentPoolError,
+    curve::{ICBSCurve, Q96},
+    math::{ceil_div, mul_div_u128},
+};
+
+#[derive(Accounts)]
+pub struct GetPriceAtSupply {}
+
+pub fn handler(
+    _ctx: Context<GetPriceAtSupply>,
+    side: TokenSide,
+    s_long: u64,
+    s_short: u64,
+    sigma_long_q64: u128,
+    sigma_short_q64: u128,
+    lambda_q96: u128,
+) -> Result<PriceAtSupply> {
+    price_at_supply(side, s_long, s_short, (sigma_long_q64, sigma_short_q64), lambda_q96)
+}
+
+/// Evaluate the marginal price of `side` at display supplies (s_long, s_short)
+///
+/// Mirrors the pool math: ŝ = ⌈s·2^64/σ⌉ (min 1 when s > 0), then
+/// p_display = λ·ŝ_side / ||ŝ|| / σ_side.
+pub(crate) fn price_at_supply(
+    side: TokenSide,
+    s_long: u64,
+    s_short: u64,
+    (sigma_long_q64, sigma_short_q64): (u128, u128),
+    lambda_q96: u128,
+) -> Result<PriceAtSupply> {
+    require!(
+        (SIGMA_MIN..=SIGMA_MAX).contains(&sigma_long_q64)
+            && (SIGMA_MIN..=SIGMA_MAX).contains(&sigma_short_q64),
+        ContentPoolError::InvalidParameter
+    );
+
+    let to_virtual = |s: u64, sigma: u128| -> Result<u64> {
+        if s == 0 {
+            return Ok(0);
+        }
+        u64::try_from(ceil_div(s as u128 * Q64, sigma).max(1))
+            .map_err(|_| ContentPoolError::NumericalOverflow.into())
+    };
+    let s_long_virtual = to_virtual(s_long, sigma_long_q64)?;
+    let s_short_virtual = to_virtual(s_short, sigma_short_q64)?;
+
+    let sqrt_price_x96 = ICBSCurve::sqrt_marginal_price_from_virtual(
+        s_long_virtual,
+        s_short_virtual,
+        side,
+        lambda_q96,
+        sigma_long_q64,
+        sigma_short_q64,
+        1, 1, 2,
+    )?;
+
+    // (√p · 2^96)² / 2^96 = p · 2^96
+    let price_q96 = mul_div_u128(sqrt_price_x96, sqrt_price_x96, Q96)?;
+    let price_micro = u64::try_from(price_q96 / Q96)
+        .map_err(|_| ContentPoolError::NumericalOverflow)?;
+
+    Ok(PriceAtSupply {
+        sqrt_price_x96,
+        price_q96,
+        price_micro,
+        s_long_virtual,
+        s_short_virtual,
+    })
+}
+
+/// Return type for get_price_at_supply view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PriceAtSupply {
+    /// √(price) · 2^96, price in µUSDC per DISPLAY token (same format as sqrt_price_*_x96)
+    pub sqrt_price_x96: u128,
+    /// Price in µUSDC per display token, Q96 (squared from sqrt_price_x96)
+    pub price_q96: u128,
+    /// Price in µUSDC per display token, floored (1_000_000 = 1 USDC)
+    pub price_micro: u64,
+    /// LONG virtual supply ŝ_L used for the quote
+    pub s_long_virtual: u64,
+    /// SHORT virtual supply ŝ_S used for the quote
+    pub s_short_virtual: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::instructions::trade::lambda_from_virtual;
+
+    #[test]
+    fn test_price_at_balanced_supply() {
+        // 100 USDC backing 1,000 tokens per side at σ = 1: each side holds 50 USDC,
+        // so the marginal price is 50 USDC / 1,000 tokens = 0.05 USDC
+        let lambda = lambda_from_virtual(100_000_000, 1_000, 1_000).unwrap();
+        let quote = price_at_supply(TokenSide::Long, 1_000, 1_000, (Q64, Q64), lambda).unwrap();
+        assert_eq!((quote.s_long_virtual, quote.s_short_virtual), (1_000, 1_000));
+        assert!(quote.price_micro.abs_diff(50_000) <= 50, "price {}", quote.price_micro);
+
+        // Doubling σ on the quoted side halves its virtual supply and display price
+        let doubled = price_at_supply(TokenSide::Long, 2_000, 1_000, (2 * Q64, Q64), lambda).unwrap();
+        assert_eq!(doubled.s_long_virtual, 1_000);
+        assert!(doubled.price_micro.abs_diff(quote.price_micro / 2) <= 1);
+
+        // Empty side quotes zero; σ out of bounds is rejected
+        let empty = price_at_supply(TokenSide::Short, 1_000, 0, (Q64, Q64), lambda).unwrap();
+        assert_eq!(empty.price_micro, 0);
+        assert!(price_at_supply(TokenSide::Long, 1_000, 1_000, (0, Q64), lambda).is_err());
+    }
+}
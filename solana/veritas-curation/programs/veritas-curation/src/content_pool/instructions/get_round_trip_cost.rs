@@ -0,0 +1,136 @@
+//! View-only instruction: Cost of buying then immediately selling the tokens back
+//!
+//! Does NOT mutate on-chain state - quotes a buy of `usdc_amount` on a copy of the pool,
+//! then the sell of every token it minted, and reports what the trader would lose to
+//! fees, POL and curve convexity. Early-exit fees are included when the pool charges them.
+//! Used by: trade UIs ("you'll lose about X% to trade in and out")
+
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::*,
+    errors::ContentPoolError,
+};
+use super::simulate_buy_then_settle::{project_buy, project_sell};
+use super::trade::{apply_creator_fee_floor, calc_fees, calc_pol, early_exit_fee, effective_fee_config};
+
+#[derive(Accounts)]
+pub struct GetRoundTripCost<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn handler(
+    ctx: Context<GetRoundTripCost>,
+    side: TokenSide,
+    usdc_amount: u64,
+) -> Result<RoundTripCost> {
+    let factory = &ctx.accounts.factory;
+    let mut pool = ContentPool::clone(&ctx.accounts.pool);
+
+    require!(pool.market_deployer != Pubkey::default(), ContentPoolError::MarketNotDeployed);
+    require!(
+        (MIN_TRADE_SIZE..=MAX_TRADE_SIZE).contains(&usdc_amount),
+        ContentPoolError::InvalidTradeAmount
+    );
+
+    // Buy leg: fees then POL out of the input, no stake skim
+    let (total_fee_bps, creator_split_bps) = effective_fee_config(&pool, factory);
+    let (buy_fee, _, _) = apply_creator_fee_floor(
+        calc_fees(usdc_amount, total_fee_bps, creator_split_bps)?,
+        creator_split_bps,
+        factory.creator_fee_floor,
+    );
+    let usdc_to_trade = usdc_amount
+        .checked_sub(buy_fee)
+        .ok_or(ContentPoolError::FeeCalculationOverflow)?;
+    let usdc_to_pol = calc_pol(usdc_amount, factory.pol_bps)?;
+    let tokens_display = project_buy(&mut pool, side, usdc_to_trade, usdc_to_pol)?;
+
+    // Sell leg: every minted token straight back, inside any early-exit window
+    let gross_usdc_out = project_sell(&mut pool, side, tokens_display)?;
+    let (sell_fee, _, _) = apply_creator_fee_floor(
+        calc_fees(gross_usdc_out, total_fee_bps, creator_split_bps)?,
+        creator_split_bps,
+        factory.creator_fee_floor,
+    );
+    // Bought this instant, so the sell is always inside the hold window
+    let early_exit_fee = if factory.early_exit_fee_enabled && pool.min_hold_secs > 0 {
+        let now = Clock::get()?.unix_timestamp;
+        early_exit_fee(gross_usdc_out, pool.early_exit_fee_bps, pool.min_hold_secs, now, now)?
+    } else {
+        0
+    };
+    let usdc_back = gross_usdc_out
+        .saturating_sub(sell_fee)
+        .saturating_sub(early_exit_fee);
+
+    let (loss_micro, loss_bps) = round_trip_loss(usdc_amount, usdc_back);
+
+    Ok(RoundTripCost {
+        tokens_display,
+        buy_fee,
+        sell_fee,
+        early_exit_fee,
+        usdc_back,
+        loss_micro,
+        loss_bps,
+    })
+}
+
+/// (usdc_in - usdc_back, same as bps of usdc_in), floored at zero
+fn round_trip_loss(usdc_in: u64, usdc_back: u64) -> (u64, u16) {
+    let loss = usdc_in.saturating_sub(usdc_back);
+    let bps = (loss as u128 * 10000)
+        .checked_div(usdc_in as u128)
+        .unwrap_or(0)
+        .min(10000) as u16;
+    (loss, bps)
+}
+
+/// Return type for get_round_trip_cost view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RoundTripCost {
+    /// Display tokens the buy would mint (and the sell burns)
+    pub tokens_display: u64,
+    /// Trading fee on the buy (µUSDC)
+    pub buy_fee: u64,
+    /// Trading fee on the sell (µUSDC)
+    pub sell_fee: u64,
+    /// Early-exit fee on the sell, 0 when the pool charges none (µUSDC)
+    pub early_exit_fee: u64,
+    /// Net USDC the sell returns to the trader (µUSDC)
+    pub usdc_back: u64,
+    /// usdc_amount - usdc_back (µUSDC)
+    pub loss_micro: u64,
+    /// loss_micro as bps of usdc_amount
+    pub loss_bps: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::balanced_pool;
+
+    #[test]
+    fn test_fee_free_round_trip_loses_only_rounding() {
+        // No fees or POL: buying then selling the same tokens returns the input, less
+        // the rounding of the display delta to whole tokens
+        let mut pool = balanced_pool();
+        let tokens = project_buy(&mut pool, TokenSide::Long, 10_000_000, 0).unwrap();
+        let back = project_sell(&mut pool, TokenSide::Long, tokens).unwrap();
+        let (loss, bps) = round_trip_loss(10_000_000, back);
+        assert!(bps <= 100, "fee-free round trip lost {} µUSDC ({} bps)", loss, bps);
+    }
+
+    #[test]
+    fn test_round_trip_loss() {
+        assert_eq!(round_trip_loss(1_000_000, 990_000), (10_000, 100));
+        assert_eq!(round_trip_loss(1_000_000, 1_000_001), (0, 0));
+    }
+}
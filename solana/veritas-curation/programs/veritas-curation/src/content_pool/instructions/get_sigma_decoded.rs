@@ -0,0 +1,80 @@
+//! View-only instruction: Returns σ_L / σ_S in unambiguous, pre-decoded forms
+//!
+//! Does NOT mutate on-chain state - σ is stored as Q64.64 (value = raw / 2^64), which
+//! clients keep mis-decoding as Q96 or as a plain integer. This returns each σ both as an
+//! exact rational and as a 9-decimal fixed-point integer.
+//! Used by: clients reconstructing virtual supplies ŝ = s / σ
+
+use anchor_lang::prelude::*;
+use crate::content_pool::{
+    state::ContentPool,
+    math::{mul_div_u128, q64},
+    errors::ContentPoolError,
+};
+
+/// Decimal places in `*_e9` fields (σ = sigma_e9 / 10^9)
+pub const SIGMA_DECIMALS: u8 = 9;
+const SIGMA_DECIMAL_SCALE: u128 = 1_000_000_000;
+
+#[derive(Accounts)]
+pub struct GetSigmaDecoded<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+}
+
+pub fn handler(ctx: Context<GetSigmaDecoded>) -> Result<SigmaDecoded> {
+    let pool = &ctx.accounts.pool;
+
+    Ok(SigmaDecoded {
+        sigma_long_numerator: pool.s_scale_long_q64,
+        sigma_short_numerator: pool.s_scale_short_q64,
+        sigma_denominator: q64::ONE,
+        sigma_long_e9: sigma_to_e9(pool.s_scale_long_q64)?,
+        sigma_short_e9: sigma_to_e9(pool.s_scale_short_q64)?,
+        decimals: SIGMA_DECIMALS,
+    })
+}
+
+/// Q64.64 σ → floor(σ × 10^9)
+///
+/// σ is bounded by SIGMA_MAX = 2^32, so the result is at most ~4.3e18 and fits a u64.
+pub fn sigma_to_e9(sigma_q64: u128) -> Result<u64> {
+    let scaled = mul_div_u128(sigma_q64, SIGMA_DECIMAL_SCALE, q64::ONE)?;
+    u64::try_from(scaled).map_err(|_| ContentPoolError::NumericalOverflow.into())
+}
+
+/// Return type for get_sigma_decoded view function
+///
+/// Exact: σ = numerator / sigma_denominator. Rounded: σ ≈ sigma_e9 / 10^decimals,
+/// truncated toward zero (error < 1e-9).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SigmaDecoded {
+    /// σ_L numerator (the raw stored Q64.64 value)
+    pub sigma_long_numerator: u128,
+    /// σ_S numerator (the raw stored Q64.64 value)
+    pub sigma_short_numerator: u128,
+    /// Shared denominator, always 2^64
+    pub sigma_denominator: u128,
+    /// σ_L × 10^9, truncated
+    pub sigma_long_e9: u64,
+    /// σ_S × 10^9, truncated
+    pub sigma_short_e9: u64,
+    /// Decimal places in the `*_e9` fields (always 9)
+    pub decimals: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::state::{SIGMA_MIN, SIGMA_MAX};
+
+    #[test]
+    fn test_sigma_to_e9_decodes_q64() {
+        assert_eq!(sigma_to_e9(q64::ONE).unwrap(), 1_000_000_000);
+        assert_eq!(sigma_to_e9(q64::ONE + q64::HALF).unwrap(), 1_500_000_000);
+
+        // Bounds: 2^-16 truncates to 15_258 (1.52587890625e-5), 2^32 still fits a u64
+        assert_eq!(sigma_to_e9(SIGMA_MIN).unwrap(), 15_258);
+        assert_eq!(sigma_to_e9(SIGMA_MAX).unwrap(), (1u64 << 32) * 1_000_000_000);
+    }
+}
@@ -0,0 +1,72 @@
+//! View-only instruction: Reconciles pool display supply with SPL mint supply
+//!
+//! Does NOT mutate on-chain state - compares s_long/s_short (whole tokens) with
+//! each mint's supply (atomic units, 6 decimals) to surface accounting drift at
+//! the display/atomic boundary.
+//! Used by: monitoring, post-incident diagnostics
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::content_pool::{
+    state::ContentPool,
+    errors::ContentPoolError,
+};
+use super::trade::TOKEN_SCALE;
+
+#[derive(Accounts)]
+pub struct GetSupplyReconciliation<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = long_mint.key() == pool.long_mint @ ContentPoolError::InvalidMint
+    )]
+    pub long_mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = short_mint.key() == pool.short_mint @ ContentPoolError::InvalidMint
+    )]
+    pub short_mint: Account<'info, Mint>,
+}
+
+pub fn handler(ctx: Context<GetSupplyReconciliation>) -> Result<SupplyReconciliation> {
+    let pool = &ctx.accounts.pool;
+    let long_supply = ctx.accounts.long_mint.supply;
+    let short_supply = ctx.accounts.short_mint.supply;
+
+    Ok(SupplyReconciliation {
+        s_long: pool.s_long,
+        s_short: pool.s_short,
+        long_mint_supply_display: long_supply / TOKEN_SCALE,
+        short_mint_supply_display: short_supply / TOKEN_SCALE,
+        long_matches: supply_matches(pool.s_long, long_supply),
+        short_matches: supply_matches(pool.s_short, short_supply),
+    })
+}
+
+/// Whether a mint's atomic supply is exactly `s_display` whole tokens
+///
+/// A mismatch below the pool supply can be benign (holders may burn through SPL
+/// directly); one above it means the program minted without recording it.
+fn supply_matches(s_display: u64, mint_supply: u64) -> bool {
+    s_display
+        .checked_mul(TOKEN_SCALE)
+        .is_some_and(|atomic| atomic == mint_supply)
+}
+
+/// Return type for get_supply_reconciliation view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SupplyReconciliation {
+    /// Pool LONG supply (display units)
+    pub s_long: u64,
+    /// Pool SHORT supply (display units)
+    pub s_short: u64,
+    /// LONG mint supply in display units (floor of atomic / 1e6)
+    pub long_mint_supply_display: u64,
+    /// SHORT mint supply in display units (floor of atomic / 1e6)
+    pub short_mint_supply_display: u64,
+    /// True if LONG mint supply is exactly s_long × 1e6
+    pub long_matches: bool,
+    /// True if SHORT mint supply is exactly s_short × 1e6
+    pub short_matches: bool,
+}
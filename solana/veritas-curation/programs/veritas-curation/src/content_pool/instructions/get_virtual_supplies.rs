@@ -0,0 +1,72 @@
+//! View-only instruction: Returns the virtual supplies and norm the curve prices on
+//!
+//! Does NOT mutate on-chain state - the curve never sees display supply directly, only
+//! ŝ = s / σ, computed exactly as derive_lambda does:
+//!   ŝ = ceil(s × 2^64 / σ_Q64), then at least 1 for a non-zero side (0 stays 0)
+//!   ||ŝ|| = floor(sqrt(ŝ_L² + ŝ_S²)), at least 1
+//! The ceiling means ŝ can exceed s / σ by up to 1, so recomputing with floor or float
+//! division off-chain will not reproduce λ or prices exactly.
+//! Used by: off-chain curve replication and research tooling
+
+use anchor_lang::prelude::*;
+use crate::content_pool::state::ContentPool;
+use super::trade::{virtual_supplies, virtual_norm};
+
+#[derive(Accounts)]
+pub struct GetVirtualSupplies<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+}
+
+pub fn handler(ctx: Context<GetVirtualSupplies>) -> Result<VirtualSupplies> {
+    let pool = &ctx.accounts.pool;
+
+    // virtual_supplies rejects anything past u64, so the casts below are lossless
+    let (s_long_virtual, s_short_virtual) = virtual_supplies(pool)?;
+
+    Ok(VirtualSupplies {
+        s_long_virtual: s_long_virtual as u64,
+        s_short_virtual: s_short_virtual as u64,
+        virtual_norm: virtual_norm(s_long_virtual, s_short_virtual)?,
+    })
+}
+
+/// Return type for get_virtual_supplies view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VirtualSupplies {
+    /// ŝ_L = ceil(s_long / σ_L), min 1 when s_long > 0
+    pub s_long_virtual: u64,
+    /// ŝ_S = ceil(s_short / σ_S), min 1 when s_short > 0
+    pub s_short_virtual: u64,
+    /// ||ŝ|| = floor(sqrt(ŝ_L² + ŝ_S²)), min 1 - λ = vault_balance / virtual_norm
+    pub virtual_norm: u128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::zeroed_pool;
+    use crate::content_pool::state::Q64;
+
+    #[test]
+    fn test_virtual_supplies_round_up() {
+        let mut pool = zeroed_pool();
+
+        // σ_L = 3: 1000 / 3 = 333.3 rounds up to 334; σ_S = 1/2 doubles exactly
+        pool.s_long = 1_000;
+        pool.s_short = 300;
+        pool.s_scale_long_q64 = 3 * Q64;
+        pool.s_scale_short_q64 = Q64 / 2;
+        let (s_long_virtual, s_short_virtual) = virtual_supplies(&pool).unwrap();
+        assert_eq!((s_long_virtual, s_short_virtual), (334, 600));
+        // sqrt(334² + 600²) = 686.7
+        assert_eq!(virtual_norm(s_long_virtual, s_short_virtual).unwrap(), 686);
+
+        // A tiny side never rounds to zero, an empty side stays empty
+        pool.s_long = 1;
+        pool.s_scale_long_q64 = 1_000 * Q64;
+        pool.s_short = 0;
+        assert_eq!(virtual_supplies(&pool).unwrap(), (1, 0));
+        assert_eq!(virtual_norm(0, 0).unwrap(), 1);
+    }
+}
@@ -1,15 +1,88 @@
 pub mod deploy_market;
+pub mod deploy_market_batch;
 pub mod trade;
+pub mod dual_trade;
+pub mod buy_both_sides;
 pub mod add_liquidity;
 pub mod settle_epoch;
+pub mod skip_epoch;
+pub mod settle_stake_to_pool;
 pub mod close_pool;
+pub mod emergency_close;
+pub mod burn_and_refund;
+pub mod close_empty_position;
+pub mod prepare_trader_accounts;
+pub mod transfer_position;
 pub mod get_current_state;
+pub mod set_fee_override;
+pub mod set_early_exit_fee;
+pub mod set_wind_down;
+pub mod set_curve_params;
+pub mod resync_vault_balance;
+pub mod recompute_initial_q;
+pub mod preview_settlement_lambda;
+pub mod simulate_buy_then_settle;
+pub mod get_round_trip_cost;
+pub mod get_creator_earnings;
+pub mod get_pool_status;
+pub mod get_epoch_info;
+pub mod get_epoch_info_batch;
+pub mod verify_fee_accounts;
+pub mod get_all_pool_fields;
+pub mod get_all_events_state;
+pub mod get_implied_odds;
+pub mod get_current_lambda;
+pub mod get_virtual_supplies;
+pub mod get_sigma_decoded;
+pub mod estimate_fees;
+pub mod quote_buy_exact_out;
+pub mod get_max_buy;
+pub mod get_price_at_supply;
+pub mod get_supply_reconciliation;
+pub mod cost_to_move_q;
+pub mod can_trade;
 
 // Re-export all types for Anchor macros (glob needed for client accounts)
 #[allow(ambiguous_glob_reexports)]
 pub use deploy_market::*;
+pub use deploy_market_batch::*;
 pub use trade::*;
+pub use dual_trade::*;
+pub use buy_both_sides::*;
 pub use add_liquidity::*;
 pub use settle_epoch::*;
+pub use skip_epoch::*;
+pub use settle_stake_to_pool::*;
 pub use close_pool::*;
-pub use get_current_state::*;
\ No newline at end of file
+pub use emergency_close::*;
+pub use close_empty_position::*;
+pub use prepare_trader_accounts::*;
+pub use transfer_position::*;
+pub use get_current_state::*;
+pub use set_fee_override::*;
+pub use set_early_exit_fee::*;
+pub use set_wind_down::*;
+pub use set_curve_params::*;
+pub use resync_vault_balance::*;
+pub use recompute_initial_q::*;
+pub use preview_settlement_lambda::*;
+pub use simulate_buy_then_settle::*;
+pub use get_round_trip_cost::*;
+pub use get_creator_earnings::*;
+pub use get_pool_status::*;
+pub use get_epoch_info::*;
+pub use get_epoch_info_batch::*;
+pub use verify_fee_accounts::*;
+pub use get_all_pool_fields::*;
+pub use get_all_events_state::*;
+pub use get_implied_odds::*;
+pub use get_current_lambda::*;
+pub use get_virtual_supplies::*;
+pub use get_sigma_decoded::*;
+pub use estimate_fees::*;
+pub use quote_buy_exact_out::*;
+pub use get_max_buy::*;
+pub use get_price_at_supply::*;
+pub use get_supply_reconciliation::*;
+pub use cost_to_move_q::*;
+pub use can_trade::*;
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::content_pool::{
+    state::ContentPool,
+    errors::ContentPoolError,
+};
+
+#[derive(Accounts)]
+pub struct PrepareTraderAccounts<'info> {
+    #[account(
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump,
+        constraint = pool.market_deployer != Pubkey::default() @ ContentPoolError::MarketNotDeployed
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = long_mint.key() == pool.long_mint @ ContentPoolError::InvalidMint
+    )]
+    pub long_mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = short_mint.key() == pool.short_mint @ ContentPoolError::InvalidMint
+    )]
+    pub short_mint: Account<'info, Mint>,
+
+    /// Trader's LONG ATA - created if missing, left untouched otherwise
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = long_mint,
+        associated_token::authority = trader
+    )]
+    pub trader_long: Account<'info, TokenAccount>,
+
+    /// Trader's SHORT ATA - created if missing, left untouched otherwise
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = short_mint,
+        associated_token::authority = trader
+    )]
+    pub trader_short: Account<'info, TokenAccount>,
+
+    /// CHECK: ATA owner only; creating an ATA needs no signature from its owner
+    pub trader: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a trader's LONG and SHORT ATAs for a pool ahead of their first trade
+///
+/// Idempotent: existing ATAs are validated and kept. After this, `trade`'s
+/// init_if_needed on trader_tokens is a no-op and the trade pays no rent.
+pub fn handler(_ctx: Context<PrepareTraderAccounts>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,211 @@
+//! View-only instruction: Quotes the USDC a buy needs to mint an exact token amount
+//!
+//! Does NOT mutate on-chain state - inverts the buy path (skim → fees → POL → curve)
+//! so exact-out UIs know which `amount` to pass to `trade`.
+//! Used by: trade form "receive exactly N tokens" mode
+
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::*,
+    errors::ContentPoolError,
+    curve::{integer_sqrt, Q96},
+    math::{mul_div_u128, renormalize_scales, ceil_div},
+};
+use super::trade::{calc_fees, calc_pol, effective_fee_config, virtual_supplies, lambda_from_virtual};
+
+#[derive(Accounts)]
+pub struct QuoteBuyExactOut<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn handler(
+    ctx: Context<QuoteBuyExactOut>,
+    side: TokenSide,
+    tokens_display_wanted: u64,
+    stake_skim: u64,
+) -> Result<BuyExactOutQuote> {
+    let factory = &ctx.accounts.factory;
+    let mut pool = ContentPool::clone(&ctx.accounts.pool);
+
+    require!(pool.market_deployer != Pubkey::default(), ContentPoolError::MarketNotDeployed);
+    require!(tokens_display_wanted > 0, ContentPoolError::InvalidTradeAmount);
+
+    let s_side = match side {
+        TokenSide::Long => pool.s_long,
+        TokenSide::Short => pool.s_short,
+    };
+    let new_supply = s_side
+        .checked_add(tokens_display_wanted)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    require!(new_supply <= S_DISPLAY_CAP, ContentPoolError::SupplyOverflow);
+
+    // Same pre-curve state a buy sees: σ renormalized, then λ from vault + virtual supplies
+    renormalize_scales(
+        &mut pool.s_scale_long_q64,
+        &mut pool.s_scale_short_q64,
+        pool.s_long,
+        pool.s_short,
+    );
+    let (s_long_virtual, s_short_virtual) = virtual_supplies(&pool)?;
+    let lambda_q96 = lambda_from_virtual(pool.vault_balance, s_long_virtual, s_short_virtual)?;
+
+    let (s_side_virtual, s_other_virtual, sigma_side_q64) = match side {
+        TokenSide::Long => (s_long_virtual, s_short_virtual, pool.s_scale_long_q64),
+        TokenSide::Short => (s_short_virtual, s_long_virtual, pool.s_scale_short_q64),
+    };
+
+    // Virtual delta whose display rounding yields at least the wanted tokens
+    let delta_virtual = ceil_div(tokens_display_wanted as u128 * Q64, sigma_side_q64);
+
+    let usdc_to_curve = curve_cost_for_exact_out(
+        s_side_virtual,
+        s_other_virtual,
+        delta_virtual,
+        lambda_q96,
+    )?;
+
+    let (total_fee_bps, _) = effective_fee_config(&pool, factory);
+    let after_skim = gross_up_for_fees(usdc_to_curve, total_fee_bps, factory.pol_bps)?;
+    let (total_fee, _, _) = calc_fees(after_skim, total_fee_bps, 0)?;
+    let usdc_to_pol = calc_pol(after_skim, factory.pol_bps)?;
+
+    let usdc_amount = after_skim
+        .checked_add(stake_skim)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    require!(stake_skim <= usdc_amount / 2, ContentPoolError::InvalidStakeSkim);
+
+    Ok(BuyExactOutQuote {
+        usdc_amount,
+        stake_skim,
+        total_fee,
+        usdc_to_pol,
+        usdc_to_curve,
+        tokens_display: tokens_display_wanted,
+    })
+}
+
+/// Minimum USDC the curve must receive to move the bought side from `s_side_v` to
+/// `s_side_v + delta_v` virtual tokens (inverse of ICBSCurve::calculate_buy)
+///
+/// calculate_buy sets norm_after = norm_before + ⌊usdc·Q96/λ⌋ and mints
+/// ⌊√(norm_after² − s_other²)⌋ − s_side, so we need norm_after ≥ ⌈√(s_new² + s_other²)⌉
+/// and the smallest usdc with ⌊usdc·Q96/λ⌋ ≥ norm_after − norm_before.
+pub(crate) fn curve_cost_for_exact_out(
+    s_side_v: u128,
+    s_other_v: u128,
+    delta_v: u128,
+    lambda_q96: u128,
+) -> Result<u64> {
+    let norm_sq = |a: u128, b: u128| -> Result<u128> {
+        a.checked_mul(a)
+            .and_then(|a2| b.checked_mul(b).and_then(|b2| a2.checked_add(b2)))
+            .ok_or(ContentPoolError::NumericalOverflow.into())
+    };
+
+    let norm_before = integer_sqrt(norm_sq(s_side_v, s_other_v)?)?;
+
+    let s_new = s_side_v
+        .checked_add(delta_v)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let target_sq = norm_sq(s_new, s_other_v)?;
+    let mut norm_after = integer_sqrt(target_sq)?;
+    if norm_after * norm_after < target_sq {
+        norm_after += 1;
+    }
+    let delta_norm = norm_after.saturating_sub(norm_before);
+
+    let mut usdc = mul_div_u128(delta_norm, lambda_q96, Q96)?;
+    if mul_div_u128(usdc, Q96, lambda_q96)? < delta_norm {
+        usdc += 1;
+    }
+    u64::try_from(usdc).map_err(|_| ContentPoolError::NumericalOverflow.into())
+}
+
+/// Smallest after-skim amount A whose curve share covers `usdc_to_curve`
+///
+/// On a buy, fees and POL are both taken from A before the curve:
+///   usdc_to_curve = A − ⌊A·fee_bps/10⁴⌋ − ⌊A·pol_bps/10⁴⌋
+/// Solving without the floors gives A = ⌈C·10⁴ / (10⁴ − fee_bps − pol_bps)⌉, which
+/// is always enough; the floors can make a slightly smaller A work, so step down.
+pub(crate) fn gross_up_for_fees(usdc_to_curve: u64, total_fee_bps: u16, pol_bps: u16) -> Result<u64> {
+    let kept_bps = 10_000u128
+        .checked_sub(total_fee_bps as u128 + pol_bps as u128)
+        .filter(|&k| k > 0)
+        .ok_or(ContentPoolError::InvalidParameter)?;
+
+    let mut after_skim = u64::try_from(ceil_div(usdc_to_curve as u128 * 10_000, kept_bps))
+        .map_err(|_| ContentPoolError::NumericalOverflow)?;
+    while after_skim > usdc_to_curve
+        && curve_share(after_skim - 1, total_fee_bps, pol_bps)? >= usdc_to_curve
+    {
+        after_skim -= 1;
+    }
+    Ok(after_skim)
+}
+
+/// Part of an after-skim amount the curve prices: A − fee − POL (no creator fee floor)
+pub(crate) fn curve_share(after_skim: u64, total_fee_bps: u16, pol_bps: u16) -> Result<u64> {
+    let (fee, _, _) = calc_fees(after_skim, total_fee_bps, 0)?;
+    Ok(after_skim - fee - calc_pol(after_skim, pol_bps)?)
+}
+
+/// Return type for quote_buy_exact_out view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BuyExactOutQuote {
+    /// Gross USDC to pass as `amount` to `trade` (µUSDC, includes skim and fees)
+    pub usdc_amount: u64,
+    /// Stake skim included in usdc_amount (µUSDC, as requested)
+    pub stake_skim: u64,
+    /// Trading fee taken from usdc_amount - stake_skim (µUSDC)
+    pub total_fee: u64,
+    /// Protocol-owned liquidity share (µUSDC, not priced by the curve)
+    pub usdc_to_pol: u64,
+    /// USDC the curve prices (µUSDC)
+    pub usdc_to_curve: u64,
+    /// Display tokens the buy mints at current state (at least this many)
+    pub tokens_display: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::curve::ICBSCurve;
+
+    #[test]
+    fn test_curve_cost_inverts_calculate_buy() {
+        let lambda = lambda_from_virtual(100_000_000, 1_000, 1_000).unwrap();
+        for (s_side, s_other, delta) in [(1_000u128, 1_000u128, 1u128), (1_000, 1_000, 250), (400, 2_500, 37)] {
+            let usdc = curve_cost_for_exact_out(s_side, s_other, delta, lambda).unwrap();
+            let (minted, _) = ICBSCurve::calculate_buy(
+                s_side as u64, usdc, lambda, s_other as u64, 1, 1, 2, true, Q64, Q64,
+            ).unwrap();
+            assert!(minted as u128 >= delta, "usdc {} minted {} < {}", usdc, minted, delta);
+
+            // One µUSDC less mints fewer tokens (or rounds below the current supply and errors)
+            let short = ICBSCurve::calculate_buy(
+                s_side as u64, usdc - 1, lambda, s_other as u64, 1, 1, 2, true, Q64, Q64,
+            ).map_or(0, |(minted, _)| minted);
+            assert!((short as u128) < delta, "quote not minimal: 1 µUSDC less still mints {}", short);
+        }
+    }
+
+    #[test]
+    fn test_gross_up_for_fees() {
+        for (curve, fee_bps, pol_bps) in [(10_000_000u64, 50u16, 0u16), (10_000_000, 100, 1000), (1, 50, 0), (123_457, 33, 250)] {
+            let a = gross_up_for_fees(curve, fee_bps, pol_bps).unwrap();
+            let net = |a: u64| a - calc_fees(a, fee_bps, 0).unwrap().0 - calc_pol(a, pol_bps).unwrap();
+            assert!(net(a) >= curve);
+            assert!(net(a - 1) < curve);
+        }
+
+        assert_eq!(gross_up_for_fees(5_000_000, 0, 0).unwrap(), 5_000_000);
+        assert!(gross_up_for_fees(1, 5_000, 5_000).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::*,
+    events::InitialQRecomputedEvent,
+    errors::ContentPoolError,
+};
+use super::deploy_market::initial_q_from_reserves;
+
+#[derive(Accounts)]
+pub struct RecomputeInitialQ<'info> {
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump,
+        constraint = pool.market_deployer != Pubkey::default() @ ContentPoolError::MarketNotDeployed
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        constraint = protocol_authority.key() == factory.protocol_authority @ ContentPoolError::UnauthorizedProtocol
+    )]
+    pub protocol_authority: Signer<'info>,
+}
+
+/// Heal a pool deployed before the initial_q clamp
+///
+/// Such pools may store exactly 0 or Q32_ONE. The deploy-time reserves are gone, so the
+/// value is recomputed from current reserves with the same clamp deploy_market applies.
+/// Pools whose initial_q is already in range are rejected, so real history is never overwritten.
+pub fn handler(ctx: Context<RecomputeInitialQ>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    let initial_q_before = pool.initial_q;
+    pool.initial_q = repaired_initial_q(initial_q_before, pool.r_long, pool.r_short)?;
+
+    emit!(InitialQRecomputedEvent {
+        pool: pool.key(),
+        initial_q_before,
+        initial_q_after: pool.initial_q,
+        updated_by: ctx.accounts.protocol_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Clamped initial_q from reserves, or an error if `stored` needs no repair
+fn repaired_initial_q(stored: u64, r_long: u64, r_short: u64) -> Result<u64> {
+    require!(
+        !(Q32_MIN_INITIAL_Q..=Q32_MAX_INITIAL_Q).contains(&stored),
+        ContentPoolError::InitialQNotDegenerate
+    );
+    Ok(initial_q_from_reserves(r_long as u128, r_long as u128 + r_short as u128))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repairs_pre_clamp_initial_q() {
+        // Pre-clamp deploy of a 99.99% LONG allocation stored q = Q32_ONE exactly
+        assert_eq!(repaired_initial_q(Q32_ONE, 99_990_000, 10_000).unwrap(), Q32_MAX_INITIAL_Q);
+        // ...and the mirror image stored 0
+        assert_eq!(repaired_initial_q(0, 10_000, 99_990_000).unwrap(), Q32_MIN_INITIAL_Q);
+        // Trading since deploy moved q back inside the clamp: that value is used as-is
+        assert_eq!(repaired_initial_q(0, 30_000_000, 70_000_000).unwrap(), Q32_ONE * 3 / 10);
+    }
+
+    #[test]
+    fn test_rejects_in_range_initial_q() {
+        for stored in [Q32_MIN_INITIAL_Q, Q32_ONE / 2, Q32_MAX_INITIAL_Q] {
+            assert!(repaired_initial_q(stored, 1, 1).is_err());
+        }
+    }
+}
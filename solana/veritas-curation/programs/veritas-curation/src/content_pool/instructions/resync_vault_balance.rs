@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::*,
+    events::VaultResyncedEvent,
+    errors::ContentPoolError,
+    curve::ICBSCurve,
+};
+use super::trade::{virtual_supplies, lambda_from_virtual};
+
+#[derive(Accounts)]
+pub struct ResyncVaultBalance<'info> {
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump,
+        constraint = pool.market_deployer != Pubkey::default() @ ContentPoolError::MarketNotDeployed
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        constraint = vault.key() == pool.vault @ ContentPoolError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = protocol_authority.key() == factory.protocol_authority @ ContentPoolError::UnauthorizedProtocol
+    )]
+    pub protocol_authority: Signer<'info>,
+}
+
+/// Re-couple the pool's accounting to the live vault balance
+///
+/// Sets vault_balance = vault.amount, re-derives λ from it and re-prices both sides,
+/// restoring r_long + r_short == vault_balance == vault.amount after a donation or drift.
+/// Supplies and σ are untouched, so every holder's share of the vault is preserved.
+pub fn handler(ctx: Context<ResyncVaultBalance>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    let vault_balance_before = pool.vault_balance;
+    let (r_long_before, r_short_before) = (pool.r_long, pool.r_short);
+
+    pool.vault_balance = ctx.accounts.vault.amount;
+
+    let (s_long_virtual, s_short_virtual) = virtual_supplies(pool)?;
+    let lambda_q96 = lambda_from_virtual(pool.vault_balance, s_long_virtual, s_short_virtual)?;
+
+    pool.sqrt_price_long_x96 = ICBSCurve::sqrt_marginal_price_from_virtual(
+        s_long_virtual as u64,
+        s_short_virtual as u64,
+        TokenSide::Long,
+        lambda_q96,
+        pool.s_scale_long_q64,
+        pool.s_scale_short_q64,
+        pool.f,
+        pool.beta_num,
+        pool.beta_den,
+    )?;
+    pool.sqrt_price_short_x96 = ICBSCurve::sqrt_marginal_price_from_virtual(
+        s_long_virtual as u64,
+        s_short_virtual as u64,
+        TokenSide::Short,
+        lambda_q96,
+        pool.s_scale_long_q64,
+        pool.s_scale_short_q64,
+        pool.f,
+        pool.beta_num,
+        pool.beta_den,
+    )?;
+
+    // ENFORCE INVARIANT: r_long + r_short = vault_balance
+    let r_long_calc = ICBSCurve::reserve_from_lambda_and_virtual(
+        s_long_virtual as u64,
+        s_short_virtual as u64,
+        lambda_q96,
+    )?;
+    pool.r_long = r_long_calc.min(pool.vault_balance);
+    pool.r_short = pool.vault_balance.saturating_sub(pool.r_long);
+
+    // Keep the deprecated telemetry fields in step with the derived λ
+    pool.lambda_long_q96 = lambda_q96;
+    pool.lambda_short_q96 = lambda_q96;
+
+    emit!(VaultResyncedEvent {
+        pool: pool.key(),
+        vault_balance_before,
+        vault_balance_after: pool.vault_balance,
+        r_long_before,
+        r_short_before,
+        r_long_after: pool.r_long,
+        r_short_after: pool.r_short,
+        lambda_q96_after: lambda_q96,
+        updated_by: ctx.accounts.protocol_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
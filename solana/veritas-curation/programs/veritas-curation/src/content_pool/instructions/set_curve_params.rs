@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::{PoolFactory, SUPPORTED_F, SUPPORTED_BETA};
+use crate::content_pool::{
+    state::ContentPool,
+    events::CurveParamsUpdatedEvent,
+    errors::ContentPoolError,
+    math::reduce_fraction,
+};
+
+#[derive(Accounts)]
+pub struct SetCurveParams<'info> {
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        constraint = protocol_authority.key() == factory.protocol_authority @ ContentPoolError::UnauthorizedProtocol
+    )]
+    pub protocol_authority: Signer<'info>,
+}
+
+/// Validate a per-pool F/β change
+///
+/// Curve parameters are immutable once the market is deployed: tokens minted at the
+/// old curve would no longer match reserves, so only undeployed pools may change them.
+/// The curve math implements F = 1, β = 1/2 only, so anything else would fail every deploy.
+fn validate_curve_params(
+    market_deployer: Pubkey,
+    f: u16,
+    beta_num: u16,
+    beta_den: u16,
+) -> Result<()> {
+    require!(
+        market_deployer == Pubkey::default(),
+        ContentPoolError::MarketAlreadyDeployed
+    );
+    require!(f == SUPPORTED_F, ContentPoolError::InvalidExponent);
+    require!(beta_num > 0 && beta_den > 0, ContentPoolError::InvalidBeta);
+    require!(
+        reduce_fraction(beta_num, beta_den) == SUPPORTED_BETA,
+        ContentPoolError::InvalidBeta
+    );
+    Ok(())
+}
+
+/// Set per-pool curve parameters (F, β) before the market is deployed
+pub fn handler(
+    ctx: Context<SetCurveParams>,
+    f: u16,
+    beta_num: u16,
+    beta_den: u16,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    validate_curve_params(pool.market_deployer, f, beta_num, beta_den)?;
+    // Stored in lowest terms: the curve only recognises β = 0.5 as exactly 1 / 2
+    let (beta_num, beta_den) = reduce_fraction(beta_num, beta_den);
+
+    pool.f = f;
+    pool.beta_num = beta_num;
+    pool.beta_den = beta_den;
+
+    emit!(CurveParamsUpdatedEvent {
+        pool: pool.key(),
+        f,
+        beta_num,
+        beta_den,
+        updated_by: ctx.accounts.protocol_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beta_change_rejected_after_deploy() {
+        let deployer = Pubkey::new_unique();
+        assert_eq!(
+            validate_curve_params(deployer, 1, 2, 4).unwrap_err(),
+            ContentPoolError::MarketAlreadyDeployed.into()
+        );
+
+        // Same change is allowed before deployment
+        assert!(validate_curve_params(Pubkey::default(), 1, 2, 4).is_ok());
+
+        // Only the curve the math implements is accepted pre-deploy
+        assert_eq!(
+            validate_curve_params(Pubkey::default(), 1, 3, 10).unwrap_err(),
+            ContentPoolError::InvalidBeta.into()
+        );
+        assert_eq!(
+            validate_curve_params(Pubkey::default(), 2, 1, 2).unwrap_err(),
+            ContentPoolError::InvalidExponent.into()
+        );
+        assert!(validate_curve_params(Pubkey::default(), 1, 1, 0).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::{ContentPool, MAX_EARLY_EXIT_FEE_BPS},
+    events::EarlyExitFeeUpdatedEvent,
+    errors::ContentPoolError,
+};
+
+#[derive(Accounts)]
+pub struct SetEarlyExitFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        constraint = protocol_authority.key() == factory.protocol_authority @ ContentPoolError::UnauthorizedProtocol
+    )]
+    pub protocol_authority: Signer<'info>,
+}
+
+/// Set the per-pool early-exit fee (0 = none); charged only while the factory flag is on
+pub fn handler(
+    ctx: Context<SetEarlyExitFee>,
+    early_exit_fee_bps: u16,
+    min_hold_secs: u32,
+) -> Result<()> {
+    require!(
+        early_exit_fee_bps <= MAX_EARLY_EXIT_FEE_BPS,
+        ContentPoolError::InvalidFeeOverride
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    pool.early_exit_fee_bps = early_exit_fee_bps;
+    pool.min_hold_secs = min_hold_secs;
+
+    emit!(EarlyExitFeeUpdatedEvent {
+        pool: pool.key(),
+        early_exit_fee_bps,
+        min_hold_secs,
+        updated_by: ctx.accounts.protocol_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::ContentPool,
+    events::FeeOverrideUpdatedEvent,
+    errors::ContentPoolError,
+};
+
+#[derive(Accounts)]
+pub struct SetFeeOverride<'info> {
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        constraint = protocol_authority.key() == factory.protocol_authority @ ContentPoolError::UnauthorizedProtocol
+    )]
+    pub protocol_authority: Signer<'info>,
+}
+
+/// Set per-pool fee overrides (None = fall back to factory config)
+pub fn handler(
+    ctx: Context<SetFeeOverride>,
+    fee_bps_override: Option<u16>,
+    creator_split_override: Option<u16>,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    apply_fee_override(pool, fee_bps_override, creator_split_override)?;
+
+    emit!(FeeOverrideUpdatedEvent {
+        pool: pool.key(),
+        fee_bps_override,
+        creator_split_override,
+        updated_by: ctx.accounts.protocol_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Validate and store both overrides; None clears one back to the factory value
+// map_or rather than is_none_or, which the Solana platform toolchain predates
+#[allow(clippy::unnecessary_map_or)]
+fn apply_fee_override(
+    pool: &mut ContentPool,
+    fee_bps_override: Option<u16>,
+    creator_split_override: Option<u16>,
+) -> Result<()> {
+    // Same bounds as the factory fee config
+    require!(
+        fee_bps_override.map_or(true, PoolFactory::total_fee_allowed),
+        ContentPoolError::InvalidFeeOverride
+    );
+    require!(
+        creator_split_override.map_or(true, |bps| bps <= 10000),
+        ContentPoolError::InvalidFeeOverride
+    );
+
+    pool.fee_override_set = fee_bps_override.is_some();
+    pool.fee_bps_override = fee_bps_override.unwrap_or(0);
+    pool.creator_split_override_set = creator_split_override.is_some();
+    pool.creator_split_override = creator_split_override.unwrap_or(0);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool_factory::state::MAX_TOTAL_FEE_BPS;
+    use crate::content_pool::test_utils::zeroed_pool;
+
+    #[test]
+    fn test_zero_creator_split_is_an_override() {
+        let mut pool = zeroed_pool();
+        apply_fee_override(&mut pool, Some(0), Some(0)).unwrap();
+        assert_eq!(pool.fee_override_bps(), Some(0));
+        assert_eq!(pool.creator_split_override_bps(), Some(0));
+    }
+
+    #[test]
+    fn test_none_clears_override() {
+        let mut pool = zeroed_pool();
+        apply_fee_override(&mut pool, Some(300), Some(5_000)).unwrap();
+        assert_eq!((pool.fee_override_bps(), pool.creator_split_override_bps()), (Some(300), Some(5_000)));
+
+        apply_fee_override(&mut pool, None, Some(5_000)).unwrap();
+        assert_eq!((pool.fee_override_bps(), pool.creator_split_override_bps()), (None, Some(5_000)));
+        apply_fee_override(&mut pool, None, None).unwrap();
+        assert_eq!((pool.fee_override_bps(), pool.creator_split_override_bps()), (None, None));
+    }
+
+    #[test]
+    fn test_override_bounds() {
+        let mut pool = zeroed_pool();
+        assert!(apply_fee_override(&mut pool, Some(MAX_TOTAL_FEE_BPS), Some(10_000)).is_ok());
+        assert!(apply_fee_override(&mut pool, Some(MAX_TOTAL_FEE_BPS + 1), None).is_err());
+        assert!(apply_fee_override(&mut pool, None, Some(10_001)).is_err());
+
+        // A rejected call leaves the previous overrides in place
+        assert_eq!((pool.fee_override_bps(), pool.creator_split_override_bps()), (Some(MAX_TOTAL_FEE_BPS), Some(10_000)));
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::ContentPool,
+    events::WindDownUpdatedEvent,
+    errors::ContentPoolError,
+};
+
+#[derive(Accounts)]
+pub struct SetWindDown<'info> {
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump,
+        constraint = !pool.closed @ ContentPoolError::PoolClosed
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        constraint = protocol_authority.key() == factory.protocol_authority @ ContentPoolError::UnauthorizedProtocol
+    )]
+    pub protocol_authority: Signer<'info>,
+}
+
+/// Flag a pool for wind-down ahead of an emergency_close (or clear the flag)
+/// While set, buys revert and sells pay no trading or early-exit fee
+pub fn handler(ctx: Context<SetWindDown>, wind_down: bool) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.wind_down = wind_down;
+
+    emit!(WindDownUpdatedEvent {
+        pool: pool.key(),
+        wind_down,
+        updated_by: ctx.accounts.protocol_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
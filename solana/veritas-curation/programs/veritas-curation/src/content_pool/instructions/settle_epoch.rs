@@ -5,8 +5,9 @@ use crate::content_pool::{
     state::*,
     events::SettlementEvent,
     errors::ContentPoolError,
-    math::{renormalize_scales, mul_div_u128, ceil_div},
+    math::ceil_div,
     curve::{ICBSCurve, Q96},
+    settlement::{market_prediction_q, settlement_factors, floored_factors, settled_scales, isqrt_u128, settled_reserves, zero_sum_reserves, SettledReserves, cooldown_satisfied, q_within_tolerance},
 };
 
 #[derive(Accounts)]
@@ -14,7 +15,8 @@ pub struct SettleEpoch<'info> {
     #[account(
         mut,
         seeds = [b"content_pool", pool.content_id.as_ref()],
-        bump = pool.bump
+        bump = pool.bump,
+        constraint = !pool.closed @ ContentPoolError::PoolClosed
     )]
     pub pool: Account<'info, ContentPool>,
 
@@ -23,16 +25,21 @@ pub struct SettleEpoch<'info> {
     )]
     pub factory: Account<'info, PoolFactory>,
 
+    /// Protocol authority, or the factory's settlement_authority (forced settlements excepted)
     #[account(
-        constraint = protocol_authority.key() == factory.protocol_authority @ ContentPoolError::UnauthorizedProtocol
+        constraint = factory.can_settle(&protocol_authority.key()) @ ContentPoolError::UnauthorizedProtocol
     )]
     pub protocol_authority: Signer<'info>,
 
     pub settler: Signer<'info>,
 
-    /// Vault token account (needed for λ derivation to update prices)
+    /// Vault token account; must hold at least vault_balance
+    /// λ is derived from vault_balance, the same total the reserves are scaled to, so a
+    /// donation (untracked until resync_vault_balance) is ignored, but a vault short of
+    /// vault_balance means the tracked reserves are not actually backed
     #[account(
-        constraint = vault.key() == pool.vault @ ContentPoolError::InvalidVault
+        constraint = vault.key() == pool.vault @ ContentPoolError::InvalidVault,
+        constraint = vault.amount >= pool.vault_balance @ ContentPoolError::VaultBalanceMismatch
     )]
     pub vault: Account<'info, TokenAccount>,
 }
@@ -40,17 +47,41 @@ pub struct SettleEpoch<'info> {
 pub fn handler(
     ctx: Context<SettleEpoch>,
     bd_score: u32,  // BD score in millionths format [0, 1_000_000] where 500_000 = 50%
+    zero_sum: bool, // Set reserves directly from BD score instead of scaling by factors
+    force: bool,    // Protocol authority override: skip the settlement cooldown (not the settlement authority)
+    expected_q_bps: Option<u16>,      // q the BD score was computed against; None skips the check
+    max_q_deviation_bps: Option<u16>, // Allowed |live q - expected_q_bps| in whole bps (default 0)
 ) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
 
-    // Check settlement cooldown
-    if pool.last_settle_ts > 0 {
-        let elapsed = clock.unix_timestamp - pool.last_settle_ts;
+    // No market, nothing to settle: skip before any epoch or event is produced
+    ensure_has_market(pool)?;
+
+    // Ordering vs decay: decay (if due) must be applied before settlement, so the BD
+    // score scales decayed reserves rather than having decay re-price a settled pool.
+    // Decay is not implemented on-chain (expiration_timestamp is always 0 and nothing
+    // advances last_decay_update), so there is no decay step to run here. A future
+    // decay crank must be invoked at this point, ahead of the cooldown check.
+
+    // Check settlement cooldown (skipped for forced settlements, which only the
+    // protocol authority itself may request)
+    require!(
+        cooldown_satisfied(
+            pool.last_settle_ts,
+            ctx.accounts.factory.effective_settle_interval(pool.min_settle_interval),
+            clock.unix_timestamp,
+            force,
+        ),
+        ContentPoolError::SettlementCooldown
+    );
+    if force {
+        // Skipping the cooldown stays with the protocol authority, not the settlement bot
         require!(
-            elapsed >= pool.min_settle_interval,
-            ContentPoolError::SettlementCooldown
+            ctx.accounts.protocol_authority.key() == ctx.accounts.factory.protocol_authority,
+            ContentPoolError::UnauthorizedProtocol
         );
+        msg!("settle_epoch: forced settlement, cooldown skipped");
     }
 
     // Validate BD score (0 to 1 million = 0% to 100%)
@@ -65,116 +96,66 @@ pub fn handler(
 
     // Calculate current market prediction q from stored reserves
     // q = R_L / (R_L + R_S)
-    let total_reserves = (pool.r_long as u128)
-        .checked_add(pool.r_short as u128)
-        .ok_or(ContentPoolError::NumericalOverflow)?;
-
-    let q = if total_reserves == 0 {
-        500_000 // Default to 50% if no reserves
-    } else {
-        ((pool.r_long as u128 * 1_000_000) / total_reserves) as u64
-    };
-
-    // Clamp q to prevent division issues (1000 = 0.1%, 999000 = 99.9%)
-    let q_clamped = if q < 1000 {
-        1000
-    } else if q > 999_000 {
-        999_000
-    } else {
-        q
-    };
-
-    // Calculate raw settlement factors
-    // f_L = x / q
-    let f_long_raw = ((bd_score as u128 * 1_000_000) / q_clamped as u128) as u64;
-
-    // f_S = (1 - x) / (1 - q)
-    let one_minus_x = 1_000_000u64.saturating_sub(bd_score as u64);
-    let one_minus_q = 1_000_000u64.saturating_sub(q_clamped);
-    let f_short_raw = ((one_minus_x as u128 * 1_000_000) / one_minus_q as u128) as u64;
+    let q = market_prediction_q(pool.r_long, pool.r_short);
+
+    // Slippage guard: a trade since the operator observed q would change the factors
+    if let Some(expected_q_bps) = expected_q_bps {
+        require!(expected_q_bps <= 10_000, ContentPoolError::InvalidParameter);
+        let max_q_deviation_bps = max_q_deviation_bps.unwrap_or(0);
+        if !q_within_tolerance(q, expected_q_bps, max_q_deviation_bps) {
+            msg!("settle_epoch: q={} (millionths), expected {} ± {} bps", q, expected_q_bps, max_q_deviation_bps);
+            return err!(ContentPoolError::QMovedError);
+        }
+    }
 
-    // Hard-cap factors to [0.01, 100] to prevent unbounded drift
-    let f_long = f_long_raw.clamp(F_MIN, F_MAX);
-    let f_short = f_short_raw.clamp(F_MIN, F_MAX);
+    // Calculate settlement factors (q clamped, factors hard-capped to [0.01, 100]), then
+    // raise a side that would settle below MIN_SIDE_RESERVE so σ and reserves agree
+    let (f_long, f_short) = settlement_factors(bd_score, q);
+    let (f_long, f_short) =
+        floored_factors(pool.r_long, pool.r_short, pool.vault_balance, f_long, f_short)?;
 
     // Store old scales for event
     let scale_long_before = pool.s_scale_long_q64;
     let scale_short_before = pool.s_scale_short_q64;
 
-    // Update scales (Q64 conversion)
-    let f_long_q64 = ((f_long as u128) << 64) / 1_000_000;
-    let f_short_q64 = ((f_short as u128) << 64) / 1_000_000;
-
     // --- SAFE σ UPDATE WITH SQUARE-ROOT FACTORS ---
-    // CRITICAL: Use SQUARE-ROOT of factors to keep price-implied reserves consistent with actual reserves
-    // When updating σ by sqrt(f) instead of f, the post-settlement price-implied ratio equals BD exactly:
-    //   q_price = q·√f_L² / (q·√f_L² + (1-q)·√f_S²) = q·f_L / (q·f_L + (1-q)·f_S) = x
-    //
-    // For a value x in Q64, sqrt(x) in Q64 = isqrt(x_q64) << 32
-    let sqrt_f_long_q64 = isqrt_u128(f_long_q64) << 32;
-    let sqrt_f_short_q64 = isqrt_u128(f_short_q64) << 32;
-
-    // σ_new = σ_old / sqrt(f)
-    pool.s_scale_long_q64 = mul_div_u128(pool.s_scale_long_q64, Q64, sqrt_f_long_q64)?;
-    pool.s_scale_short_q64 = mul_div_u128(pool.s_scale_short_q64, Q64, sqrt_f_short_q64)?;
-
-    // Renormalize scales to keep both sigma and virtual norm in safe range
-    {
-        let mut sigma_long = pool.s_scale_long_q64;
-        let mut sigma_short = pool.s_scale_short_q64;
-        let s_long = pool.s_long;
-        let s_short = pool.s_short;
-        renormalize_scales(
-            &mut sigma_long,
-            &mut sigma_short,
-            s_long,
-            s_short,
-        );
-        pool.s_scale_long_q64 = sigma_long;
-        pool.s_scale_short_q64 = sigma_short;
-    }
+    // σ_new = σ_old / sqrt(f), then renormalize to keep both sigma and virtual norm
+    // in safe range. Both stages are reported in the event so indexers can replay them.
+    let scales = settled_scales(
+        pool.s_scale_long_q64,
+        pool.s_scale_short_q64,
+        f_long,
+        f_short,
+        pool.s_long,
+        pool.s_short,
+    )?;
+    pool.s_scale_long_q64 = scales.long;
+    pool.s_scale_short_q64 = scales.short;
 
     // --- SAFE RESERVE UPDATE ---
-    // CRITICAL FIX: Detect extreme imbalance (one side near zero)
-    // When one reserve is < 1% of total, multiplicative scaling fails because
-    // multiplying near-zero by any factor gives near-zero, then the recouple
-    // undoes the settlement by proportionally redistributing.
-    //
-    // Instead: directly redistribute vault_balance according to BD score
-    const EXTREME_IMBALANCE_THRESHOLD: u128 = 10_000; // 1% in micro-units
-    let total_before = total_reserves;
-    let is_extreme_imbalance =
-        (pool.r_long as u128 * 1_000_000) < (total_before * EXTREME_IMBALANCE_THRESHOLD) ||
-        (pool.r_short as u128 * 1_000_000) < (total_before * EXTREME_IMBALANCE_THRESHOLD);
-
-    if is_extreme_imbalance {
-        // Direct redistribution: ignore multiplicative factors
-        // r_long = vault × bd_score
-        // r_short = vault × (1 - bd_score)
-        pool.r_long = mul_div_u128(pool.vault_balance as u128, bd_score as u128, 1_000_000u128)? as u64;
-        pool.r_short = pool.vault_balance.saturating_sub(pool.r_long);
-    } else {
-        // Normal case: multiplicative scaling with recouple
-        pool.r_long = mul_div_u128(pool.r_long as u128, f_long as u128, 1_000_000u128)? as u64;
-        pool.r_short = mul_div_u128(pool.r_short as u128, f_short as u128, 1_000_000u128)? as u64;
-
-        // --- INVARIANT RECOUPLE: r_L + r_S == vault_balance ---
-        // After scaling by capped factors, reserves may drift from vault due to clamping/rounding.
-        // Proportionally adjust reserves to maintain the invariant.
-        let total_after = (pool.r_long as u128)
-            .checked_add(pool.r_short as u128)
-            .ok_or(ContentPoolError::NumericalOverflow)?;
-
-        if total_after > 0 {
-            let target = pool.vault_balance as u128;
-            if total_after != target {
-                // Proportional recouple: scale both reserves to sum to vault_balance
-                let r_long_new = mul_div_u128(pool.r_long as u128, target, total_after)?;
-                pool.r_long = r_long_new as u64;
-                pool.r_short = (target.saturating_sub(r_long_new)) as u64;
-            }
+    let reserves = if zero_sum {
+        // Zero-sum relevance transfer: r_L + r_S == vault_balance by construction,
+        // so there is no factor step and nothing to recouple
+        let (long, short) = zero_sum_reserves(pool.vault_balance, bd_score)?;
+        SettledReserves {
+            long_pre_recouple: long as u128,
+            short_pre_recouple: short as u128,
+            long,
+            short,
         }
-    }
+    } else {
+        // Multiplicative scaling with recouple (direct redistribution on extreme imbalance)
+        settled_reserves(
+            pool.r_long,
+            pool.r_short,
+            pool.vault_balance,
+            bd_score,
+            f_long,
+            f_short,
+        )?
+    };
+    pool.r_long = reserves.long;
+    pool.r_short = reserves.short;
 
     // DO NOT UPDATE vault_balance, s_long, s_short here!
     //
@@ -183,7 +164,7 @@ pub fn handler(
     // vault_balance = λ × ||ŝ_v|| where ŝ_v = s_display / σ
     //
     // This means λ automatically adjusts after settlements to maintain the invariant.
-    // The old sqrt_lambda_* fields are deprecated but kept for backward compatibility.
+    // The lambda_*_q96 fields are deprecated telemetry, refreshed below for old readers.
 
     // --- PRICE RECOMPUTATION (recommended for UX) ---
     // Settlement changed σ, which affects virtual supplies and thus display prices.
@@ -203,7 +184,7 @@ pub fn handler(
     };
 
     // Derive λ with current σ (vault unchanged)
-    let lambda_q96 = derive_lambda(&ctx.accounts.vault, &pool)?;
+    let lambda_q96 = derive_lambda(pool)?;
 
     // Store display-token sqrt prices (consistent with trade.rs)
     pool.sqrt_price_long_x96 = ICBSCurve::sqrt_marginal_price_from_virtual(
@@ -230,6 +211,10 @@ pub fn handler(
         pool.beta_den,
     )?;
 
+    // Keep the deprecated telemetry fields in step with the derived λ
+    pool.lambda_long_q96 = lambda_q96;
+    pool.lambda_short_q96 = lambda_q96;
+
     // Update last settlement timestamp and increment pool epoch
     pool.last_settle_ts = clock.unix_timestamp;
     pool.current_epoch = pool.current_epoch.checked_add(1).ok_or(ContentPoolError::NumericalOverflow)?;
@@ -241,15 +226,21 @@ pub fn handler(
         epoch: pool.current_epoch,
         bd_score,
         market_prediction_q: q as u128,
+        zero_sum,
+        forced: force,
         f_long: f_long as u128,
         f_short: f_short as u128,
         r_long_before: r_long_before as u128,
         r_short_before: r_short_before as u128,
+        r_long_pre_recouple: reserves.long_pre_recouple,
+        r_short_pre_recouple: reserves.short_pre_recouple,
         r_long_after: pool.r_long as u128,
         r_short_after: pool.r_short as u128,
         s_scale_long_before: scale_long_before,
+        s_scale_long_pre_renorm: scales.long_pre_renorm,
         s_scale_long_after: pool.s_scale_long_q64,
         s_scale_short_before: scale_short_before,
+        s_scale_short_pre_renorm: scales.short_pre_renorm,
         s_scale_short_after: pool.s_scale_short_q64,
         timestamp: clock.unix_timestamp,
     });
@@ -259,27 +250,13 @@ pub fn handler(
 
 // Helper functions
 
-/// Integer square root for u128 (floor)
-fn isqrt_u128(n: u128) -> u128 {
-    if n == 0 {
-        return 0;
-    }
-    let mut x = n;
-    let mut y = (x + 1) / 2;
-    while y < x {
-        x = y;
-        y = (x + n / x) / 2;
-    }
-    x
-}
-
-/// Derive λ from current pool state (vault, σ, s_v)
+/// Derive λ from current pool state (vault_balance, σ, s_v)
 ///
 /// λ is NOT stored; it's derived fresh each time from the invariant:
 /// vault_balance = λ × ||ŝ_v||
 ///
 /// This ensures λ automatically adjusts to keep the invariant after trades/settlements.
-fn derive_lambda(vault: &Account<TokenAccount>, pool: &ContentPool) -> Result<u128> {
+fn derive_lambda(pool: &ContentPool) -> Result<u128> {
     // 1. Compute virtual supplies with CEILING division to prevent zero
     let s_long_virtual = if pool.s_long > 0 {
         ceil_div(pool.s_long as u128 * Q64, pool.s_scale_long_q64).max(1)
@@ -308,13 +285,12 @@ fn derive_lambda(vault: &Account<TokenAccount>, pool: &ContentPool) -> Result<u1
         .checked_mul(s_long_virtual)
         .and_then(|v| v.checked_add(s_short_virtual.checked_mul(s_short_virtual)?))
         .ok_or(ContentPoolError::NumericalOverflow)?;
-    let norm = isqrt_u128(norm_sq).max(1); // min 1 to avoid div-by-zero
+    let norm = isqrt_u128(norm_sq)?.max(1); // min 1 to avoid div-by-zero
 
     // 4. Derive λ using DIVISION-FIRST to avoid overflow
     // Instead of: lambda_q96 = (vault * Q96) / norm  (can overflow at multiply)
     // We do: lambda_q96 = (vault / norm) * Q96 + (vault % norm * Q96) / norm
-    let vault_balance = vault.amount;
-    let a = vault_balance as u128;
+    let a = pool.vault_balance as u128;
     let d = norm;
     let q = a / d;
     let r = a % d;
@@ -329,4 +305,60 @@ fn derive_lambda(vault: &Account<TokenAccount>, pool: &ContentPool) -> Result<u1
         .ok_or(ContentPoolError::NumericalOverflow)?;
 
     Ok(lambda_q96)
-}
\ No newline at end of file
+}
+
+/// Reject pools with no market to settle
+///
+/// An undeployed or fully drained pool has zero reserves: market_prediction_q would fall
+/// back to 50%, the reserve scaling would be a no-op, and settlement would still advance
+/// the epoch and emit a SettlementEvent for a pool nobody can trade.
+fn ensure_has_market(pool: &ContentPool) -> Result<()> {
+    require!(
+        pool.market_deployer != Pubkey::default()
+            && (pool.r_long as u128 + pool.r_short as u128) > 0,
+        ContentPoolError::NoLiquidity
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::instructions::simulate_buy_then_settle::project_buy;
+    use crate::content_pool::test_utils::{balanced_pool, zeroed_pool};
+
+    #[test]
+    fn test_trade_between_observation_and_settlement_trips_q_guard() {
+        let mut pool = balanced_pool();
+
+        // Operator observes q = 50% and computes the settlement against it
+        let observed_bps = (market_prediction_q(pool.r_long, pool.r_short) / 100) as u16;
+        assert_eq!(observed_bps, 5_000);
+        assert!(q_within_tolerance(market_prediction_q(pool.r_long, pool.r_short), observed_bps, 50));
+
+        // A 20 USDC LONG buy lands first and drags q well past a 0.5% tolerance
+        project_buy(&mut pool, TokenSide::Long, 20_000_000, 0).unwrap();
+        let live_q = market_prediction_q(pool.r_long, pool.r_short);
+        assert!(live_q > 550_000, "live q {}", live_q);
+        assert!(!q_within_tolerance(live_q, observed_bps, 50));
+
+        // A loose enough tolerance still lets it through
+        assert!(q_within_tolerance(live_q, observed_bps, 2_500));
+    }
+
+    #[test]
+    fn test_settlement_requires_a_market() {
+        let mut pool = zeroed_pool();
+
+        // Created but never deployed
+        assert_eq!(ensure_has_market(&pool).unwrap_err(), ContentPoolError::NoLiquidity.into());
+
+        // Deployed, then drained to zero reserves
+        pool.market_deployer = Pubkey::new_unique();
+        assert_eq!(ensure_has_market(&pool).unwrap_err(), ContentPoolError::NoLiquidity.into());
+
+        pool.r_long = 50_000_000;
+        pool.r_short = 50_000_000;
+        assert!(ensure_has_market(&pool).is_ok());
+    }
+}
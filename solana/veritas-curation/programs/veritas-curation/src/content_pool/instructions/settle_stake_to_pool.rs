@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::pool_factory::state::PoolFactory;
+use crate::veritas_custodian::state::{VeritasCustodian, CUSTODIAN_SEED};
+use crate::content_pool::{
+    state::*,
+    events::StakeSettledToPoolEvent,
+    errors::ContentPoolError,
+    curve::ICBSCurve,
+    math::ceil_div,
+};
+
+#[derive(Accounts)]
+pub struct SettleStakeToPool<'info> {
+    #[account(
+        mut,
+        seeds = [CUSTODIAN_SEED],
+        bump = custodian.bump
+    )]
+    pub custodian: Account<'info, VeritasCustodian>,
+
+    #[account(
+        mut,
+        constraint = custodian_usdc_vault.key() == custodian.usdc_vault @ ErrorCode::InvalidVault
+    )]
+    pub custodian_usdc_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump,
+        constraint = !pool.closed @ ContentPoolError::PoolClosed
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ ContentPoolError::InvalidVault,
+        constraint = pool_vault.mint == custodian_usdc_vault.mint @ ContentPoolError::InvalidMint
+    )]
+    pub pool_vault: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Move skimmed stake from the custodian into a pool's vault as a reward
+///
+/// Supplies are unchanged, so the extra USDC raises λ: reserves and sqrt prices are
+/// re-derived from the new vault balance, keeping r_long + r_short == vault_balance.
+pub fn handler(ctx: Context<SettleStakeToPool>, amount: u64) -> Result<()> {
+    let custodian = &mut ctx.accounts.custodian;
+    let pool = &mut ctx.accounts.pool;
+
+    // Same gates as a custodian withdrawal
+    require!(!custodian.emergency_pause, ErrorCode::SystemPaused);
+    require!(
+        ctx.accounts.authority.key() == custodian.protocol_authority,
+        ErrorCode::Unauthorized
+    );
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        amount <= ctx.accounts.custodian_usdc_vault.amount,
+        ErrorCode::InsufficientVaultBalance
+    );
+
+    // A reward needs supplies to price against
+    require!(
+        pool.market_deployer != Pubkey::default(),
+        ContentPoolError::MarketNotDeployed
+    );
+    require!(pool.s_long > 0 || pool.s_short > 0, ContentPoolError::NoLiquidity);
+
+    custodian.total_withdrawals = custodian.total_withdrawals
+        .checked_add(amount as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    let seeds = &[
+        CUSTODIAN_SEED,
+        &[custodian.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.custodian_usdc_vault.to_account_info(),
+                to: ctx.accounts.pool_vault.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    pool.vault_balance = pool
+        .vault_balance
+        .checked_add(amount)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+
+    // Virtual supplies (σ unchanged) and λ from the new vault balance
+    let s_long_v = if pool.s_long > 0 {
+        ceil_div(pool.s_long as u128 * Q64, pool.s_scale_long_q64).max(1)
+    } else {
+        0
+    };
+    let s_short_v = if pool.s_short > 0 {
+        ceil_div(pool.s_short as u128 * Q64, pool.s_scale_short_q64).max(1)
+    } else {
+        0
+    };
+    let lambda_q96 = super::trade::lambda_from_virtual(pool.vault_balance, s_long_v, s_short_v)?;
+
+    pool.sqrt_price_long_x96 = ICBSCurve::sqrt_marginal_price_from_virtual(
+        s_long_v as u64,
+        s_short_v as u64,
+        TokenSide::Long,
+        lambda_q96,
+        pool.s_scale_long_q64,
+        pool.s_scale_short_q64,
+        pool.f,
+        pool.beta_num,
+        pool.beta_den,
+    )?;
+    pool.sqrt_price_short_x96 = ICBSCurve::sqrt_marginal_price_from_virtual(
+        s_long_v as u64,
+        s_short_v as u64,
+        TokenSide::Short,
+        lambda_q96,
+        pool.s_scale_long_q64,
+        pool.s_scale_short_q64,
+        pool.f,
+        pool.beta_num,
+        pool.beta_den,
+    )?;
+
+    // ENFORCE INVARIANT: r_long + r_short = vault_balance
+    let r_long_calc = ICBSCurve::reserve_from_lambda_and_virtual(
+        s_long_v as u64,
+        s_short_v as u64,
+        lambda_q96,
+    )?;
+    pool.r_long = r_long_calc.min(pool.vault_balance);
+    pool.r_short = pool.vault_balance.saturating_sub(pool.r_long);
+
+    // Keep the deprecated telemetry fields in step with the derived λ
+    pool.lambda_long_q96 = lambda_q96;
+    pool.lambda_short_q96 = lambda_q96;
+
+    emit!(StakeSettledToPoolEvent {
+        pool: pool.key(),
+        custodian: custodian.key(),
+        amount,
+        new_vault_balance: pool.vault_balance,
+        new_r_long: pool.r_long,
+        new_r_short: pool.r_short,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Stake settled to pool: pool={}, amount={}", pool.key(), amount);
+    Ok(())
+}
@@ -0,0 +1,345 @@
+//! View-only instruction: What-if of a buy followed by a settlement at a given BD score
+//!
+//! Does NOT mutate on-chain state - runs the trade buy path on a copy of the pool, then
+//! the settle_epoch reserve math on that projected state, and values the bought tokens.
+//! A what-if tool, not a guarantee: other trades, settlements or liquidity changes
+//! landing first will change the outcome.
+//! Used by: strategy backtesting, "what if I buy and the score comes in at Y" UIs
+
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::*,
+    errors::ContentPoolError,
+    curve::ICBSCurve,
+    math::{mul_div_u128, renormalize_scales, round_to_nearest},
+    settlement::{market_prediction_q, settlement_factors, floored_factors, settled_scales, scaled_reserves, zero_sum_reserves},
+};
+use super::trade::{calc_fees, calc_pol, effective_fee_config, virtual_supplies, lambda_from_virtual};
+
+#[derive(Accounts)]
+pub struct SimulateBuyThenSettle<'info> {
+    /// CHECK: Read-only account, no validation needed
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn handler(
+    ctx: Context<SimulateBuyThenSettle>,
+    side: TokenSide,
+    amount: u64,
+    stake_skim: u64,
+    bd_score: u32,
+    zero_sum: bool,
+) -> Result<BuyThenSettleSimulation> {
+    let factory = &ctx.accounts.factory;
+    let mut pool = ContentPool::clone(&ctx.accounts.pool);
+
+    require!(pool.market_deployer != Pubkey::default(), ContentPoolError::MarketNotDeployed);
+    require!(
+        (MIN_TRADE_SIZE..=MAX_TRADE_SIZE).contains(&amount),
+        ContentPoolError::InvalidTradeAmount
+    );
+    require!(stake_skim <= amount / 2, ContentPoolError::InvalidStakeSkim);
+    require!(bd_score <= 1_000_000, ContentPoolError::InvalidBDScore);
+
+    // Same split as a trade buy: skim, then fees and POL out of the remainder
+    let after_skim = amount - stake_skim;
+    let (total_fee_bps, _) = effective_fee_config(&pool, factory);
+    let (total_fee, _, _) = calc_fees(after_skim, total_fee_bps, 0)?;
+    let usdc_to_trade = after_skim
+        .checked_sub(total_fee)
+        .ok_or(ContentPoolError::FeeCalculationOverflow)?;
+    let usdc_to_pol = calc_pol(after_skim, factory.pol_bps)?;
+
+    let tokens_display = project_buy(&mut pool, side, usdc_to_trade, usdc_to_pol)?;
+    let (r_long_after_buy, r_short_after_buy) = (pool.r_long, pool.r_short);
+    let value_after_buy = position_value(&pool, side, tokens_display)?;
+
+    project_settlement(&mut pool, bd_score, zero_sum)?;
+
+    Ok(BuyThenSettleSimulation {
+        tokens_display,
+        total_fee,
+        usdc_to_trade,
+        s_long_after: pool.s_long,
+        s_short_after: pool.s_short,
+        r_long_after_buy,
+        r_short_after_buy,
+        value_after_buy,
+        r_long_after_settle: pool.r_long,
+        r_short_after_settle: pool.r_short,
+        value_after_settle: position_value(&pool, side, tokens_display)?,
+    })
+}
+
+/// Apply a buy of `usdc_to_trade` (of which `usdc_to_pol` bypasses the curve) to `pool`
+///
+/// Mirrors the trade buy path: σ renormalized, λ from vault + virtual supplies, curve on
+/// virtual supplies, display delta rounded to nearest, reserves from λ clamped to the vault.
+/// Pools trade would refuse a buy on (closed, winding down) fail with trade's error.
+/// Returns the display tokens minted.
+pub(super) fn project_buy(pool: &mut ContentPool, side: TokenSide, usdc_to_trade: u64, usdc_to_pol: u64) -> Result<u64> {
+    require!(!pool.closed, ContentPoolError::PoolClosed);
+    require!(!pool.wind_down, ContentPoolError::PoolWindingDown);
+
+    let usdc_to_curve = usdc_to_trade
+        .checked_sub(usdc_to_pol)
+        .ok_or(ContentPoolError::FeeCalculationOverflow)?;
+
+    renormalize_scales(
+        &mut pool.s_scale_long_q64,
+        &mut pool.s_scale_short_q64,
+        pool.s_long,
+        pool.s_short,
+    );
+    let (s_long_virtual, s_short_virtual) = virtual_supplies(pool)?;
+    let lambda_q96 = lambda_from_virtual(pool.vault_balance, s_long_virtual, s_short_virtual)?;
+
+    let is_long = side == TokenSide::Long;
+    let (s_side_virtual, s_other_virtual, sigma_side_q64) = if is_long {
+        (s_long_virtual, s_short_virtual, pool.s_scale_long_q64)
+    } else {
+        (s_short_virtual, s_long_virtual, pool.s_scale_short_q64)
+    };
+    let (delta_virtual, _) = ICBSCurve::calculate_buy(
+        s_side_virtual as u64,
+        usdc_to_curve,
+        lambda_q96,
+        s_other_virtual as u64,
+        pool.f,
+        pool.beta_num,
+        pool.beta_den,
+        is_long,
+        pool.s_scale_long_q64,
+        pool.s_scale_short_q64,
+    )?;
+    let delta_display = round_to_nearest(delta_virtual as u128 * sigma_side_q64, Q64);
+    require!(
+        delta_display > 0 || usdc_to_curve == 0,
+        ContentPoolError::TooSmallAfterRounding
+    );
+
+    let supply = if is_long { &mut pool.s_long } else { &mut pool.s_short };
+    *supply = supply
+        .checked_add(delta_display)
+        .filter(|&s| s <= S_DISPLAY_CAP)
+        .ok_or(ContentPoolError::SupplyOverflow)?;
+    pool.vault_balance = pool
+        .vault_balance
+        .checked_add(usdc_to_trade)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+
+    let (s_long_v_after, s_short_v_after) = if is_long {
+        (s_long_virtual + delta_virtual as u128, s_short_virtual)
+    } else {
+        (s_long_virtual, s_short_virtual + delta_virtual as u128)
+    };
+    // POL grew the vault beyond what the curve priced: re-derive λ, as trade does
+    let lambda_q96 = if usdc_to_pol > 0 {
+        lambda_from_virtual(pool.vault_balance, s_long_v_after, s_short_v_after)?
+    } else {
+        lambda_q96
+    };
+    let r_long = ICBSCurve::reserve_from_lambda_and_virtual(
+        s_long_v_after as u64,
+        s_short_v_after as u64,
+        lambda_q96,
+    )?;
+    pool.r_long = r_long.min(pool.vault_balance);
+    pool.r_short = pool.vault_balance.saturating_sub(pool.r_long);
+
+    Ok(delta_display)
+}
+
+/// Mirrors the trade sell path: floor checks, σ renormalized, curve on virtual
+/// supplies, gross proceeds out of the vault, reserves from λ clamped to the vault.
+/// Returns the gross µUSDC out, before fees.
+pub(super) fn project_sell(pool: &mut ContentPool, side: TokenSide, sell_display: u64) -> Result<u64> {
+    let is_long = side == TokenSide::Long;
+    let (s_self, s_other) = if is_long {
+        (pool.s_long, pool.s_short)
+    } else {
+        (pool.s_short, pool.s_long)
+    };
+    require!(sell_display >= MIN_TOKEN_TRADE_SIZE, ContentPoolError::InvalidTradeAmount);
+    require!(
+        sell_display <= ICBSCurve::max_sellable(s_self, MIN_POOL_LIQUIDITY)
+            && s_other >= MIN_POOL_LIQUIDITY,
+        ContentPoolError::NoLiquidity
+    );
+
+    renormalize_scales(
+        &mut pool.s_scale_long_q64,
+        &mut pool.s_scale_short_q64,
+        pool.s_long,
+        pool.s_short,
+    );
+    let (s_long_virtual, s_short_virtual) = virtual_supplies(pool)?;
+    let lambda_q96 = lambda_from_virtual(pool.vault_balance, s_long_virtual, s_short_virtual)?;
+
+    let (s_side_virtual, s_other_virtual, sigma_side_q64) = if is_long {
+        (s_long_virtual, s_short_virtual, pool.s_scale_long_q64)
+    } else {
+        (s_short_virtual, s_long_virtual, pool.s_scale_short_q64)
+    };
+    let sell_virtual = round_to_nearest(sell_display as u128 * Q64, sigma_side_q64);
+    require!(sell_virtual > 0, ContentPoolError::TooSmallAfterRounding);
+
+    let (gross_usdc_out, _) = ICBSCurve::calculate_sell(
+        s_side_virtual as u64,
+        sell_virtual,
+        lambda_q96,
+        s_other_virtual as u64,
+        pool.f,
+        pool.beta_num,
+        pool.beta_den,
+        is_long,
+        pool.s_scale_long_q64,
+        pool.s_scale_short_q64,
+    )?;
+
+    pool.vault_balance = pool
+        .vault_balance
+        .checked_sub(gross_usdc_out)
+        .ok_or(ContentPoolError::InsufficientBalance)?;
+    let s_side_v_after = (s_side_virtual as u64)
+        .checked_sub(sell_virtual)
+        .ok_or(ContentPoolError::InsufficientBalance)?;
+    let (s_long_v_after, s_short_v_after) = if is_long {
+        pool.s_long -= sell_display;
+        (s_side_v_after, s_short_virtual as u64)
+    } else {
+        pool.s_short -= sell_display;
+        (s_long_virtual as u64, s_side_v_after)
+    };
+
+    let r_long = ICBSCurve::reserve_from_lambda_and_virtual(s_long_v_after, s_short_v_after, lambda_q96)?;
+    pool.r_long = r_long.min(pool.vault_balance);
+    pool.r_short = pool.vault_balance.saturating_sub(pool.r_long);
+    Ok(gross_usdc_out)
+}
+
+/// Apply settle_epoch's σ and reserve update for `bd_score` to `pool`
+fn project_settlement(pool: &mut ContentPool, bd_score: u32, zero_sum: bool) -> Result<()> {
+    let q = market_prediction_q(pool.r_long, pool.r_short);
+    let (f_long, f_short) = settlement_factors(bd_score, q);
+    let (f_long, f_short) =
+        floored_factors(pool.r_long, pool.r_short, pool.vault_balance, f_long, f_short)?;
+
+    let scales = settled_scales(
+        pool.s_scale_long_q64,
+        pool.s_scale_short_q64,
+        f_long,
+        f_short,
+        pool.s_long,
+        pool.s_short,
+    )?;
+    pool.s_scale_long_q64 = scales.long;
+    pool.s_scale_short_q64 = scales.short;
+
+    (pool.r_long, pool.r_short) = if zero_sum {
+        zero_sum_reserves(pool.vault_balance, bd_score)?
+    } else {
+        scaled_reserves(pool.r_long, pool.r_short, pool.vault_balance, bd_score, f_long, f_short)?
+    };
+    Ok(())
+}
+
+/// Value of `tokens` display tokens at the side's reserve / supply price (µUSDC)
+fn position_value(pool: &ContentPool, side: TokenSide, tokens: u64) -> Result<u64> {
+    let (reserve, supply) = match side {
+        TokenSide::Long => (pool.r_long, pool.s_long),
+        TokenSide::Short => (pool.r_short, pool.s_short),
+    };
+    if supply == 0 {
+        return Ok(0);
+    }
+    Ok(mul_div_u128(tokens as u128, reserve as u128, supply as u128)? as u64)
+}
+
+/// Return type for simulate_buy_then_settle view function
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BuyThenSettleSimulation {
+    /// Display tokens the buy would mint at current state
+    pub tokens_display: u64,
+    /// Trading fee taken from the buy (µUSDC)
+    pub total_fee: u64,
+    /// USDC the buy adds to the vault, POL included (µUSDC)
+    pub usdc_to_trade: u64,
+    /// LONG supply after the buy (display tokens)
+    pub s_long_after: u64,
+    /// SHORT supply after the buy (display tokens)
+    pub s_short_after: u64,
+    /// LONG reserve after the buy, before settlement (µUSDC)
+    pub r_long_after_buy: u64,
+    /// SHORT reserve after the buy, before settlement (µUSDC)
+    pub r_short_after_buy: u64,
+    /// Bought tokens valued at reserve / supply after the buy (µUSDC)
+    pub value_after_buy: u64,
+    /// LONG reserve after the projected settlement (µUSDC)
+    pub r_long_after_settle: u64,
+    /// SHORT reserve after the projected settlement (µUSDC)
+    pub r_short_after_settle: u64,
+    /// Bought tokens valued at reserve / supply after the settlement (µUSDC)
+    pub value_after_settle: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::balanced_pool;
+
+    #[test]
+    fn test_buy_then_settle_tracks_bd_score() {
+        let mut pool = balanced_pool();
+        let tokens = project_buy(&mut pool, TokenSide::Long, 10_000_000, 0).unwrap();
+        assert!(tokens > 0);
+        assert_eq!(pool.s_long, 1_000 + tokens);
+        assert_eq!(pool.r_long + pool.r_short, 110_000_000);
+        let value_after_buy = position_value(&pool, TokenSide::Long, tokens).unwrap();
+
+        // Score above the post-buy q: the LONG position is worth more after settlement
+        let mut up = pool.clone();
+        project_settlement(&mut up, 800_000, false).unwrap();
+        assert_eq!(up.r_long + up.r_short, 110_000_000);
+        assert!(position_value(&up, TokenSide::Long, tokens).unwrap() > value_after_buy);
+
+        // Score below it: worth less
+        let mut down = pool.clone();
+        project_settlement(&mut down, 200_000, false).unwrap();
+        assert!(position_value(&down, TokenSide::Long, tokens).unwrap() < value_after_buy);
+    }
+
+    #[test]
+    fn test_settle_at_current_q_keeps_value() {
+        let mut pool = balanced_pool();
+        let tokens = project_buy(&mut pool, TokenSide::Short, 5_000_000, 0).unwrap();
+        let before = position_value(&pool, TokenSide::Short, tokens).unwrap();
+
+        let q = market_prediction_q(pool.r_long, pool.r_short);
+        project_settlement(&mut pool, q as u32, false).unwrap();
+        let after = position_value(&pool, TokenSide::Short, tokens).unwrap();
+        assert!(before.abs_diff(after) <= 1, "neutral settlement moved value {} -> {}", before, after);
+    }
+
+    #[test]
+    fn test_projected_buy_refused_where_trade_refuses() {
+        let mut pool = balanced_pool();
+        pool.wind_down = true;
+        assert_eq!(
+            project_buy(&mut pool, TokenSide::Long, 10_000_000, 0).unwrap_err(),
+            ContentPoolError::PoolWindingDown.into()
+        );
+
+        pool.closed = true;
+        assert_eq!(
+            project_buy(&mut pool, TokenSide::Long, 10_000_000, 0).unwrap_err(),
+            ContentPoolError::PoolClosed.into()
+        );
+    }
+}
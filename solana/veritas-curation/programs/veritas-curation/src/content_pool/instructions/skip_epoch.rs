@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::*,
+    events::SettlementEvent,
+    errors::ContentPoolError,
+    settlement::{market_prediction_q, cooldown_satisfied, MICRO},
+};
+
+#[derive(Accounts)]
+pub struct SkipEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump,
+        constraint = !pool.closed @ ContentPoolError::PoolClosed
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    #[account(
+        constraint = protocol_authority.key() == factory.protocol_authority @ ContentPoolError::UnauthorizedProtocol
+    )]
+    pub protocol_authority: Signer<'info>,
+}
+
+/// Advance the epoch without settling (no reliable BD signal this epoch)
+///
+/// Only current_epoch and last_settle_ts change; reserves, σ and prices are left
+/// bit-identical rather than settling at the current q, which can drift via rounding.
+/// The SettlementEvent reports factors of exactly 1.0 so indexers see a neutral epoch.
+pub fn handler(ctx: Context<SkipEpoch>, force: bool) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(
+        cooldown_satisfied(
+            pool.last_settle_ts,
+            ctx.accounts.factory.effective_settle_interval(pool.min_settle_interval),
+            clock.unix_timestamp,
+            force,
+        ),
+        ContentPoolError::SettlementCooldown
+    );
+    if force {
+        msg!("skip_epoch: forced, cooldown skipped");
+    }
+
+    advance_epoch(pool, clock.unix_timestamp)?;
+
+    let q = market_prediction_q(pool.r_long, pool.r_short);
+    emit!(SettlementEvent {
+        pool: pool.key(),
+        settler: ctx.accounts.protocol_authority.key(),
+        epoch: pool.current_epoch,
+        bd_score: q as u32,
+        market_prediction_q: q as u128,
+        zero_sum: false,
+        forced: force,
+        f_long: MICRO as u128,
+        f_short: MICRO as u128,
+        r_long_before: pool.r_long as u128,
+        r_short_before: pool.r_short as u128,
+        r_long_pre_recouple: pool.r_long as u128,
+        r_short_pre_recouple: pool.r_short as u128,
+        r_long_after: pool.r_long as u128,
+        r_short_after: pool.r_short as u128,
+        s_scale_long_before: pool.s_scale_long_q64,
+        s_scale_long_pre_renorm: pool.s_scale_long_q64,
+        s_scale_long_after: pool.s_scale_long_q64,
+        s_scale_short_before: pool.s_scale_short_q64,
+        s_scale_short_pre_renorm: pool.s_scale_short_q64,
+        s_scale_short_after: pool.s_scale_short_q64,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// The only state a skipped epoch touches: epoch counter and settlement timestamp
+pub(crate) fn advance_epoch(pool: &mut ContentPool, now: i64) -> Result<()> {
+    pool.last_settle_ts = now;
+    pool.current_epoch = pool.current_epoch.checked_add(1).ok_or(ContentPoolError::NumericalOverflow)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::zeroed_pool;
+
+    #[test]
+    fn test_advance_epoch_leaves_market_untouched() {
+        let mut pool = zeroed_pool();
+        pool.r_long = 123_456_789;
+        pool.r_short = 987_654_321;
+        pool.s_scale_long_q64 = Q64 + 7;
+        pool.s_scale_short_q64 = Q64 - 3;
+        pool.sqrt_price_long_x96 = 1 << 90;
+        pool.current_epoch = 4;
+
+        let mut before = Vec::new();
+        pool.try_serialize(&mut before).unwrap();
+
+        advance_epoch(&mut pool, 1_700_000_000).unwrap();
+        assert_eq!(pool.current_epoch, 5);
+        assert_eq!(pool.last_settle_ts, 1_700_000_000);
+
+        // Restore the two advanced fields: every other byte must be identical
+        pool.current_epoch = 4;
+        pool.last_settle_ts = 0;
+        let mut after = Vec::new();
+        pool.try_serialize(&mut after).unwrap();
+        assert_eq!(before, after);
+    }
+}
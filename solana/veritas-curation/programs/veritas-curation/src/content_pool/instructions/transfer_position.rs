@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use crate::content_pool::{
+    state::ContentPool,
+    events::PositionTransferredEvent,
+    errors::ContentPoolError,
+};
+use super::trade::atomic_to_display_exact;
+
+#[derive(Accounts)]
+pub struct TransferPosition<'info> {
+    #[account(
+        seeds = [b"content_pool", pool.content_id.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = long_mint.key() == pool.long_mint @ ContentPoolError::InvalidMint
+    )]
+    pub long_mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = short_mint.key() == pool.short_mint @ ContentPoolError::InvalidMint
+    )]
+    pub short_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = long_mint,
+        associated_token::authority = sender
+    )]
+    pub sender_long: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = short_mint,
+        associated_token::authority = sender
+    )]
+    pub sender_short: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = long_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_long: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = short_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_short: Account<'info, TokenAccount>,
+
+    pub sender: Signer<'info>,
+
+    /// CHECK: Recipient wallet; only used as the ATA authority
+    #[account(
+        constraint = recipient.key() != sender.key() @ ContentPoolError::InvalidParameter
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move LONG and/or SHORT tokens between wallets off-curve and record it
+///
+/// A plain SPL transfer leaves indexers blind to OTC moves; this one emits
+/// PositionTransferredEvent. No curve interaction, no fees, pool state untouched.
+/// Amounts are atomic SPL units and must be whole tokens, like sells.
+pub fn handler(
+    ctx: Context<TransferPosition>,
+    long_amount: u64,
+    short_amount: u64,
+) -> Result<()> {
+    require!(
+        long_amount > 0 || short_amount > 0,
+        ContentPoolError::InvalidTradeAmount
+    );
+    let long_display = atomic_to_display_exact(long_amount)?;
+    let short_display = atomic_to_display_exact(short_amount)?;
+
+    let accounts = &ctx.accounts;
+    for (from, to, amount) in [
+        (&accounts.sender_long, &accounts.recipient_long, long_amount),
+        (&accounts.sender_short, &accounts.recipient_short, short_amount),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        token::transfer(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: accounts.sender.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    emit!(PositionTransferredEvent {
+        pool: accounts.pool.key(),
+        from: accounts.sender.key(),
+        to: accounts.recipient.key(),
+        long_tokens: long_display,
+        short_tokens: short_display,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! Read-only instruction: Verifies a pool's fee recipient accounts
+//!
+//! Does NOT mutate on-chain state - confirms the post creator and protocol
+//! treasury USDC accounts exist with the right owner and mint, so a missing
+//! or misconfigured ATA surfaces at pool creation instead of deep inside
+//! the first trade's fee transfer.
+//! Used by: pool creation flow (same transaction as create_pool)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::pool_factory::state::PoolFactory;
+use crate::content_pool::{
+    state::ContentPool,
+    errors::ContentPoolError,
+};
+
+#[derive(Accounts)]
+pub struct VerifyFeeAccounts<'info> {
+    pub pool: Account<'info, ContentPool>,
+
+    #[account(
+        constraint = factory.key() == pool.factory @ ContentPoolError::InvalidFactory
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    /// Custodian stake vault - reference for the USDC mint
+    #[account(
+        constraint = stake_vault.key() == pool.stake_vault @ ContentPoolError::InvalidStakeVault
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = post_creator_usdc_account.owner == pool.post_creator @ ContentPoolError::InvalidPostCreator,
+        constraint = post_creator_usdc_account.mint == stake_vault.mint @ ContentPoolError::InvalidMint
+    )]
+    pub post_creator_usdc_account: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = protocol_treasury_usdc_account.owner == factory.protocol_treasury @ ContentPoolError::InvalidProtocolTreasury,
+        constraint = protocol_treasury_usdc_account.mint == stake_vault.mint @ ContentPoolError::InvalidMint
+    )]
+    pub protocol_treasury_usdc_account: Account<'info, TokenAccount>,
+}
+
+pub fn handler(_ctx: Context<VerifyFeeAccounts>) -> Result<()> {
+    // All checks are account constraints - reaching here means both fee accounts are valid
+    Ok(())
+}
@@ -74,20 +74,38 @@ pub fn mul_div_u128(a: u128, b: u128, d: u128) -> Result<u128> {
     div_256_by_128(hi, lo, d)
 }
 
-/// Plain integer sqrt for u128 (floor)
+/// Cap on Newton steps for integer sqrt
+///
+/// Seeded at n, the iteration halves until it nears √n, so even u128::MAX converges in
+/// about 70 steps. The cap only trips if a future change breaks monotone convergence.
+pub const MAX_SQRT_ITERATIONS: u32 = 200;
+
+/// Integer sqrt for u128 (floor) - the one Newton loop every content_pool sqrt goes through
+///
+/// Errors with SolverConvergenceFailed rather than spinning past MAX_SQRT_ITERATIONS.
 #[inline]
-fn isqrt_u128(n: u128) -> u128 {
+pub fn isqrt_u128(n: u128) -> Result<u128> {
+    newton_isqrt(n, MAX_SQRT_ITERATIONS).ok_or(ContentPoolError::SolverConvergenceFailed.into())
+}
+
+/// Newton's method on integers, or None if it has not converged within `max_iterations`
+#[inline]
+fn newton_isqrt(n: u128, max_iterations: u32) -> Option<u128> {
     if n == 0 {
-        return 0;
+        return Some(0);
     }
-    // Newton's method on integers (fast and deterministic)
     let mut x = n;
-    let mut y = (x + 1) >> 1;
+    let mut y = x.div_ceil(2);
+    let mut iterations = 0;
     while y < x {
+        if iterations == max_iterations {
+            return None;
+        }
+        iterations += 1;
         x = y;
         y = (x + n / x) >> 1;
     }
-    x
+    Some(x)
 }
 
 /// (a_q96 * b) >> 96 for Q96 fixed-point
@@ -119,6 +137,19 @@ pub fn mul_shift_right_96(a_q96: u128, b: u128) -> Result<u128> {
     Ok(result)
 }
 
+/// num / den in lowest terms (den > 0)
+///
+/// The curve checks β by exact integers (1 / 2), so an equal ratio such as 2 / 4 must be
+/// reduced before it is stored.
+pub fn reduce_fraction(num: u16, den: u16) -> (u16, u16) {
+    let (mut a, mut b) = (num, den);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    // a = gcd(num, den) >= 1 since den > 0
+    (num / a, den / a)
+}
+
 /// Q64.64 fixed-point math library
 /// 64 bits for integer part, 64 bits for fractional part
 pub mod q64 {
@@ -175,7 +206,7 @@ pub mod q64 {
         let int_part = n >> 64;
         // Seed near sqrt(X) in Q64.64 scale
         let mut x = if int_part > 0 {
-            isqrt_u128(int_part) << 64
+            isqrt_u128(int_part)? << 64
         } else {
             1u128 << 63 // 0.5 in Q64.64 to avoid div by zero for 0<X<1
         };
@@ -218,6 +249,14 @@ pub mod q32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_reduce_fraction() {
+        assert_eq!(reduce_fraction(2, 4), (1, 2));
+        assert_eq!(reduce_fraction(1, 2), (1, 2));
+        assert_eq!(reduce_fraction(45, 100), (9, 20));
+        assert_eq!(reduce_fraction(7, 7), (1, 1));
+    }
+
     #[test]
     fn test_full_mul_128() {
         // Simple case: 2 * 3
@@ -300,6 +339,29 @@ mod tests {
         assert_eq!(result, HALF);
     }
 
+    #[test]
+    fn test_isqrt_converges_well_within_bound() {
+        // Worst case is the largest input; it needs well under half the cap
+        let inputs = [0u128, 1, 2, 3, 4, 99, 1 << 64, (1 << 96) + 12_345, u64::MAX as u128, u128::MAX - 1, u128::MAX];
+        for n in inputs {
+            let root = newton_isqrt(n, MAX_SQRT_ITERATIONS / 2).unwrap();
+            assert_eq!(isqrt_u128(n).unwrap(), root);
+            assert!(root.checked_mul(root).is_some_and(|sq| sq <= n));
+            // (root + 1)² overflowing also means root is the largest valid root
+            if let Some(sq) = (root + 1).checked_mul(root + 1) {
+                assert!(sq > n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_isqrt_fails_past_iteration_cap() {
+        // A cap too small to reach √u128::MAX reports non-convergence instead of a wrong root
+        assert_eq!(newton_isqrt(u128::MAX, 3), None);
+        assert_eq!(newton_isqrt(16, 0), None);
+        assert_eq!(newton_isqrt(0, 0), Some(0));
+    }
+
     #[test]
     fn test_q64_sqrt() {
         use q64::*;
@@ -322,6 +384,35 @@ mod tests {
         let result_f64 = (result as f64) / (ONE as f64);
         assert!((result_f64 - 1.414).abs() < 0.001);
     }
+
+    #[test]
+    fn test_renormalize_keeps_sigma_in_bounds() {
+        use crate::content_pool::state::{SIGMA_MIN, SIGMA_MAX};
+
+        // Tiny LONG norm asks for a 12-bit right shift, but σ_S is already at SIGMA_MIN.
+        // A single pass used to push σ_S to 2^36.
+        let (mut sigma_long, mut sigma_short) = (1u128 << 60, SIGMA_MIN);
+        renormalize_scales(&mut sigma_long, &mut sigma_short, 1, 0);
+        assert!(sigma_short >= SIGMA_MIN && sigma_long >= SIGMA_MIN);
+        assert_eq!((sigma_long, sigma_short), (1u128 << 60, SIGMA_MIN));
+
+        // Partial headroom: shift only as far as σ_S allows (2 bits)
+        let (mut sigma_long, mut sigma_short) = (1u128 << 60, SIGMA_MIN << 2);
+        renormalize_scales(&mut sigma_long, &mut sigma_short, 1, 0);
+        assert_eq!((sigma_long, sigma_short), (1u128 << 58, SIGMA_MIN));
+
+        // Huge SHORT norm asks for a left shift, but σ_L is already at SIGMA_MAX
+        let (mut sigma_long, mut sigma_short) = (SIGMA_MAX, SIGMA_MIN);
+        renormalize_scales(&mut sigma_long, &mut sigma_short, 0, 1_000_000_000_000);
+        assert!(sigma_long <= SIGMA_MAX && sigma_short <= SIGMA_MAX);
+        assert_eq!((sigma_long, sigma_short), (SIGMA_MAX, SIGMA_MIN));
+
+        // With room on both sides the norm adjustment is unchanged
+        let (mut sigma_long, mut sigma_short) = (1u128 << 60, 1u128 << 60);
+        renormalize_scales(&mut sigma_long, &mut sigma_short, 1, 1);
+        assert!((SIGMA_MIN..1u128 << 60).contains(&sigma_long));
+        assert_eq!(sigma_long, sigma_short);
+    }
 }
 
 /// Round to nearest (banker's rounding)
@@ -355,6 +446,26 @@ fn bitlen_u128(x: u128) -> u32 {
     if x == 0 { 0 } else { 128 - x.leading_zeros() }
 }
 
+/// Largest k with both σ >> k still >= `floor` (0 if either is already below it)
+#[inline]
+fn max_shift_right(sigma_long: u128, sigma_short: u128, floor: u128) -> u32 {
+    let min_sigma = sigma_long.min(sigma_short);
+    if min_sigma < floor {
+        return 0;
+    }
+    (min_sigma / floor).ilog2()
+}
+
+/// Largest k with both σ << k still <= `cap` (0 if either is already above it)
+#[inline]
+fn max_shift_left(sigma_long: u128, sigma_short: u128, cap: u128) -> u32 {
+    let max_sigma = sigma_long.max(sigma_short);
+    if max_sigma == 0 || max_sigma > cap {
+        return 0;
+    }
+    (cap / max_sigma).ilog2()
+}
+
 /// Renormalize sigma scales to keep both sigma and virtual norm in safe ranges
 /// Power-of-2 shifts preserve exact price ratios (gauge transformation)
 /// O(1) complexity - no loops, no sqrt!
@@ -409,17 +520,21 @@ pub fn renormalize_scales(
     }
 
     // ONE-SHOT adjustment based on squared bounds
+    // The shift is capped so σ never leaves [SIGMA_MIN, SIGMA_MAX]: the sigma clamp above
+    // does not run again. When the cap binds, σ bounds win and the norm stays out of range.
     if norm_sq < VIRTUAL_NORM_MIN_SQ {
         // Need norm' >= MIN ⇒ multiply norm by 2^k ⇒ norm_sq by 2^(2k)
         // k = ceil((log2(MIN_SQ) - log2(norm_sq)) / 2)
         let need_bits = (bitlen_u128(VIRTUAL_NORM_MIN_SQ) as i32) - (bitlen_u128(norm_sq) as i32);
         let k = ((need_bits + 1) / 2).max(0) as u32;
+        let k = k.min(max_shift_right(*sigma_long, *sigma_short, SIGMA_MIN));
         *sigma_long >>= k;  // Decreasing σ increases virtuals
         *sigma_short >>= k;
     } else if norm_sq > VIRTUAL_NORM_MAX_SQ {
         // Need norm' <= MAX ⇒ divide norm by 2^k ⇒ norm_sq by 2^(2k)
         let need_bits = (bitlen_u128(norm_sq) as i32) - (bitlen_u128(VIRTUAL_NORM_MAX_SQ) as i32);
         let k = ((need_bits + 1) / 2).max(0) as u32;
+        let k = k.min(max_shift_left(*sigma_long, *sigma_short, SIGMA_MAX));
         *sigma_long <<= k;  // Increasing σ reduces virtuals
         *sigma_short <<= k;
     }
@@ -4,6 +4,9 @@ pub mod events;
 pub mod errors;
 pub mod math;
 pub mod curve;
+pub mod settlement;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 pub use state::*;
 pub use instructions::*;
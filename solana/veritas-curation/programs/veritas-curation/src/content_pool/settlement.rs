@@ -0,0 +1,526 @@
+//! Pure settlement math used by `settle_epoch`
+//!
+//! All ratios (q, BD score, factors) are in millionths: 1_000_000 = 1.0
+
+use anchor_lang::prelude::*;
+use super::errors::ContentPoolError;
+use super::math::{mul_div_u128, renormalize_scales};
+pub(crate) use super::math::isqrt_u128;
+use super::state::{F_MIN, F_MAX, MIN_PREDICTION_BPS, MAX_PREDICTION_BPS, MIN_SIDE_RESERVE, Q64};
+
+/// Millionths scale for q / BD score / settlement factors
+pub const MICRO: u64 = 1_000_000;
+
+/// When one side holds less than this share of reserves, multiplicative
+/// scaling is replaced by direct redistribution (1% in millionths)
+pub const EXTREME_IMBALANCE_THRESHOLD: u128 = 10_000;
+
+/// Market prediction q = R_L / (R_L + R_S) in millionths (50% for an empty pool)
+pub fn market_prediction_q(r_long: u64, r_short: u64) -> u64 {
+    let total_reserves = r_long as u128 + r_short as u128;
+    (r_long as u128 * MICRO as u128)
+        .checked_div(total_reserves)
+        .map_or(500_000, |q| q as u64)
+}
+
+/// Calculate raw settlement factors from BD score x and market prediction q
+/// f_L = x / q, f_S = (1 - x) / (1 - q), hard-capped to [F_MIN, F_MAX]
+///
+/// q is clamped to [0.1%, 99.9%] to prevent division issues.
+pub fn settlement_factors(bd_score: u32, q: u64) -> (u64, u64) {
+    let q_clamped = q.clamp(1_000, 999_000);
+
+    let f_long_raw = ((bd_score as u128 * MICRO as u128) / q_clamped as u128) as u64;
+
+    let one_minus_x = MICRO.saturating_sub(bd_score as u64);
+    let one_minus_q = MICRO.saturating_sub(q_clamped);
+    let f_short_raw = ((one_minus_x as u128 * MICRO as u128) / one_minus_q as u128) as u64;
+
+    (f_long_raw.clamp(F_MIN, F_MAX), f_short_raw.clamp(F_MIN, F_MAX))
+}
+
+/// Raise the factor of a side that would otherwise settle below MIN_SIDE_RESERVE
+///
+/// Applied before the factors reach both settled_scales and settled_reserves, so σ moves
+/// by the same ratio as the reserves instead of the reserve clamp alone lifting a side
+/// whose σ (and so its price) kept the unfloored move. For the side below the floor this
+/// solves r·f' / (r·f' + r_other·f_other) = floor / vault_balance, rounding f' up.
+pub fn floored_factors(
+    r_long: u64,
+    r_short: u64,
+    vault_balance: u64,
+    f_long: u64,
+    f_short: u64,
+) -> Result<(u64, u64)> {
+    let floor = MIN_SIDE_RESERVE.min(vault_balance / 2) as u128;
+    let vault = vault_balance as u128;
+    let long_scaled = r_long as u128 * f_long as u128;
+    let short_scaled = r_short as u128 * f_short as u128;
+    let total = long_scaled + short_scaled;
+    if total == 0 {
+        return Ok((f_long, f_short));
+    }
+
+    let raised = |r: u64, other_scaled: u128| -> Result<u64> {
+        let share = mul_div_u128(floor, other_scaled, r as u128 * (vault - floor))?;
+        to_u64(share + 1)
+    };
+    if r_long > 0 && mul_div_u128(vault, long_scaled, total)? < floor {
+        return Ok((raised(r_long, short_scaled)?, f_short));
+    }
+    if r_short > 0 && mul_div_u128(vault, short_scaled, total)? < floor {
+        return Ok((f_long, raised(r_short, long_scaled)?));
+    }
+    Ok((f_long, f_short))
+}
+
+/// Default settlement: scale each reserve by its factor, then recouple to vault_balance
+///
+/// When one side is < 1% of total, multiplying near-zero by any factor gives near-zero
+/// and the recouple undoes the settlement, so reserves are redistributed directly by BD score.
+pub fn scaled_reserves(
+    r_long: u64,
+    r_short: u64,
+    vault_balance: u64,
+    bd_score: u32,
+    f_long: u64,
+    f_short: u64,
+) -> Result<(u64, u64)> {
+    let reserves = settled_reserves(r_long, r_short, vault_balance, bd_score, f_long, f_short)?;
+    Ok((reserves.long, reserves.short))
+}
+
+/// scaled_reserves with the factor-step result kept alongside the recoupled reserves
+///
+/// The factor step is carried in u128: a side scaled by up to F_MAX can exceed u64 (and
+/// vault_balance) before the recouple pulls it back, and the final values are converted
+/// with a checked cast rather than truncated.
+pub fn settled_reserves(
+    r_long: u64,
+    r_short: u64,
+    vault_balance: u64,
+    bd_score: u32,
+    f_long: u64,
+    f_short: u64,
+) -> Result<SettledReserves> {
+    let total_before = (r_long as u128)
+        .checked_add(r_short as u128)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+    let is_extreme_imbalance =
+        (r_long as u128 * MICRO as u128) < (total_before * EXTREME_IMBALANCE_THRESHOLD) ||
+        (r_short as u128 * MICRO as u128) < (total_before * EXTREME_IMBALANCE_THRESHOLD);
+
+    if is_extreme_imbalance {
+        // Direct redistribution: r_long = vault × x, r_short = vault × (1 - x)
+        let r_long_new = to_u64(mul_div_u128(vault_balance as u128, bd_score as u128, MICRO as u128)?)?;
+        let (long, short) = clamp_to_reserve_floor(r_long_new, vault_balance);
+        return Ok(SettledReserves {
+            long_pre_recouple: r_long_new as u128,
+            short_pre_recouple: vault_balance.saturating_sub(r_long_new) as u128,
+            long,
+            short,
+        });
+    }
+
+    let long_pre_recouple = mul_div_u128(r_long as u128, f_long as u128, MICRO as u128)?;
+    let short_pre_recouple = mul_div_u128(r_short as u128, f_short as u128, MICRO as u128)?;
+    let mut r_long_new = long_pre_recouple;
+    let mut r_short_new = short_pre_recouple;
+
+    // --- INVARIANT RECOUPLE: r_L + r_S == vault_balance ---
+    // After scaling by capped factors, reserves may drift from vault due to clamping/rounding.
+    let total_after = long_pre_recouple
+        .checked_add(short_pre_recouple)
+        .ok_or(ContentPoolError::NumericalOverflow)?;
+
+    if total_after > 0 {
+        let target = vault_balance as u128;
+        if total_after != target {
+            r_long_new = mul_div_u128(long_pre_recouple, target, total_after)?;
+            r_short_new = target.saturating_sub(r_long_new);
+        }
+    }
+
+    let r_long_new = to_u64(r_long_new)?;
+    let r_short_new = to_u64(r_short_new)?;
+    let (long, short) = clamp_to_reserve_floor(r_long_new, r_long_new.saturating_add(r_short_new));
+    Ok(SettledReserves {
+        long_pre_recouple,
+        short_pre_recouple,
+        long,
+        short,
+    })
+}
+
+fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| ContentPoolError::NumericalOverflow.into())
+}
+
+/// Zero-sum settlement: transfer relevance between sides without scaling
+///
+/// Reserves are set directly from the BD score (clamped to [1%, 99%]):
+///   r_long = vault × x, r_short = vault - r_long
+/// so r_long + r_short == vault_balance holds by construction.
+pub fn zero_sum_reserves(vault_balance: u64, bd_score: u32) -> Result<(u64, u64)> {
+    let x = (bd_score as u64).clamp(
+        MIN_PREDICTION_BPS as u64 * 100,
+        MAX_PREDICTION_BPS as u64 * 100,
+    );
+    let r_long = mul_div_u128(vault_balance as u128, x as u128, MICRO as u128)? as u64;
+    require!(r_long <= vault_balance, ContentPoolError::SettlementInvariantViolation);
+    Ok(clamp_to_reserve_floor(r_long, vault_balance))
+}
+
+/// Split `total` into (r_long, r_short) with each side at least MIN_SIDE_RESERVE
+///
+/// Clamps rather than rejects: the side below the floor is raised to it and the other side
+/// gives up the difference, so r_long + r_short == total still holds. A pool holding less
+/// than twice the floor is split evenly. Only settlement moves reserves off the curve this
+/// way (decay is not implemented on-chain), so only settlement applies the floor. The
+/// factors are floored first (floored_factors); this catches their rounding and the
+/// zero-sum and direct-redistribution splits, which do not follow the factors.
+pub fn clamp_to_reserve_floor(r_long: u64, total: u64) -> (u64, u64) {
+    let floor = MIN_SIDE_RESERVE.min(total / 2);
+    let r_long = r_long.clamp(floor, total - floor);
+    (r_long, total - r_long)
+}
+
+/// σ after a settlement, in the order the transforms are applied
+///
+/// 1. Factor step: σ / √f per side (`*_pre_renorm`)
+/// 2. `renormalize_scales` on the factor-step result (`long` / `short`)
+#[derive(Debug, PartialEq, Eq)]
+pub struct SettledScales {
+    pub long_pre_renorm: u128,
+    pub short_pre_renorm: u128,
+    pub long: u128,
+    pub short: u128,
+}
+
+/// Reserves after a settlement, before and after the recouple to vault_balance
+///
+/// `*_pre_recouple` is the factor step (r × f), or the direct vault × x split on extreme
+/// imbalance. A large gap to `long` / `short` means the caps or the recouple overrode
+/// most of the move the BD score asked for.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SettledReserves {
+    pub long_pre_recouple: u128,
+    pub short_pre_recouple: u128,
+    pub long: u64,
+    pub short: u64,
+}
+
+/// Apply settlement factors (millionths) to σ, then renormalize
+///
+/// σ is divided by √f rather than f so the price-implied ratio after settlement
+/// equals the BD score: q·f_L / (q·f_L + (1-q)·f_S) = x.
+pub fn settled_scales(
+    sigma_long: u128,
+    sigma_short: u128,
+    f_long: u64,
+    f_short: u64,
+    s_long: u64,
+    s_short: u64,
+) -> Result<SettledScales> {
+    let f_long_q64 = ((f_long as u128) << 64) / MICRO as u128;
+    let f_short_q64 = ((f_short as u128) << 64) / MICRO as u128;
+
+    // For a value x in Q64, sqrt(x) in Q64 = isqrt(x_q64) << 32
+    let sqrt_f_long_q64 = isqrt_u128(f_long_q64)? << 32;
+    let sqrt_f_short_q64 = isqrt_u128(f_short_q64)? << 32;
+
+    let long_pre_renorm = mul_div_u128(sigma_long, Q64, sqrt_f_long_q64)?;
+    let short_pre_renorm = mul_div_u128(sigma_short, Q64, sqrt_f_short_q64)?;
+
+    let mut long = long_pre_renorm;
+    let mut short = short_pre_renorm;
+    renormalize_scales(&mut long, &mut short, s_long, s_short);
+
+    Ok(SettledScales { long_pre_renorm, short_pre_renorm, long, short })
+}
+
+/// Seconds until a pool may settle again (0 if eligible now)
+///
+/// A pool that has never settled (last_settle_ts == 0) is always eligible.
+/// A last_settle_ts in the future (clock skew, bad state) never counts as elapsed:
+/// the cooldown runs from that timestamp and is not met before it.
+pub fn seconds_until_settle_eligible(
+    last_settle_ts: i64,
+    min_settle_interval: i64,
+    now: i64,
+) -> i64 {
+    if last_settle_ts == 0 {
+        return 0;
+    }
+    match now.checked_sub(last_settle_ts) {
+        Some(elapsed) if elapsed >= 0 => min_settle_interval.saturating_sub(elapsed).max(0),
+        Some(elapsed) => min_settle_interval.saturating_sub(elapsed).max(1),
+        None => i64::MAX,
+    }
+}
+
+/// Whether the settlement cooldown allows settling now
+///
+/// `force` is the protocol authority's emergency override and skips the cooldown.
+pub fn cooldown_satisfied(
+    last_settle_ts: i64,
+    min_settle_interval: i64,
+    now: i64,
+    force: bool,
+) -> bool {
+    force || seconds_until_settle_eligible(last_settle_ts, min_settle_interval, now) == 0
+}
+
+/// Whether live q (millionths) is within `max_deviation_bps` of `expected_q_bps`
+///
+/// Guards a settlement whose BD score was computed against an observed q: a trade in
+/// between moves q and, with it, the settlement factors. The caller only sees q in whole
+/// bps, so anything short of one more bps passes: an unmoved pool at q = 50.0099% matches
+/// 5000 (truncated) or 5001 (rounded) even with no tolerance.
+pub fn q_within_tolerance(q: u64, expected_q_bps: u16, max_deviation_bps: u16) -> bool {
+    q.abs_diff(expected_q_bps as u64 * 100) < (max_deviation_bps as u64 + 1) * 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VAULT: u64 = 1_000_000_000; // 1,000 USDC
+
+    #[test]
+    fn test_zero_sum_preserves_vault_exactly() {
+        for bd_score in [0u32, 1, 333_333, 500_000, 777_777, 999_999, 1_000_000] {
+            let (r_long, r_short) = zero_sum_reserves(VAULT + 7, bd_score).unwrap();
+            assert_eq!(r_long + r_short, VAULT + 7, "bd_score={}", bd_score);
+        }
+    }
+
+    #[test]
+    fn test_zero_sum_resplit_is_exact_where_per_side_scaling_drifts() {
+        // Moving q to a target x: scaling each side by x / q and (1 - x) / (1 - q) floors
+        // twice and drifts below the vault; zero_sum_reserves re-splits it exactly.
+        let (r_long, r_short) = (333_333_333u64, 666_666_667u64);
+        let vault = r_long + r_short;
+        let q = market_prediction_q(r_long, r_short);
+
+        let mut drifted = false;
+        for x_decay in [300_000u64, 250_000, 100_000, 500_000] {
+            let per_side = mul_div_u128(r_long as u128, x_decay as u128, q as u128).unwrap()
+                + mul_div_u128(r_short as u128, (MICRO - x_decay) as u128, (MICRO - q) as u128).unwrap();
+            drifted |= per_side != vault as u128;
+
+            let (r_l, r_s) = zero_sum_reserves(vault, x_decay as u32).unwrap();
+            assert_eq!(r_l + r_s, vault, "x_decay={}", x_decay);
+            assert_eq!(market_prediction_q(r_l, r_s), x_decay);
+        }
+        assert!(drifted, "per-side scaling was expected to lose rounding dust");
+    }
+
+    #[test]
+    fn test_zero_sum_clamps_extremes() {
+        // x = 0 → 1%, x = 1 → 99%
+        let (r_long, r_short) = zero_sum_reserves(VAULT, 0).unwrap();
+        assert_eq!(r_long, VAULT / 100);
+        assert_eq!(r_short, VAULT - VAULT / 100);
+
+        let (r_long, r_short) = zero_sum_reserves(VAULT, 1_000_000).unwrap();
+        assert_eq!(r_long, VAULT * 99 / 100);
+        assert_eq!(r_short, VAULT / 100);
+    }
+
+    #[test]
+    fn test_modes_agree_away_from_clamps() {
+        // q = 50%, x = 60%: factors (1.2, 0.8) never hit the caps
+        let (r_l, r_s) = (VAULT / 2, VAULT / 2);
+        let (f_long, f_short) = settlement_factors(600_000, 500_000);
+        let scaled = scaled_reserves(r_l, r_s, VAULT, 600_000, f_long, f_short).unwrap();
+        let zero_sum = zero_sum_reserves(VAULT, 600_000).unwrap();
+        assert_eq!(scaled, zero_sum);
+    }
+
+    #[test]
+    fn test_modes_diverge_near_clamps() {
+        // q = 50%, x = 0: f_L = 0 is capped at F_MIN, so the multiplicative
+        // mode leaves LONG with ~0.5% while zero-sum pins it at the 1% floor
+        let (r_l, r_s) = (VAULT / 2, VAULT / 2);
+        let (f_long, f_short) = settlement_factors(0, 500_000);
+        assert_eq!(f_long, F_MIN);
+
+        let (scaled_long, scaled_short) =
+            scaled_reserves(r_l, r_s, VAULT, 0, f_long, f_short).unwrap();
+        let (zs_long, zs_short) = zero_sum_reserves(VAULT, 0).unwrap();
+
+        assert_eq!(scaled_long + scaled_short, VAULT);
+        assert_eq!(zs_long + zs_short, VAULT);
+        assert!(scaled_long < zs_long, "scaled={} zero_sum={}", scaled_long, zs_long);
+        assert_eq!(zs_long, VAULT / 100);
+    }
+
+    #[test]
+    fn test_extreme_settlement_keeps_reserve_floor() {
+        // 5 USDC pool settled toward each extreme: without the floor the losing side
+        // ends near 0 (scaled) or at 1% = 0.05 USDC (zero-sum)
+        let vault = 5_000_000u64;
+        for bd_score in [0u32, 1_000_000] {
+            for (r_l, r_s) in [(vault / 2, vault / 2), (vault / 200, vault - vault / 200)] {
+                let q = market_prediction_q(r_l, r_s);
+                let (f_long, f_short) = settlement_factors(bd_score, q);
+                let scaled = scaled_reserves(r_l, r_s, vault, bd_score, f_long, f_short).unwrap();
+                let zero_sum = zero_sum_reserves(vault, bd_score).unwrap();
+                for (r_long, r_short) in [scaled, zero_sum] {
+                    assert_eq!(r_long + r_short, vault);
+                    assert!(r_long >= MIN_SIDE_RESERVE && r_short >= MIN_SIDE_RESERVE,
+                        "bd_score={} reserves=({}, {})", bd_score, r_long, r_short);
+                }
+            }
+        }
+
+        // Below twice the floor both sides get half
+        assert_eq!(clamp_to_reserve_floor(0, 150_001), (75_000, 75_001));
+        assert_eq!(clamp_to_reserve_floor(0, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_floored_factors_move_sigma_with_reserves() {
+        // 10 USDC pool at q = 30% settled to 0: F_MIN alone leaves LONG at ~0.03 USDC
+        let vault = 10_000_000u64;
+        let (r_l, r_s) = (3_000_000, 7_000_000);
+        let (f_long, f_short) = settlement_factors(0, market_prediction_q(r_l, r_s));
+        assert_eq!(f_long, F_MIN);
+        let unfloored = settled_reserves(r_l, r_s, vault, 0, f_long, f_short).unwrap();
+        assert!(unfloored.long_pre_recouple * (vault as u128)
+            < MIN_SIDE_RESERVE as u128 * (unfloored.long_pre_recouple + unfloored.short_pre_recouple));
+
+        // With the floored factor the factor step itself lands on the floor, so σ and
+        // the reserves see the same move and the clamp has nothing left to lift
+        let (f_long, f_short) = floored_factors(r_l, r_s, vault, f_long, f_short).unwrap();
+        assert!(f_long > F_MIN);
+        let reserves = settled_reserves(r_l, r_s, vault, 0, f_long, f_short).unwrap();
+        let total = reserves.long_pre_recouple + reserves.short_pre_recouple;
+        let recoupled_long = mul_div_u128(reserves.long_pre_recouple, vault as u128, total).unwrap() as u64;
+        assert_eq!(recoupled_long, reserves.long);
+        // Rounded up to the factor's millionths precision: a few µUSDC over the floor
+        assert!((MIN_SIDE_RESERVE..=MIN_SIDE_RESERVE + 10).contains(&reserves.long), "{:?}", reserves);
+
+        // Away from the floor the factors pass through untouched
+        let (f_long, f_short) = settlement_factors(600_000, 500_000);
+        assert_eq!(floored_factors(VAULT / 2, VAULT / 2, VAULT, f_long, f_short).unwrap(), (f_long, f_short));
+    }
+
+    #[test]
+    fn test_extreme_imbalance_redistributes_directly() {
+        // LONG holds 0.5% of reserves → direct redistribution by BD score
+        let (r_l, r_s) = (VAULT / 200, VAULT - VAULT / 200);
+        let (f_long, f_short) = settlement_factors(400_000, 5_000);
+        let (r_long, r_short) = scaled_reserves(r_l, r_s, VAULT, 400_000, f_long, f_short).unwrap();
+        assert_eq!(r_long, VAULT * 4 / 10);
+        assert_eq!(r_short, VAULT - r_long);
+    }
+
+    #[test]
+    fn test_settled_scales_reports_pre_and_post_renorm() {
+        // 1,000 display tokens per side at σ = 1.0 → virtual norm ≈ 1,414,
+        // below VIRTUAL_NORM_MIN, so renormalization shifts σ after the factor step
+        let (f_long, f_short) = settlement_factors(600_000, 500_000);
+        let scales = settled_scales(Q64, Q64, f_long, f_short, 1_000, 1_000).unwrap();
+
+        // Factor step alone: LONG σ shrinks (f_L > 1), SHORT σ grows (f_S < 1)
+        assert!(scales.long_pre_renorm < Q64);
+        assert!(scales.short_pre_renorm > Q64);
+
+        // Renormalization triggered and changed both sides by the same power of two
+        assert_ne!(scales.long, scales.long_pre_renorm);
+        assert_ne!(scales.short, scales.short_pre_renorm);
+        assert_eq!(scales.long_pre_renorm / scales.long, scales.short_pre_renorm / scales.short);
+
+        // Replaying factor-then-renorm from the pre-renorm values reproduces the final σ
+        let (mut long, mut short) = (scales.long_pre_renorm, scales.short_pre_renorm);
+        renormalize_scales(&mut long, &mut short, 1_000, 1_000);
+        assert_eq!((long, short), (scales.long, scales.short));
+    }
+
+    #[test]
+    fn test_settled_reserves_reports_pre_and_post_recouple() {
+        // q = 2% (just above the extreme-imbalance cutoff), BD score = 100%:
+        // f_L = 50x asks LONG to hold ~100% of the vault, f_S hits F_MIN
+        let (r_l, r_s) = (VAULT / 50, VAULT - VAULT / 50);
+        let q = market_prediction_q(r_l, r_s);
+        let (f_long, f_short) = settlement_factors(1_000_000, q);
+        assert_eq!(f_short, F_MIN);
+
+        let reserves = settled_reserves(r_l, r_s, VAULT, 1_000_000, f_long, f_short).unwrap();
+
+        // Factor step overshoots the vault before the recouple pulls it back
+        assert_eq!(reserves.long_pre_recouple, r_l as u128 * f_long as u128 / MICRO as u128);
+        assert!(reserves.long_pre_recouple + reserves.short_pre_recouple > VAULT as u128);
+        assert_eq!(reserves.long + reserves.short, VAULT);
+        assert_ne!(reserves.long as u128, reserves.long_pre_recouple);
+        assert_ne!(reserves.short as u128, reserves.short_pre_recouple);
+
+        // scaled_reserves reports the same post-recouple split
+        assert_eq!(
+            scaled_reserves(r_l, r_s, VAULT, 1_000_000, f_long, f_short).unwrap(),
+            (reserves.long, reserves.short)
+        );
+    }
+
+    #[test]
+    fn test_q_tolerance_ignores_sub_bps_precision() {
+        // Unmoved pool whose q is not a whole bps: either way of reading it in bps matches
+        assert!(q_within_tolerance(500_099, 5_000, 0));
+        assert!(q_within_tolerance(500_099, 5_001, 0));
+        assert!(q_within_tolerance(499_951, 5_000, 0));
+
+        // A full bps of movement trips a zero tolerance, and the tolerance widens it by bps
+        assert!(!q_within_tolerance(500_100, 5_000, 0));
+        assert!(q_within_tolerance(500_100, 5_000, 1));
+        assert!(!q_within_tolerance(500_200, 5_000, 1));
+    }
+
+    #[test]
+    fn test_settle_eligibility() {
+        // Never settled → eligible
+        assert_eq!(seconds_until_settle_eligible(0, 7200, 1_000), 0);
+        // Mid-cooldown
+        assert_eq!(seconds_until_settle_eligible(10_000, 7200, 12_000), 5200);
+        // Exactly at the boundary → eligible
+        assert_eq!(seconds_until_settle_eligible(10_000, 7200, 17_200), 0);
+        // Past cooldown
+        assert_eq!(seconds_until_settle_eligible(10_000, 7200, 99_999), 0);
+    }
+
+    #[test]
+    fn test_future_last_settle_ts_blocks_settlement() {
+        // last_settle_ts 1h ahead of the clock: cooldown counts from that point
+        assert_eq!(seconds_until_settle_eligible(13_600, 7200, 10_000), 10_800);
+        assert!(!cooldown_satisfied(13_600, 7200, 10_000, false));
+
+        // Even with no configured interval, a future timestamp is not "elapsed"
+        assert!(seconds_until_settle_eligible(13_600, 0, 10_000) > 0);
+        assert!(!cooldown_satisfied(13_600, 0, 10_000, false));
+
+        // Subtraction that would overflow i64 is treated as not met
+        assert_eq!(seconds_until_settle_eligible(i64::MAX, 7200, i64::MIN), i64::MAX);
+        assert!(!cooldown_satisfied(i64::MAX, 7200, i64::MIN, false));
+
+        // Forced settlement still bypasses the cooldown
+        assert!(cooldown_satisfied(13_600, 7200, 10_000, true));
+    }
+
+    #[test]
+    fn test_forced_settlements_within_cooldown() {
+        // First settlement at t=10_000, cooldown 7200s
+        let first = 10_000;
+        assert!(cooldown_satisfied(0, 7200, first, false));
+
+        // 60s later: normal settlement blocked, forced settlement allowed
+        let second = first + 60;
+        assert!(!cooldown_satisfied(first, 7200, second, false));
+        assert!(cooldown_satisfied(first, 7200, second, true));
+
+        // Another forced settlement 60s after that is still allowed
+        let third = second + 60;
+        assert!(!cooldown_satisfied(second, 7200, third, false));
+        assert!(cooldown_satisfied(second, 7200, third, true));
+    }
+}
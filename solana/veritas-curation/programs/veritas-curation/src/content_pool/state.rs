@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 /// Primary account structure for ContentPool
-/// Total size: 496 bytes + 8 discriminator = 504 bytes
+/// Total size: 528 bytes + 8 discriminator = 536 bytes
 #[account]
 #[derive(Debug)]
 pub struct ContentPool {
@@ -27,15 +27,22 @@ pub struct ContentPool {
     /// Global stake vault (VeritasCustodian) (32 bytes)
     pub stake_vault: Pubkey,
 
-    // ICBS Parameters (16 bytes)
+    // ICBS Parameters (16 bytes) - f/β immutable once the market is deployed
     /// Growth exponent (default: 3)
     pub f: u16,
     /// β numerator (default: 1)
     pub beta_num: u16,
     /// β denominator (default: 2, so β = 0.5)
     pub beta_den: u16,
-    /// Alignment padding
-    pub _padding1: [u8; 10],
+    /// Per-pool total trading fee in bps (only used while fee_override_set)
+    pub fee_bps_override: u16,
+    /// Per-pool creator split in bps (only used while creator_split_override_set)
+    pub creator_split_override: u16,
+    /// Extra sell fee (bps, to treasury) within min_hold_secs of the seller's last buy
+    /// Only charged while the factory's early_exit_fee_enabled flag is on
+    pub early_exit_fee_bps: u16,
+    /// Minimum holding period in seconds before a sell avoids early_exit_fee_bps
+    pub min_hold_secs: u32,
 
     // Token Supplies - Integer (16 bytes)
     /// LONG token supply in WHOLE TOKENS (e.g., 25 = 25 tokens)
@@ -67,9 +74,11 @@ pub struct ContentPool {
 
     // Lambda Scale - Q96 (32 bytes)
     /// DEPRECATED: Telemetry only. Lambda is now derived from vault + sigma scales.
+    /// Refreshed by deploy, trades, settlement and liquidity changes; read get_current_lambda instead.
     /// λ_L in Q96 format (NOT sqrt!)
     pub lambda_long_q96: u128,
     /// DEPRECATED: Telemetry only. Lambda is now derived from vault + sigma scales.
+    /// Refreshed by deploy, trades, settlement and liquidity changes; read get_current_lambda instead.
     /// λ_S in Q96 format (NOT sqrt!)
     pub lambda_short_q96: u128,
 
@@ -95,15 +104,47 @@ pub struct ContentPool {
     /// PoolFactory that created this pool
     pub factory: Pubkey,
 
-    // Bump (1 byte + 7 padding)
+    // Bump + Version + Closed + Wind-down + Override flags (6 bytes + 2 padding)
     /// PDA bump seed
     pub bump: u8,
+    /// Account layout version (0 = created before versioning, i.e. v1)
+    pub version: u8,
+    /// Set by emergency_close: vault swept, trading and settlement disabled
+    pub closed: bool,
+    /// Set by the protocol authority ahead of a shutdown: buys disabled, sells fee-free
+    pub wind_down: bool,
+    /// fee_bps_override replaces the factory total_fee_bps (false = use factory) - v7
+    pub fee_override_set: bool,
+    /// creator_split_override replaces the factory creator_split_bps (false = use factory) - v7
+    pub creator_split_override_set: bool,
     /// Alignment
-    pub _padding2: [u8; 7],
+    pub _padding2: [u8; 2],
+
+    // Event Sequencing (8 bytes) - v3
+    /// Trades executed on this pool; TradeEvent carries the post-increment value
+    /// so indexers can detect gaps and backfill
+    pub trade_seq: u64,
+
+    // Lifecycle Timestamps (16 bytes) - v4
+    /// When create_pool ran (0 for pools created before v4; see PoolRegistry.created_at)
+    pub created_at: i64,
+    /// When deploy_market ran - the market's true age for ranking (0 = not deployed, or
+    /// deployed before v4)
+    pub deployed_at: i64,
+
+    // Creator Earnings (8 bytes) - v6
+    /// Lifetime creator fees paid out by trades on this pool (µUSDC). Counts from v6:
+    /// fees paid before a pool was migrated are only in its TradeFeeEvents
+    pub cumulative_creator_fees: u64,
 }
 
 impl ContentPool {
-    pub const LEN: usize = 496;
+    pub const LEN: usize = 528;
+    /// Current layout version; migrate_account brings older pools up to it
+    /// v2 added `version` (in former padding), v3 appended `trade_seq`,
+    /// v4 appended `created_at` / `deployed_at`, v5 added `wind_down` (in former padding),
+    /// v6 appended `cumulative_creator_fees`, v7 added the fee override flags (in former padding)
+    pub const VERSION: u8 = 7;
 
     /// Seeds for PDA derivation
     pub fn seeds(&self) -> Vec<Vec<u8>> {
@@ -112,6 +153,35 @@ impl ContentPool {
             self.content_id.to_bytes().to_vec(),
         ]
     }
+
+    /// Per-pool total fee override in bps, if one is set
+    pub fn fee_override_bps(&self) -> Option<u16> {
+        self.fee_override_set.then_some(self.fee_bps_override)
+    }
+
+    /// Per-pool creator split override in bps, if one is set
+    pub fn creator_split_override_bps(&self) -> Option<u16> {
+        self.creator_split_override_set.then_some(self.creator_split_override)
+    }
+}
+
+/// Per-(pool, trader) record of the trader's last buy, for the early-exit fee
+/// PDA seeds: [b"hold", pool, trader]
+#[account]
+#[derive(Debug)]
+pub struct HoldRecord {
+    /// Pool this record belongs to (32 bytes)
+    pub pool: Pubkey,
+    /// Trader whose buys are tracked (32 bytes)
+    pub owner: Pubkey,
+    /// Timestamp of the trader's most recent buy on this pool (8 bytes)
+    pub last_buy_ts: i64,
+    /// PDA bump seed (1 byte)
+    pub bump: u8,
+}
+
+impl HoldRecord {
+    pub const LEN: usize = 32 + 32 + 8 + 1; // 73 bytes
 }
 
 /// Token side for trading
@@ -133,11 +203,22 @@ pub const MAX_SAFE_SUPPLY: u64 = 1_000_000_000_000;  // 1 trillion tokens (with
 pub const MIN_TRADE_SIZE: u64 = 100_000;             // 0.1 USDC (for BUY) - increased to prevent overflow in ICBS calculations
 pub const MAX_TRADE_SIZE: u64 = 1_000_000_000_000;   // 1M USDC
 pub const MIN_TOKEN_TRADE_SIZE: u64 = 1;         // 0.000001 tokens (for SELL) - very permissive
+pub const MIN_POOL_LIQUIDITY: u64 = 1_000;       // Display supply each side must keep after a sell
+// Reserve each side keeps after a settlement (0.1 USDC). The supply floor above only stops
+// sells from draining a side's tokens; settlement moves reserves without touching supply,
+// so a side could otherwise keep its tokens while its reserve (and price) falls to ~0.
+pub const MIN_SIDE_RESERVE: u64 = 100_000;
+pub const MAX_EARLY_EXIT_FEE_BPS: u16 = 1000;    // Early-exit penalty capped at 10% of sell proceeds
 
 // Initial Deposit Limits
 pub const MIN_INITIAL_DEPOSIT: u64 = 100_000_000;  // 100 USDC (6 decimals)
 pub const MAX_INITIAL_DEPOSIT: u64 = 10_000_000_000; // 10K USDC (6 decimals)
 
+// Batch Deployment
+pub const MAX_BATCH_DEPLOY: usize = 4;             // Deployments per deploy_market_batch transaction
+pub const BATCH_DEPLOY_ACCOUNTS: usize = 6;        // remaining_accounts per deployment
+pub const MAX_EPOCH_INFO_BATCH: usize = 20;        // Pools per get_epoch_info_batch (48-byte entries, 1024-byte return data)
+
 // Price Bounds (in micro-USDC per token)
 pub const MIN_PRICE_MICRO: u64 = 1;                  // 0.000001 USDC/token
 pub const MAX_PRICE_MICRO: u64 = 1_000_000_000_000;  // 1M USDC/token
@@ -150,6 +231,8 @@ pub const MIN_SETTLE_INTERVAL: i64 = 7200;    // 2 hours (increased from 5 minut
 // Fixed-Point for X96 format
 pub const Q96_ONE: u128 = 1 << 96;        // 1.0 in X96
 pub const Q32_ONE: u64 = 1 << 32;         // 1.0 in Q32.32 (for BD scores)
+pub const Q32_MIN_INITIAL_Q: u64 = Q32_ONE / 1000;        // 0.1%
+pub const Q32_MAX_INITIAL_Q: u64 = Q32_ONE * 999 / 1000;  // 99.9%
 
 // Q64.64 constants (for settlement logic)
 pub const Q64_ONE: u128 = 1 << 64;
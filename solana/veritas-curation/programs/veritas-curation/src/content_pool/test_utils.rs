@@ -0,0 +1,36 @@
+//! Shared ContentPool and PoolFactory fixtures for unit tests
+
+use anchor_lang::prelude::*;
+use crate::content_pool::state::{ContentPool, Q64};
+use crate::pool_factory::state::PoolFactory;
+
+/// Factory with every field zeroed: no fees, caps or floors configured
+pub(crate) fn zeroed_factory() -> PoolFactory {
+    let mut data = <PoolFactory as anchor_lang::Discriminator>::DISCRIMINATOR.to_vec();
+    data.resize(8 + PoolFactory::LEN, 0);
+    PoolFactory::try_deserialize(&mut &data[..]).unwrap()
+}
+
+/// Pool with every field zeroed: created, never deployed
+pub(crate) fn zeroed_pool() -> ContentPool {
+    let mut data = <ContentPool as anchor_lang::Discriminator>::DISCRIMINATOR.to_vec();
+    data.resize(8 + ContentPool::LEN, 0);
+    ContentPool::try_deserialize(&mut &data[..]).unwrap()
+}
+
+/// Deployed 50/50 pool: 1000 tokens a side, 100 USDC vault, σ = 1, F = 1, β = 0.5
+pub(crate) fn balanced_pool() -> ContentPool {
+    let mut pool = zeroed_pool();
+    pool.market_deployer = Pubkey::new_unique();
+    pool.f = 1;
+    pool.beta_num = 1;
+    pool.beta_den = 2;
+    pool.s_long = 1_000;
+    pool.s_short = 1_000;
+    pool.s_scale_long_q64 = Q64;
+    pool.s_scale_short_q64 = Q64;
+    pool.vault_balance = 100_000_000;
+    pool.r_long = 50_000_000;
+    pool.r_short = 50_000_000;
+    pool
+}
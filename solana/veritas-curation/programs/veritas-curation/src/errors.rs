@@ -64,4 +64,10 @@ pub enum ErrorCode {
 
     #[msg("Invalid upgrade authority")]
     InvalidUpgradeAuthority,
+
+    #[msg("Missing or invalid Ed25519 signature instruction")]
+    InvalidSignature, // 401
+
+    #[msg("Withdrawal nonce already used")]
+    NonceAlreadyUsed, // 409
 }
@@ -30,19 +30,21 @@ pub mod veritas_curation {
     // ============================================================================
 
     /// Deploy market with initial liquidity (first trader)
-    pub fn deploy_market(
-        ctx: Context<DeployMarket>,
-        initial_deposit: u64,
-        long_allocation: u64,
+    pub fn deploy_market(ctx: Context<DeployMarket>, params: DeployParams) -> Result<()> {
+        content_pool::instructions::deploy_market::handler(ctx, params)
+    }
+
+    /// Deploy several markets in one transaction (genesis catalog seeding)
+    /// Each entry takes deploy_market's params, defaults included
+    pub fn deploy_market_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeployMarketBatch<'info>>,
+        entries: Vec<DeployParams>,
     ) -> Result<()> {
-        content_pool::instructions::deploy_market::handler(
-            ctx,
-            initial_deposit,
-            long_allocation,
-        )
+        content_pool::instructions::deploy_market_batch::handler(ctx, entries)
     }
 
     /// Trade on the ICBS market (buy or sell LONG/SHORT tokens)
+    /// `min_tokens_out` is in atomic SPL units (display × 1e6); see trade_display_limit
     pub fn trade(
         ctx: Context<Trade>,
         side: TokenSide,
@@ -58,11 +60,58 @@ pub mod veritas_curation {
             trade_type,
             amount,
             stake_skim,
-            min_tokens_out,
+            content_pool::instructions::trade::MinTokensOut::Atomic(min_tokens_out),
+            min_usdc_out,
+        )
+    }
+
+    /// Trade with the buy slippage limit in display tokens (same units as TradeEvent.tokens_traded)
+    pub fn trade_display_limit(
+        ctx: Context<Trade>,
+        side: TokenSide,
+        trade_type: TradeType,
+        amount: u64,
+        stake_skim: u64,
+        min_tokens_out_display: u64,
+        min_usdc_out: u64,
+    ) -> Result<()> {
+        content_pool::instructions::trade::handler(
+            ctx,
+            side,
+            trade_type,
+            amount,
+            stake_skim,
+            content_pool::instructions::trade::MinTokensOut::Display(min_tokens_out_display),
             min_usdc_out,
         )
     }
 
+    /// Execute trades on two different pools atomically (e.g. cross-market arbitrage)
+    pub fn dual_trade<'info>(
+        ctx: Context<'_, '_, '_, 'info, DualTrade<'info>>,
+        leg_a: TradeLeg,
+        leg_b: TradeLeg,
+    ) -> Result<()> {
+        content_pool::instructions::dual_trade::handler(ctx, leg_a, leg_b)
+    }
+
+    /// Buy LONG and SHORT together, splitting `amount` by the current reserve ratio
+    pub fn buy_both_sides<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyBothSides<'info>>,
+        amount: u64,
+        stake_skim: u64,
+        min_long_tokens_out: u64,
+        min_short_tokens_out: u64,
+    ) -> Result<()> {
+        content_pool::instructions::buy_both_sides::handler(
+            ctx,
+            amount,
+            stake_skim,
+            min_long_tokens_out,
+            min_short_tokens_out,
+        )
+    }
+
     /// Add bilateral liquidity to both sides of the market
     pub fn add_liquidity(
         ctx: Context<AddLiquidity>,
@@ -72,11 +121,36 @@ pub mod veritas_curation {
     }
 
     /// Settle epoch with BD score
+    /// `zero_sum` sets reserves directly from the BD score, preserving vault_balance exactly
+    /// `force` skips the settlement cooldown (protocol authority emergency override)
+    /// `expected_q_bps` reverts with QMovedError if q moved more than `max_q_deviation_bps` from it
     pub fn settle_epoch(
         ctx: Context<SettleEpoch>,
         bd_score: u32,
+        zero_sum: bool,
+        force: bool,
+        expected_q_bps: Option<u16>,
+        max_q_deviation_bps: Option<u16>,
     ) -> Result<()> {
-        content_pool::instructions::settle_epoch::handler(ctx, bd_score)
+        content_pool::instructions::settle_epoch::handler(
+            ctx,
+            bd_score,
+            zero_sum,
+            force,
+            expected_q_bps,
+            max_q_deviation_bps,
+        )
+    }
+
+    /// Advance the epoch without moving reserves (no reliable BD signal)
+    /// `force` skips the settlement cooldown, as in settle_epoch
+    pub fn skip_epoch(ctx: Context<SkipEpoch>, force: bool) -> Result<()> {
+        content_pool::instructions::skip_epoch::handler(ctx, force)
+    }
+
+    /// Move skimmed stake from the custodian into a pool's vault as a reward (protocol authority)
+    pub fn settle_stake_to_pool(ctx: Context<SettleStakeToPool>, amount: u64) -> Result<()> {
+        content_pool::instructions::settle_stake_to_pool::handler(ctx, amount)
     }
 
     /// Close an empty pool
@@ -84,12 +158,247 @@ pub mod veritas_curation {
         content_pool::instructions::close_pool::handler(ctx)
     }
 
+    /// Sweep the residual vault to treasury and disable a fully exited pool (upgrade authority)
+    pub fn emergency_close(ctx: Context<EmergencyClose>) -> Result<()> {
+        content_pool::instructions::emergency_close::handler(ctx)
+    }
+
+    /// Sell with fees waived on a pool flagged for wind-down (same accounts as trade)
+    pub fn burn_and_refund(
+        ctx: Context<Trade>,
+        side: TokenSide,
+        amount: u64,
+        min_usdc_out: u64,
+    ) -> Result<()> {
+        content_pool::instructions::burn_and_refund::handler(ctx, side, amount, min_usdc_out)
+    }
+
+    /// Close the caller's empty LONG/SHORT token account and reclaim its rent
+    pub fn close_empty_position(ctx: Context<CloseEmptyPosition>) -> Result<()> {
+        content_pool::instructions::close_empty_position::handler(ctx)
+    }
+
+    /// Create a trader's LONG/SHORT token accounts so their first trade skips ATA rent
+    pub fn prepare_trader_accounts(ctx: Context<PrepareTraderAccounts>) -> Result<()> {
+        content_pool::instructions::prepare_trader_accounts::handler(ctx)
+    }
+
+    /// Move LONG/SHORT tokens to another wallet off-curve (no fees), emitting PositionTransferredEvent
+    pub fn transfer_position(
+        ctx: Context<TransferPosition>,
+        long_amount: u64,
+        short_amount: u64,
+    ) -> Result<()> {
+        content_pool::instructions::transfer_position::handler(ctx, long_amount, short_amount)
+    }
+
+    /// Set per-pool fee overrides (None = use factory fee config)
+    pub fn set_fee_override(
+        ctx: Context<SetFeeOverride>,
+        fee_bps_override: Option<u16>,
+        creator_split_override: Option<u16>,
+    ) -> Result<()> {
+        content_pool::instructions::set_fee_override::handler(
+            ctx,
+            fee_bps_override,
+            creator_split_override,
+        )
+    }
+
+    /// Set per-pool curve parameters (F, β); rejected once the market is deployed
+    pub fn set_curve_params(
+        ctx: Context<SetCurveParams>,
+        f: u16,
+        beta_num: u16,
+        beta_den: u16,
+    ) -> Result<()> {
+        content_pool::instructions::set_curve_params::handler(ctx, f, beta_num, beta_den)
+    }
+
+    /// Set a pool's early-exit fee and minimum holding period (0 bps = none)
+    pub fn set_early_exit_fee(
+        ctx: Context<SetEarlyExitFee>,
+        early_exit_fee_bps: u16,
+        min_hold_secs: u32,
+    ) -> Result<()> {
+        content_pool::instructions::set_early_exit_fee::handler(ctx, early_exit_fee_bps, min_hold_secs)
+    }
+
+    /// Flag a pool for wind-down: buys disabled, sells fee-free via trade or burn_and_refund
+    pub fn set_wind_down(ctx: Context<SetWindDown>, wind_down: bool) -> Result<()> {
+        content_pool::instructions::set_wind_down::handler(ctx, wind_down)
+    }
+
+    /// Reset vault_balance to the live vault amount and re-derive λ, prices and reserves
+    pub fn resync_vault_balance(ctx: Context<ResyncVaultBalance>) -> Result<()> {
+        content_pool::instructions::resync_vault_balance::handler(ctx)
+    }
+
+    /// Repair a pre-clamp pool's degenerate initial_q from its current reserves
+    pub fn recompute_initial_q(ctx: Context<RecomputeInitialQ>) -> Result<()> {
+        content_pool::instructions::recompute_initial_q::handler(ctx)
+    }
+
     /// View-only instruction: Get current pool state with decay applied
     /// Does not mutate on-chain state
     pub fn get_current_state(ctx: Context<GetCurrentState>) -> Result<CurrentPoolState> {
         content_pool::instructions::get_current_state::handler(ctx)
     }
 
+    /// View-only instruction: Get pool readiness flags (deployed/tradeable/expired)
+    pub fn get_pool_status(ctx: Context<GetPoolStatus>) -> Result<PoolStatus> {
+        content_pool::instructions::get_pool_status::handler(ctx)
+    }
+
+    /// View-only instruction: Get epoch and settlement cooldown status
+    pub fn get_epoch_info(ctx: Context<GetEpochInfo>) -> Result<EpochInfo> {
+        content_pool::instructions::get_epoch_info::handler(ctx)
+    }
+
+    /// View-only instruction: Epoch and cooldown status for up to MAX_EPOCH_INFO_BATCH pools (remaining_accounts)
+    pub fn get_epoch_info_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetEpochInfoBatch<'info>>,
+    ) -> Result<Vec<PoolEpochEntry>> {
+        content_pool::instructions::get_epoch_info_batch::handler(ctx)
+    }
+
+    /// View-only instruction: Preview λ after settling at bd_score and whether it stays in bounds
+    pub fn preview_settlement_lambda(
+        ctx: Context<PreviewSettlementLambda>,
+        bd_score: u32,
+    ) -> Result<SettlementLambdaPreview> {
+        content_pool::instructions::preview_settlement_lambda::handler(ctx, bd_score)
+    }
+
+    /// View-only instruction: Get all meaningful pool fields as a versioned DTO (no padding)
+    pub fn get_all_pool_fields(ctx: Context<GetAllPoolFields>) -> Result<PoolFields> {
+        content_pool::instructions::get_all_pool_fields::handler(ctx)
+    }
+
+    /// View-only instruction: Pool snapshot for indexer cold-start (state + slot to tail from)
+    pub fn get_all_events_state(ctx: Context<GetAllEventsState>) -> Result<PoolSnapshot> {
+        content_pool::instructions::get_all_events_state::handler(ctx)
+    }
+
+    /// View-only instruction: Factory metrics (pool count, TVL, fee config) for dashboards
+    pub fn get_factory_stats(ctx: Context<GetFactoryStats>) -> Result<FactoryStats> {
+        pool_factory::instructions::get_factory_stats(ctx)
+    }
+
+    /// View-only instruction: Get implied LONG probability with total and balanced reserve backing
+    pub fn get_implied_odds(ctx: Context<GetImpliedOdds>) -> Result<ImpliedOdds> {
+        content_pool::instructions::get_implied_odds::handler(ctx)
+    }
+
+    /// View-only instruction: Get the live λ and √λ (replaces the deprecated stored lambda fields)
+    pub fn get_current_lambda(ctx: Context<GetCurrentLambda>) -> Result<CurrentLambda> {
+        content_pool::instructions::get_current_lambda::handler(ctx)
+    }
+
+    /// View-only instruction: Get the virtual supplies ŝ = s / σ and norm the curve prices on
+    pub fn get_virtual_supplies(ctx: Context<GetVirtualSupplies>) -> Result<VirtualSupplies> {
+        content_pool::instructions::get_virtual_supplies::handler(ctx)
+    }
+
+    /// View-only instruction: Get σ_L / σ_S as an exact rational and a 9-decimal integer
+    pub fn get_sigma_decoded(ctx: Context<GetSigmaDecoded>) -> Result<SigmaDecoded> {
+        content_pool::instructions::get_sigma_decoded::handler(ctx)
+    }
+
+    /// View-only instruction: Project total/creator/protocol fees for a USDC volume
+    pub fn estimate_fees(ctx: Context<EstimateFees>, volume_usdc: u64) -> Result<FeeEstimate> {
+        content_pool::instructions::estimate_fees::handler(ctx, volume_usdc)
+    }
+
+    /// View-only instruction: Gross USDC a buy needs to mint exactly `tokens_display_wanted`
+    pub fn quote_buy_exact_out(
+        ctx: Context<QuoteBuyExactOut>,
+        side: TokenSide,
+        tokens_display_wanted: u64,
+        stake_skim: u64,
+    ) -> Result<BuyExactOutQuote> {
+        content_pool::instructions::quote_buy_exact_out::handler(ctx, side, tokens_display_wanted, stake_skim)
+    }
+
+    /// View-only instruction: Largest `amount` a buy can pass to trade before TradeTooLargeForCurve
+    pub fn get_max_buy(ctx: Context<GetMaxBuy>, side: TokenSide, stake_skim: u64) -> Result<MaxBuy> {
+        content_pool::instructions::get_max_buy::handler(ctx, side, stake_skim)
+    }
+
+    /// View-only instruction: What-if value of a buy after a settlement at `bd_score` (not a guarantee)
+    pub fn simulate_buy_then_settle(
+        ctx: Context<SimulateBuyThenSettle>,
+        side: TokenSide,
+        amount: u64,
+        stake_skim: u64,
+        bd_score: u32,
+        zero_sum: bool,
+    ) -> Result<BuyThenSettleSimulation> {
+        content_pool::instructions::simulate_buy_then_settle::handler(ctx, side, amount, stake_skim, bd_score, zero_sum)
+    }
+
+    /// View-only instruction: Loss from buying `usdc_amount` and immediately selling the tokens back
+    pub fn get_round_trip_cost(
+        ctx: Context<GetRoundTripCost>,
+        side: TokenSide,
+        usdc_amount: u64,
+    ) -> Result<RoundTripCost> {
+        content_pool::instructions::get_round_trip_cost::handler(ctx, side, usdc_amount)
+    }
+
+    /// View-only instruction: Lifetime creator fees paid by trades on a pool
+    pub fn get_creator_earnings(ctx: Context<GetCreatorEarnings>) -> Result<CreatorEarnings> {
+        content_pool::instructions::get_creator_earnings::handler(ctx)
+    }
+
+    /// View-only instruction: Curve marginal price at hypothetical supplies, σ and λ (no pool needed)
+    pub fn get_price_at_supply(
+        ctx: Context<GetPriceAtSupply>,
+        side: TokenSide,
+        s_long: u64,
+        s_short: u64,
+        sigma_long_q64: u128,
+        sigma_short_q64: u128,
+        lambda_q96: u128,
+    ) -> Result<PriceAtSupply> {
+        content_pool::instructions::get_price_at_supply::handler(
+            ctx,
+            side,
+            s_long,
+            s_short,
+            sigma_long_q64,
+            sigma_short_q64,
+            lambda_q96,
+        )
+    }
+
+    /// View-only instruction: Compare pool display supplies with SPL mint supplies
+    pub fn get_supply_reconciliation(ctx: Context<GetSupplyReconciliation>) -> Result<SupplyReconciliation> {
+        content_pool::instructions::get_supply_reconciliation::handler(ctx)
+    }
+
+    /// View-only instruction: Side and USDC to buy to move q to `target_q_bps`
+    pub fn cost_to_move_q(ctx: Context<CostToMoveQ>, target_q_bps: u16) -> Result<MoveQCost> {
+        content_pool::instructions::cost_to_move_q::handler(ctx, target_q_bps)
+    }
+
+    /// View-only instruction: Whether a trade would pass the pool's guards, and if not why
+    /// Takes the factory so sells see the same token cap and dust floor as trade
+    pub fn can_trade(
+        ctx: Context<CanTrade>,
+        side: TokenSide,
+        trade_type: TradeType,
+        amount: u64,
+    ) -> Result<TradeCheck> {
+        content_pool::instructions::can_trade::handler(ctx, side, trade_type, amount)
+    }
+
+    /// Read-only check that the post creator and treasury USDC accounts exist with the right mint
+    /// Call alongside create_pool so fee-recipient problems fail before the first trade
+    pub fn verify_fee_accounts(ctx: Context<VerifyFeeAccounts>) -> Result<()> {
+        content_pool::instructions::verify_fee_accounts::handler(ctx)
+    }
+
     // ============================================================================
     // PoolFactory Instructions
     // ============================================================================
@@ -122,6 +431,11 @@ pub mod veritas_curation {
         )
     }
 
+    // The top-level governance key is the program's upgrade authority, which no instruction
+    // here can move. Transfer it only with the loader's checked SetAuthorityChecked, i.e.
+    // `solana program set-upgrade-authority <PROGRAM_ID> --new-upgrade-authority <NEW_KEYPAIR>`
+    // without `--skip-new-upgrade-authority-signer-check`: the new key must sign, so a
+    // mistyped key is rejected instead of locking governance out.
     pub fn update_protocol_authority(
         ctx: Context<UpdateProtocolAuthority>,
         new_authority: Pubkey,
@@ -129,20 +443,101 @@ pub mod veritas_curation {
         pool_factory::instructions::update_protocol_authority(ctx, new_authority)
     }
 
+    pub fn propose_protocol_authority(
+        ctx: Context<ProposeProtocolAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        pool_factory::instructions::propose_protocol_authority(ctx, new_authority)
+    }
+
+    pub fn accept_protocol_authority(ctx: Context<AcceptProtocolAuthority>) -> Result<()> {
+        pool_factory::instructions::accept_protocol_authority(ctx)
+    }
+
+    pub fn cancel_protocol_authority_transfer(
+        ctx: Context<CancelProtocolAuthorityTransfer>,
+    ) -> Result<()> {
+        pool_factory::instructions::cancel_protocol_authority_transfer(ctx)
+    }
+
     pub fn update_fee_config(
         ctx: Context<UpdateFeeConfig>,
         new_total_fee_bps: Option<u16>,
         new_creator_split_bps: Option<u16>,
+        new_pol_bps: Option<u16>,
+        new_creator_fee_floor: Option<bool>,
         update_treasury: bool,
     ) -> Result<()> {
         pool_factory::instructions::update_fee_config(
             ctx,
             new_total_fee_bps,
             new_creator_split_bps,
+            new_pol_bps,
+            new_creator_fee_floor,
             update_treasury,
         )
     }
 
+    pub fn update_protocol_treasury(
+        ctx: Context<UpdateProtocolTreasury>,
+        new_treasury: Pubkey,
+    ) -> Result<()> {
+        pool_factory::instructions::update_protocol_treasury(ctx, new_treasury)
+    }
+
+    pub fn toggle_creation_pause(
+        ctx: Context<ToggleCreationPause>,
+        paused: bool,
+    ) -> Result<()> {
+        pool_factory::instructions::toggle_creation_pause(ctx, paused)
+    }
+
+    pub fn toggle_early_exit_fee(
+        ctx: Context<ToggleEarlyExitFee>,
+        enabled: bool,
+    ) -> Result<()> {
+        pool_factory::instructions::toggle_early_exit_fee(ctx, enabled)
+    }
+
+    pub fn toggle_test_mode(
+        ctx: Context<ToggleTestMode>,
+        enabled: bool,
+    ) -> Result<()> {
+        pool_factory::instructions::toggle_test_mode(ctx, enabled)
+    }
+
+    pub fn update_settlement_authority(
+        ctx: Context<UpdateSettlementAuthority>,
+        new_settlement_authority: Pubkey,
+    ) -> Result<()> {
+        pool_factory::instructions::update_settlement_authority(ctx, new_settlement_authority)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        pool_factory::instructions::migrate_account(ctx)
+    }
+
+    pub fn update_settle_interval_floor(
+        ctx: Context<UpdateSettleIntervalFloor>,
+        global_min_settle_interval: i64,
+    ) -> Result<()> {
+        pool_factory::instructions::update_settle_interval_floor(ctx, global_min_settle_interval)
+    }
+
+    pub fn update_min_sell_value(
+        ctx: Context<UpdateMinSellValue>,
+        min_sell_value_micro: u64,
+    ) -> Result<()> {
+        pool_factory::instructions::update_min_sell_value(ctx, min_sell_value_micro)
+    }
+
+    pub fn update_max_tokens_per_trade(
+        ctx: Context<UpdateMaxTokensPerTrade>,
+        max_tokens_per_trade: u64,
+    ) -> Result<()> {
+        pool_factory::instructions::update_max_tokens_per_trade(ctx, max_tokens_per_trade)
+    }
+
     pub fn update_defaults(
         ctx: Context<UpdateDefaults>,
         default_f: Option<u16>,
@@ -186,6 +581,17 @@ pub mod veritas_curation {
         veritas_custodian::instructions::withdraw(ctx, amount, recipient)
     }
 
+    /// Withdraw with a recipient-signed authorization of (amount, recipient, nonce)
+    /// The preceding instruction must be an Ed25519 program signature verification
+    pub fn withdraw_authorized(
+        ctx: Context<WithdrawAuthorized>,
+        amount: u64,
+        recipient: Pubkey,
+        nonce: u64,
+    ) -> Result<()> {
+        veritas_custodian::instructions::withdraw_authorized(ctx, amount, recipient, nonce)
+    }
+
     pub fn update_custodian_protocol_authority(
         ctx: Context<UpdateCustodianProtocolAuthority>,
         new_protocol_authority: Pubkey,
@@ -15,12 +15,20 @@ pub enum FactoryError {
     InvalidContentId = 7011,
     #[msg("Invalid ICBS parameters")]
     InvalidParameters = 7012,
+    #[msg("Pool creation is paused")]
+    CreationPaused = 7013,
+    #[msg("Custodian does not match the factory's custodian")]
+    CustodianMismatch = 7014,
 
     // Authority (7020-7029)
     #[msg("Unauthorized (not factory authority)")]
     Unauthorized = 7020,
     #[msg("Unauthorized protocol authority")]
     UnauthorizedProtocol = 7021,
+    #[msg("No protocol authority transfer is pending")]
+    NoPendingAuthority = 7022,
+    #[msg("Signer is not the proposed protocol authority")]
+    NotPendingAuthority = 7023,
 
     // Parameters (7030-7039)
     #[msg("Invalid growth exponent F")]
@@ -31,16 +39,26 @@ pub enum FactoryError {
     InvalidMinDeposit = 7032,
     #[msg("Invalid settle interval")]
     InvalidSettleInterval = 7033,
+    #[msg("Invalid minimum sell value - must be <= MAX_MIN_SELL_VALUE_MICRO")]
+    InvalidMinSellValue = 7034,
 
     // Upgrade Authority & Governance (7040-7049)
     #[msg("Invalid upgrade authority")]
     InvalidUpgradeAuthority = 7040,
     #[msg("Invalid program data")]
     InvalidProgramData = 7041,
-    #[msg("Invalid fee configuration")]
+    #[msg("Invalid fee configuration (total_fee_bps above MAX_TOTAL_FEE_BPS)")]
     InvalidFeeConfiguration = 7042,
     #[msg("Invalid creator split - must be <= 10000 basis points")]
     InvalidCreatorSplit = 7043,
     #[msg("Fee calculation overflow")]
     FeeCalculationOverflow = 7044,
+    #[msg("Invalid POL share - must be <= MAX_POL_BPS")]
+    InvalidPolBps = 7045,
+    #[msg("Invalid protocol treasury (default or system program)")]
+    InvalidTreasury = 7046,
+
+    // Migration (7050-7059)
+    #[msg("Account is not a migratable program account")]
+    UnsupportedAccount = 7050,
 }
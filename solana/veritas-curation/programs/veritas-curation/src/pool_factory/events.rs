@@ -32,11 +32,47 @@ pub struct ProtocolAuthorityUpdatedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ProtocolAuthorityProposedEvent {
+    pub factory: Pubkey,
+    pub current_authority: Pubkey,
+    pub proposed_authority: Pubkey,
+    pub proposed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolAuthorityAcceptedEvent {
+    pub factory: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolAuthorityTransferCancelledEvent {
+    pub factory: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolTreasuryUpdatedEvent {
+    pub factory: Pubkey,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct FeeConfigUpdatedEvent {
     pub factory: Pubkey,
     pub total_fee_bps: u16,
     pub creator_split_bps: u16,
+    pub pol_bps: u16,
+    pub creator_fee_floor: bool,
     pub protocol_treasury: Pubkey,
     pub updated_by: Pubkey,
     pub timestamp: i64,
@@ -51,5 +87,76 @@ pub struct DefaultsUpdatedEvent {
     pub default_p0: u64,
     pub min_initial_deposit: u64,
     pub min_settle_interval: i64,
+    pub global_min_settle_interval: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreationPauseToggledEvent {
+    pub factory: Pubkey,
+    pub paused: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EarlyExitFeeToggledEvent {
+    pub factory: Pubkey,
+    pub enabled: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TestModeToggledEvent {
+    pub factory: Pubkey,
+    pub enabled: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettlementAuthorityUpdatedEvent {
+    pub factory: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettleIntervalFloorUpdatedEvent {
+    pub factory: Pubkey,
+    pub global_min_settle_interval: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinSellValueUpdatedEvent {
+    pub factory: Pubkey,
+    pub old_min_sell_value_micro: u64,
+    pub new_min_sell_value_micro: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxTokensPerTradeUpdatedEvent {
+    pub factory: Pubkey,
+    pub old_max_tokens_per_trade: u64,
+    pub new_max_tokens_per_trade: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigratedEvent {
+    pub account: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub old_len: u64,
+    pub new_len: u64,
+    pub migrated_by: Pubkey,
     pub timestamp: i64,
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::ProtocolAuthorityAcceptedEvent,
+    errors::FactoryError,
+};
+
+/// Second step of a protocol authority transfer: the proposed key takes over
+/// Signing proves the new key is live before the old one loses control
+/// Only callable by the pending protocol authority
+pub fn accept_protocol_authority(ctx: Context<AcceptProtocolAuthority>) -> Result<()> {
+    let factory = &mut ctx.accounts.factory;
+    let signer = ctx.accounts.new_authority.key();
+
+    require!(factory.authority_transfer_pending(), FactoryError::NoPendingAuthority);
+    let old_authority = factory
+        .accept_protocol_authority(&signer)
+        .ok_or(FactoryError::NotPendingAuthority)?;
+
+    emit!(ProtocolAuthorityAcceptedEvent {
+        factory: factory.key(),
+        old_authority,
+        new_authority: signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptProtocolAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub new_authority: Signer<'info>,
+}
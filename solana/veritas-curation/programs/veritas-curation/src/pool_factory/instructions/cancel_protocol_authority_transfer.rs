@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::ProtocolAuthorityTransferCancelledEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Withdraw a pending protocol authority proposal; the current authority is unchanged
+/// Only callable by upgrade authority (governance)
+pub fn cancel_protocol_authority_transfer(
+    ctx: Context<CancelProtocolAuthorityTransfer>,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    let factory = &mut ctx.accounts.factory;
+    let cancelled_authority = factory
+        .cancel_protocol_authority_transfer()
+        .ok_or(FactoryError::NoPendingAuthority)?;
+
+    emit!(ProtocolAuthorityTransferCancelledEvent {
+        factory: factory.key(),
+        cancelled_authority,
+        cancelled_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelProtocolAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
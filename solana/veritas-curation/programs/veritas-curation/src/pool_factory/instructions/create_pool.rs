@@ -8,7 +8,7 @@ use crate::pool_factory::{
     events::PoolCreatedEvent,
     errors::FactoryError,
 };
-use crate::veritas_custodian::state::VeritasCustodian;
+use crate::veritas_custodian::state::{VeritasCustodian, CUSTODIAN_SEED};
 
 /// Create a new ContentPool via PoolFactory
 /// Users can create pools but parameters are controlled by the factory authority
@@ -16,6 +16,15 @@ pub fn create_pool(
     ctx: Context<CreatePool>,
     content_id: Pubkey,
 ) -> Result<()> {
+    require!(!ctx.accounts.factory.creation_paused, FactoryError::CreationPaused);
+
+    // The pool's stake vault comes from this custodian, so it must be the factory's own;
+    // otherwise a caller could route every skim on the pool to a vault they control
+    require!(
+        ctx.accounts.factory.uses_custodian(&ctx.accounts.custodian.key()),
+        FactoryError::CustodianMismatch
+    );
+
     let factory = &mut ctx.accounts.factory;
     let pool = &mut ctx.accounts.pool;
     let registry = &mut ctx.accounts.registry;
@@ -44,7 +53,12 @@ pub fn create_pool(
     pool.f = f;
     pool.beta_num = beta_num;
     pool.beta_den = beta_den;
-    pool._padding1 = [0; 10];
+    pool.fee_bps_override = 0;        // No override: use factory fee config
+    pool.creator_split_override = 0;
+    pool.fee_override_set = false;
+    pool.creator_split_override_set = false;
+    pool.early_exit_fee_bps = 0;      // No early-exit fee until configured
+    pool.min_hold_secs = 0;
 
     // Initial supplies and reserves (all zero)
     pool.s_long = 0;
@@ -58,7 +72,11 @@ pub fn create_pool(
     pool.lambda_long_q96 = 0;
     pool.lambda_short_q96 = 0;
 
-    // Settlement parameters
+    // Settlement parameters - the inherited cooldown must respect the factory floor
+    require!(
+        factory.settle_interval_allowed(factory.min_settle_interval),
+        FactoryError::InvalidSettleInterval
+    );
     pool.last_settle_ts = 0;
     pool.min_settle_interval = factory.min_settle_interval;
     pool.current_epoch = 0;
@@ -77,7 +95,15 @@ pub fn create_pool(
 
     // PDA bump
     pool.bump = ctx.bumps.pool;
-    pool._padding2 = [0; 7];
+    pool.version = ContentPool::VERSION;
+    pool.closed = false;
+    pool.wind_down = false;
+    pool._padding2 = [0; 2];
+
+    // Lifecycle timestamps - deployed_at is stamped by deploy_market
+    pool.created_at = current_time;
+    pool.deployed_at = 0;
+    pool.cumulative_creator_fees = 0;
 
     // Create registry entry
     registry.content_id = content_id;
@@ -144,7 +170,12 @@ pub struct CreatePool<'info> {
     )]
     pub registry: Account<'info, PoolRegistry>,
 
-    /// VeritasCustodian (for stake vault reference)
+    /// VeritasCustodian (for stake vault reference); must be factory.custodian
+    /// Pinned to the singleton PDA, so only the real custodian's vault can be recorded
+    #[account(
+        seeds = [CUSTODIAN_SEED],
+        bump = custodian.bump
+    )]
     pub custodian: Account<'info, VeritasCustodian>,
 
     /// Pool creator (who initiates pool creation)
@@ -0,0 +1,93 @@
+//! View-only instruction: Returns factory-level metrics for dashboards
+//!
+//! Does NOT mutate on-chain state - factory analog of get_current_state, so dashboards
+//! don't have to deserialize PoolFactory or track its layout version.
+//! Used by: protocol dashboards
+
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::state::{PoolFactory, FACTORY_SEED};
+
+#[derive(Accounts)]
+pub struct GetFactoryStats<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+}
+
+pub fn get_factory_stats(ctx: Context<GetFactoryStats>) -> Result<FactoryStats> {
+    Ok(factory_stats(&ctx.accounts.factory))
+}
+
+fn factory_stats(factory: &PoolFactory) -> FactoryStats {
+    FactoryStats {
+        total_pools: factory.total_pools,
+        total_fee_bps: factory.total_fee_bps,
+        creator_split_bps: factory.creator_split_bps,
+        pol_bps: factory.pol_bps,
+        protocol_treasury: factory.protocol_treasury,
+        min_settle_interval: factory.min_settle_interval,
+        global_min_settle_interval: factory.global_min_settle_interval,
+        creation_paused: factory.creation_paused,
+        early_exit_fee_enabled: factory.early_exit_fee_enabled,
+        test_mode: factory.test_mode,
+        version: factory.version,
+    }
+}
+
+/// Return type for get_factory_stats view function
+///
+/// There is no TVL or total volume: every trade would have to write such an accumulator,
+/// serializing trades across pools on the factory. Dashboards sum pools' vault_balance
+/// (or the latest vault_balance_after per pool from TradeEvents) and TradeEvent volumes.
+/// Field order is append-only.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FactoryStats {
+    /// Pools created by this factory (closed pools included)
+    pub total_pools: u64,
+    /// Default total trading fee in bps (pools may override)
+    pub total_fee_bps: u16,
+    /// Default creator share of the fee in bps (pools may override)
+    pub creator_split_bps: u16,
+    /// Share of buys retained as protocol-owned liquidity in bps
+    pub pol_bps: u16,
+    /// Protocol treasury address
+    pub protocol_treasury: Pubkey,
+    /// Settlement cooldown new pools inherit (seconds)
+    pub min_settle_interval: i64,
+    /// Floor on any pool's settlement cooldown (seconds)
+    pub global_min_settle_interval: i64,
+    /// True while create_pool/deploy_market are paused
+    pub creation_paused: bool,
+    /// Master switch for per-pool early-exit fees
+    pub early_exit_fee_enabled: bool,
+    /// True if test mode is cutting settle cooldowns to 1s (never on in production)
+    pub test_mode: bool,
+    /// Factory account layout version
+    pub version: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::zeroed_factory;
+
+    #[test]
+    fn test_factory_stats_mirror_factory() {
+        let mut factory = zeroed_factory();
+        factory.total_pools = 3;
+        factory.total_fee_bps = 50;
+        factory.creator_split_bps = 2_000;
+        factory.creation_paused = true;
+        factory.version = PoolFactory::VERSION;
+
+        let stats = factory_stats(&factory);
+        assert_eq!(stats.total_pools, 3);
+        assert_eq!((stats.total_fee_bps, stats.creator_split_bps), (50, 2_000));
+        assert!(stats.creation_paused);
+        assert!(!stats.test_mode);
+        assert_eq!(stats.version, PoolFactory::VERSION);
+    }
+}
@@ -11,10 +11,14 @@ use crate::pool_factory::{
         DEFAULT_P0,
         DEFAULT_MIN_INITIAL_DEPOSIT,
         DEFAULT_MIN_SETTLE_INTERVAL,
+        DEFAULT_GLOBAL_MIN_SETTLE_INTERVAL,
+        DEFAULT_POL_BPS,
+        DEFAULT_MIN_SELL_VALUE_MICRO,
     },
     events::FactoryInitializedEvent,
     errors::FactoryError,
 };
+use crate::utils::require_upgrade_authority;
 use crate::program::VeritasCuration;
 
 /// Initialize the singleton factory PDA with protocol authority and fee configuration
@@ -27,24 +31,12 @@ pub fn initialize_factory(
     protocol_treasury: Pubkey,
 ) -> Result<()> {
     // Validate upgrade authority
-    let program_data_bytes = ctx.accounts.program_data.try_borrow_data()?;
-    if program_data_bytes.len() < 45 {
-        return Err(FactoryError::InvalidProgramData.into());
-    }
-
-    // Deserialize: first 4 bytes = discriminator, next 8 = slot, next 1 = Option tag, next 32 = Pubkey
-    let upgrade_authority_option = if program_data_bytes[12] == 0 {
-        None
-    } else {
-        let mut pubkey_bytes = [0u8; 32];
-        pubkey_bytes.copy_from_slice(&program_data_bytes[13..45]);
-        Some(Pubkey::new_from_array(pubkey_bytes))
-    };
-
-    require!(
-        upgrade_authority_option == Some(ctx.accounts.upgrade_authority.key()),
-        FactoryError::InvalidUpgradeAuthority
-    );
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
 
     let factory = &mut ctx.accounts.factory;
     let clock = Clock::get()?;
@@ -72,6 +64,10 @@ pub fn initialize_factory(
     );
 
     // Validate fee configuration
+    require!(
+        PoolFactory::total_fee_allowed(total_fee_bps),
+        FactoryError::InvalidFeeConfiguration
+    );
     require!(
         creator_split_bps <= 10000,
         FactoryError::InvalidCreatorSplit
@@ -83,7 +79,7 @@ pub fn initialize_factory(
     factory.total_fee_bps = total_fee_bps;
     factory.creator_split_bps = creator_split_bps;
     factory.protocol_treasury = protocol_treasury;
-    factory._padding_fee = [0; 2];
+    factory.pol_bps = DEFAULT_POL_BPS;
     factory.default_f = DEFAULT_F;
     factory.default_beta_num = DEFAULT_BETA_NUM;
     factory.default_beta_den = DEFAULT_BETA_DEN;
@@ -92,6 +88,16 @@ pub fn initialize_factory(
     factory.min_settle_interval = DEFAULT_MIN_SETTLE_INTERVAL;
     factory.custodian = custodian;
     factory.bump = ctx.bumps.factory;
+    factory.creation_paused = false;
+    factory.global_min_settle_interval = DEFAULT_GLOBAL_MIN_SETTLE_INTERVAL;
+    factory.creator_fee_floor = false;
+    factory.settlement_authority = Pubkey::default();
+    factory.version = PoolFactory::VERSION;
+    factory.early_exit_fee_enabled = false;
+    factory.test_mode = false;
+    factory.min_sell_value_micro = DEFAULT_MIN_SELL_VALUE_MICRO;
+    factory.max_tokens_per_trade = 0; // Unlimited until governance sets a cap
+    factory.pending_protocol_authority = Pubkey::default();
 
     emit!(FactoryInitializedEvent {
         factory: factory.key(),
@@ -0,0 +1,342 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+
+use crate::content_pool::state::ContentPool;
+use crate::pool_factory::{
+    state::PoolFactory,
+    events::AccountMigratedEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Bring a ContentPool or PoolFactory written under an older layout up to the current one
+/// Grows the account (topping up rent from the upgrade authority) and stamps the current
+/// version. Safe to call on an already-current account.
+/// A v1 factory is rewritten field by field from the original layout.
+/// Only callable by upgrade authority (governance)
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    let account = &ctx.accounts.account;
+    require!(account.owner == ctx.program_id, FactoryError::UnsupportedAccount);
+
+    let old_len = account.data_len();
+    let new_len = current_len(&account.try_borrow_data()?)?.max(old_len);
+
+    if new_len > old_len {
+        let rent_needed = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(account.lamports());
+        if rent_needed > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.upgrade_authority.to_account_info(),
+                        to: account.to_account_info(),
+                    },
+                ),
+                rent_needed,
+            )?;
+        }
+        account.resize(new_len)?;
+    }
+
+    let (old_version, new_version) =
+        upgrade_in_place(&mut account.try_borrow_mut_data()?, old_len)?;
+
+    emit!(AccountMigratedEvent {
+        account: account.key(),
+        old_version,
+        new_version,
+        old_len: old_len as u64,
+        new_len: new_len as u64,
+        migrated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Current serialized size (discriminator included) for the account type in `data`
+fn current_len(data: &[u8]) -> Result<usize> {
+    if data.starts_with(PoolFactory::DISCRIMINATOR) {
+        Ok(8 + PoolFactory::LEN)
+    } else if data.starts_with(ContentPool::DISCRIMINATOR) {
+        Ok(8 + ContentPool::LEN)
+    } else {
+        err!(FactoryError::UnsupportedAccount)
+    }
+}
+
+/// PoolFactory as originally deployed (v1): no `version` field, and the 2 bytes that
+/// are now `pol_bps` were padding
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub(crate) struct PoolFactoryV1 {
+    pub protocol_authority: Pubkey,
+    pub total_pools: u64,
+    pub total_fee_bps: u16,
+    pub creator_split_bps: u16,
+    pub protocol_treasury: Pubkey,
+    pub _padding_fee: [u8; 2],
+    pub default_f: u16,
+    pub default_beta_num: u16,
+    pub default_beta_den: u16,
+    pub default_p0: u64,
+    pub min_initial_deposit: u64,
+    pub min_settle_interval: i64,
+    pub custodian: Pubkey,
+    pub bump: u8,
+}
+
+impl PoolFactoryV1 {
+    pub const LEN: usize = 32 + 8 + 2 + 2 + 32 + 2 + 2 + 2 + 2 + 8 + 8 + 8 + 32 + 1; // 141 bytes
+
+    /// Current layout from v1 fields; everything v1 lacked starts disabled / unset
+    fn upgrade(self) -> PoolFactory {
+        PoolFactory {
+            protocol_authority: self.protocol_authority,
+            total_pools: self.total_pools,
+            total_fee_bps: self.total_fee_bps,
+            creator_split_bps: self.creator_split_bps,
+            protocol_treasury: self.protocol_treasury,
+            pol_bps: 0,
+            default_f: self.default_f,
+            default_beta_num: self.default_beta_num,
+            default_beta_den: self.default_beta_den,
+            default_p0: self.default_p0,
+            min_initial_deposit: self.min_initial_deposit,
+            min_settle_interval: self.min_settle_interval,
+            custodian: self.custodian,
+            bump: self.bump,
+            creation_paused: false,
+            global_min_settle_interval: 0,
+            creator_fee_floor: false,
+            settlement_authority: Pubkey::default(),
+            version: PoolFactory::VERSION,
+            early_exit_fee_enabled: false,
+            test_mode: false,
+            min_sell_value_micro: 0,
+            max_tokens_per_trade: 0,
+            pending_protocol_authority: Pubkey::default(),
+        }
+    }
+}
+
+/// Rewrite an account buffer already sized for the current layout, stamping the current version
+///
+/// `old_len` is the account's size before it was grown. Two factory layouts are handled:
+/// v1 (8 + PoolFactoryV1::LEN), decoded with that layout and re-encoded field by field,
+/// and the current one, which must already carry the current version; any other size
+/// or version is rejected. Pool fields are only ever appended or placed in former
+/// padding, so a pool is read with the current layout; a stored version of 0 is
+/// reported as v1.
+/// Returns (old_version, new_version)
+pub(crate) fn upgrade_in_place(data: &mut [u8], old_len: usize) -> Result<(u8, u8)> {
+    if data.starts_with(PoolFactory::DISCRIMINATOR) {
+        if old_len == 8 + PoolFactoryV1::LEN {
+            let v1 = PoolFactoryV1::deserialize(&mut &data[8..8 + PoolFactoryV1::LEN])?;
+            v1.upgrade().try_serialize(&mut &mut data[..])?;
+            return Ok((1, PoolFactory::VERSION));
+        }
+        require!(old_len == 8 + PoolFactory::LEN, FactoryError::UnsupportedAccount);
+        let factory = PoolFactory::try_deserialize(&mut &data[..])?;
+        require!(factory.version == PoolFactory::VERSION, FactoryError::UnsupportedAccount);
+        Ok((factory.version, factory.version))
+    } else if data.starts_with(ContentPool::DISCRIMINATOR) {
+        let mut pool = ContentPool::try_deserialize(&mut &data[..])?;
+        let old_version = pool.version.max(1);
+        pool.version = ContentPool::VERSION;
+        pool.try_serialize(&mut &mut data[..])?;
+        Ok((old_version, pool.version))
+    } else {
+        err!(FactoryError::UnsupportedAccount)
+    }
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Any ContentPool or PoolFactory; owner and discriminator checked in handler
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    /// Pays any extra rent for the larger layout
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::test_utils::{zeroed_factory, zeroed_pool};
+
+    /// A PoolFactory account as originally deployed (v1 layout, 8 + 141 bytes)
+    fn v1_factory_bytes(protocol_authority: Pubkey, custodian: Pubkey) -> Vec<u8> {
+        let v1 = PoolFactoryV1 {
+            protocol_authority,
+            total_pools: 7,
+            total_fee_bps: 50,
+            creator_split_bps: 2000,
+            protocol_treasury: Pubkey::new_unique(),
+            _padding_fee: [0; 2],
+            default_f: 1,
+            default_beta_num: 1,
+            default_beta_den: 2,
+            default_p0: 1_000_000,
+            min_initial_deposit: 50_000_000,
+            min_settle_interval: 7200,
+            custodian,
+            bump: 254,
+        };
+        let mut bytes = PoolFactory::DISCRIMINATOR.to_vec();
+        v1.serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 8 + 141);
+        bytes
+    }
+
+    #[test]
+    fn test_migrate_factory_v1_to_current() {
+        let (protocol_authority, custodian) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = v1_factory_bytes(protocol_authority, custodian);
+        let old_len = data.len();
+
+        // The current layout cannot read a v1 account
+        assert!(PoolFactory::try_deserialize(&mut &data[..]).is_err());
+
+        // What migrate_account does: grow, then rewrite field by field
+        let new_len = current_len(&data).unwrap();
+        assert_eq!(new_len, 8 + PoolFactory::LEN);
+        data.resize(new_len, 0);
+        assert_eq!(upgrade_in_place(&mut data, old_len).unwrap(), (1, PoolFactory::VERSION));
+
+        // Every v1 field survives at its value, not a shifted one
+        let factory = PoolFactory::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(factory.version, PoolFactory::VERSION);
+        assert_eq!(factory.protocol_authority, protocol_authority);
+        assert_eq!(factory.total_pools, 7);
+        assert_eq!((factory.total_fee_bps, factory.creator_split_bps), (50, 2000));
+        assert_eq!((factory.default_f, factory.default_beta_num, factory.default_beta_den), (1, 1, 2));
+        assert_eq!(factory.default_p0, 1_000_000);
+        assert_eq!(factory.min_initial_deposit, 50_000_000);
+        assert_eq!(factory.min_settle_interval, 7200);
+        assert_eq!(factory.custodian, custodian);
+        assert_eq!(factory.bump, 254);
+
+        // Fields v1 lacked start off
+        assert_eq!(factory.pol_bps, 0);
+        assert_eq!(factory.settlement_authority, Pubkey::default());
+        assert!(!factory.early_exit_fee_enabled);
+        assert!(!factory.test_mode);
+        assert_eq!(factory.min_sell_value_micro, 0);
+        assert_eq!(factory.max_tokens_per_trade, 0);
+        assert_eq!(factory.pending_protocol_authority, Pubkey::default());
+
+        // Migrating again is a no-op
+        let len = data.len();
+        assert_eq!(upgrade_in_place(&mut data, len).unwrap(), (PoolFactory::VERSION, PoolFactory::VERSION));
+        let again = PoolFactory::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(again.total_pools, 7);
+    }
+
+    #[test]
+    fn test_migrate_rejects_unknown_factory_layout() {
+        // Current size but not the current version: rejected rather than guessed at
+        let mut factory = zeroed_factory();
+        factory.version = PoolFactory::VERSION - 1;
+        let mut data = Vec::new();
+        factory.try_serialize(&mut data).unwrap();
+        let len = data.len();
+        assert!(upgrade_in_place(&mut data, len).is_err());
+
+        // Neither v1 nor current size
+        let mut data = PoolFactory::DISCRIMINATOR.to_vec();
+        data.resize(8 + 199, 0);
+        let old_len = data.len();
+        data.resize(8 + PoolFactory::LEN, 0);
+        assert!(upgrade_in_place(&mut data, old_len).is_err());
+    }
+
+    #[test]
+    fn test_migrate_pool_stamps_version_in_place() {
+        let mut data = ContentPool::DISCRIMINATOR.to_vec();
+        data.resize(8 + ContentPool::LEN, 0);
+
+        // Pools gained `version` in former padding, so no resize is needed
+        let len = data.len();
+        assert_eq!(current_len(&data).unwrap(), len);
+        assert_eq!(upgrade_in_place(&mut data, len).unwrap(), (1, ContentPool::VERSION));
+        let pool = ContentPool::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(pool.version, ContentPool::VERSION);
+    }
+
+    #[test]
+    fn test_migrate_pool_v2_grows_for_trade_seq() {
+        let mut pool = zeroed_pool();
+        pool.version = 2;
+        pool.current_epoch = 9;
+
+        let mut data = Vec::new();
+        pool.try_serialize(&mut data).unwrap();
+        // v2 ended at _padding2; trade_seq was appended in v3
+        data.truncate(8 + 496);
+        assert!(ContentPool::try_deserialize(&mut &data[..]).is_err());
+
+        let old_len = data.len();
+        let new_len = current_len(&data).unwrap();
+        assert_eq!(new_len, 8 + ContentPool::LEN);
+        data.resize(new_len, 0);
+        assert_eq!(upgrade_in_place(&mut data, old_len).unwrap(), (2, ContentPool::VERSION));
+
+        let pool = ContentPool::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(pool.current_epoch, 9);
+        assert_eq!(pool.trade_seq, 0);
+        assert_eq!(pool.deployed_at, 0);
+    }
+
+    #[test]
+    fn test_migrate_pool_v3_grows_for_timestamps() {
+        let mut pool = zeroed_pool();
+        pool.version = 3;
+        pool.trade_seq = 42;
+
+        let mut data = Vec::new();
+        pool.try_serialize(&mut data).unwrap();
+        // v3 ended at trade_seq; created_at / deployed_at were appended in v4
+        data.truncate(8 + 504);
+        assert!(ContentPool::try_deserialize(&mut &data[..]).is_err());
+
+        let old_len = data.len();
+        data.resize(current_len(&data).unwrap(), 0);
+        assert_eq!(upgrade_in_place(&mut data, old_len).unwrap(), (3, ContentPool::VERSION));
+
+        // Timestamps of pre-v4 pools are unknown and read as zero, as do creator
+        // fees paid before v6
+        let pool = ContentPool::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(pool.trade_seq, 42);
+        assert_eq!(pool.created_at, 0);
+        assert_eq!(pool.deployed_at, 0);
+        assert_eq!(pool.cumulative_creator_fees, 0);
+    }
+
+    #[test]
+    fn test_migrate_rejects_other_accounts() {
+        let mut data = vec![0u8; 64];
+        assert!(current_len(&data).is_err());
+        assert!(upgrade_in_place(&mut data, 64).is_err());
+    }
+}
@@ -1,11 +1,37 @@
 pub mod initialize_factory;
 pub mod create_pool;
 pub mod update_protocol_authority;
+pub mod propose_protocol_authority;
+pub mod accept_protocol_authority;
+pub mod cancel_protocol_authority_transfer;
 pub mod update_defaults;
 pub mod update_fee_config;
+pub mod update_protocol_treasury;
+pub mod toggle_creation_pause;
+pub mod toggle_early_exit_fee;
+pub mod toggle_test_mode;
+pub mod update_settle_interval_floor;
+pub mod update_min_sell_value;
+pub mod update_max_tokens_per_trade;
+pub mod update_settlement_authority;
+pub mod migrate_account;
+pub mod get_factory_stats;
 
 pub use initialize_factory::*;
 pub use create_pool::*;
 pub use update_protocol_authority::*;
+pub use propose_protocol_authority::*;
+pub use accept_protocol_authority::*;
+pub use cancel_protocol_authority_transfer::*;
 pub use update_defaults::*;
-pub use update_fee_config::*;
\ No newline at end of file
+pub use update_fee_config::*;
+pub use update_protocol_treasury::*;
+pub use toggle_creation_pause::*;
+pub use toggle_early_exit_fee::*;
+pub use toggle_test_mode::*;
+pub use update_settle_interval_floor::*;
+pub use update_min_sell_value::*;
+pub use update_max_tokens_per_trade::*;
+pub use update_settlement_authority::*;
+pub use migrate_account::*;
+pub use get_factory_stats::*;
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::ProtocolAuthorityProposedEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// First step of a protocol authority transfer: record the proposed key
+/// The current authority stays in control until the proposed key signs accept_protocol_authority,
+/// so a mistyped key can be cancelled or re-proposed instead of locking governance out.
+/// Proposing again replaces any pending proposal.
+/// Only callable by upgrade authority (governance)
+pub fn propose_protocol_authority(
+    ctx: Context<ProposeProtocolAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    require!(
+        new_authority != Pubkey::default(),
+        FactoryError::InvalidAuthority
+    );
+    require!(
+        new_authority != system_program::ID,
+        FactoryError::InvalidAuthority
+    );
+
+    let factory = &mut ctx.accounts.factory;
+    factory.pending_protocol_authority = new_authority;
+
+    emit!(ProtocolAuthorityProposedEvent {
+        factory: factory.key(),
+        current_authority: factory.protocol_authority,
+        proposed_authority: new_authority,
+        proposed_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeProtocolAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
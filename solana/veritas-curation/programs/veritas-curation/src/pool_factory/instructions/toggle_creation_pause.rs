@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::CreationPauseToggledEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Upgrade authority toggles the kill switch for create_pool and deploy_market
+/// Existing pools keep trading and settling; see the custodian's emergency_pause for withdrawals
+/// Only callable by upgrade authority (governance)
+pub fn toggle_creation_pause(
+    ctx: Context<ToggleCreationPause>,
+    paused: bool,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    let factory = &mut ctx.accounts.factory;
+    factory.creation_paused = paused;
+
+    emit!(CreationPauseToggledEvent {
+        factory: factory.key(),
+        paused,
+        updated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ToggleCreationPause<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
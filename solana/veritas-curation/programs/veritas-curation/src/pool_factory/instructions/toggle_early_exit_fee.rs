@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::EarlyExitFeeToggledEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Upgrade authority turns per-pool early-exit fees on or off protocol-wide
+/// Pools still need early_exit_fee_bps and min_hold_secs set (set_early_exit_fee) to charge one
+/// Only callable by upgrade authority (governance)
+pub fn toggle_early_exit_fee(
+    ctx: Context<ToggleEarlyExitFee>,
+    enabled: bool,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    let factory = &mut ctx.accounts.factory;
+    factory.early_exit_fee_enabled = enabled;
+
+    emit!(EarlyExitFeeToggledEvent {
+        factory: factory.key(),
+        enabled,
+        updated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ToggleEarlyExitFee<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED, TEST_MODE_SETTLE_INTERVAL},
+    events::TestModeToggledEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Upgrade authority turns factory test mode on or off
+/// While on, settle_epoch and skip_epoch enforce a 1-second cooldown on every pool
+/// instead of its min_settle_interval. For localnet/devnet test suites only -
+/// it must NEVER be enabled on a production factory.
+/// Only callable by upgrade authority (governance)
+pub fn toggle_test_mode(
+    ctx: Context<ToggleTestMode>,
+    enabled: bool,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    let factory = &mut ctx.accounts.factory;
+    factory.test_mode = enabled;
+    if enabled {
+        msg!("toggle_test_mode: TEST MODE ON - settle cooldown is {}s for every pool", TEST_MODE_SETTLE_INTERVAL);
+    }
+
+    emit!(TestModeToggledEvent {
+        factory: factory.key(),
+        enabled,
+        updated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ToggleTestMode<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::pool_factory::{
-    state::{PoolFactory, FACTORY_SEED, MIN_F, MAX_F, MIN_BETA, MAX_BETA},
+    state::{PoolFactory, FACTORY_SEED, SUPPORTED_F, SUPPORTED_BETA},
     events::DefaultsUpdatedEvent,
     errors::FactoryError,
 };
+use crate::content_pool::math::reduce_fraction;
+use crate::utils::require_upgrade_authority;
 use crate::program::VeritasCuration;
 
 /// Update default ICBS parameters and limits for new pools
@@ -19,53 +21,33 @@ pub fn update_defaults(
     min_settle_interval: Option<i64>,
 ) -> Result<()> {
     // Validate upgrade authority
-    let program_data_bytes = ctx.accounts.program_data.try_borrow_data()?;
-    if program_data_bytes.len() < 45 {
-        return Err(FactoryError::InvalidProgramData.into());
-    }
-
-    // Deserialize: first 4 bytes = discriminator, next 8 = slot, next 1 = Option tag, next 32 = Pubkey
-    let upgrade_authority_option = if program_data_bytes[12] == 0 {
-        None
-    } else {
-        let mut pubkey_bytes = [0u8; 32];
-        pubkey_bytes.copy_from_slice(&program_data_bytes[13..45]);
-        Some(Pubkey::new_from_array(pubkey_bytes))
-    };
-
-    require!(
-        upgrade_authority_option == Some(ctx.accounts.upgrade_authority.key()),
-        FactoryError::InvalidUpgradeAuthority
-    );
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
 
     let factory = &mut ctx.accounts.factory;
     let clock = Clock::get()?;
 
-    // Update F if provided
+    // Update F if provided (the curve implements F = 1 only)
     if let Some(f) = default_f {
-        require!(f >= MIN_F && f <= MAX_F, FactoryError::InvalidF);
+        require!(f == SUPPORTED_F, FactoryError::InvalidF);
         factory.default_f = f;
     }
 
-    // Update β numerator if provided
-    if let Some(num) = default_beta_num {
-        require!(num > 0, FactoryError::InvalidBeta);
-        factory.default_beta_num = num;
-    }
-
-    // Update β denominator if provided
-    if let Some(den) = default_beta_den {
-        require!(den > 0, FactoryError::InvalidBeta);
-        factory.default_beta_den = den;
-    }
-
-    // Validate β range after any updates
-    let beta = (factory.default_beta_num as f64) / (factory.default_beta_den as f64);
-    require!(beta >= MIN_BETA && beta <= MAX_BETA, FactoryError::InvalidBeta);
+    // Update β if either part is provided, validated and stored in lowest terms
+    let (beta_num, beta_den) = normalized_beta(
+        default_beta_num.unwrap_or(factory.default_beta_num),
+        default_beta_den.unwrap_or(factory.default_beta_den),
+    )?;
+    factory.default_beta_num = beta_num;
+    factory.default_beta_den = beta_den;
 
-    // Update p0 if provided
+    // Update p0 if provided (bounded so future deploy_market supplies stay sane)
     if let Some(p0) = default_p0 {
-        require!(p0 > 0, FactoryError::InvalidParameters);
+        require!(PoolFactory::default_p0_allowed(p0), FactoryError::InvalidParameters);
         factory.default_p0 = p0;
     }
 
@@ -75,9 +57,12 @@ pub fn update_defaults(
         factory.min_initial_deposit = min_deposit;
     }
 
-    // Update settle interval if provided
+    // Update settle interval if provided (must respect the factory floor)
     if let Some(interval) = min_settle_interval {
-        require!(interval > 0, FactoryError::InvalidSettleInterval);
+        require!(
+            factory.settle_interval_allowed(interval),
+            FactoryError::InvalidSettleInterval
+        );
         factory.min_settle_interval = interval;
     }
 
@@ -89,12 +74,24 @@ pub fn update_defaults(
         default_p0: factory.default_p0,
         min_initial_deposit: factory.min_initial_deposit,
         min_settle_interval: factory.min_settle_interval,
+        global_min_settle_interval: factory.global_min_settle_interval,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
 
+/// Validate β = num / den and reduce it to lowest terms
+///
+/// The curve only accepts the exact pair (1, 2), so any other ratio would fail every
+/// deploy, and an unreduced 2 / 4 is stored as 1 / 2.
+fn normalized_beta(num: u16, den: u16) -> Result<(u16, u16)> {
+    require!(num > 0 && den > 0, FactoryError::InvalidBeta);
+    let beta = reduce_fraction(num, den);
+    require!(beta == SUPPORTED_BETA, FactoryError::InvalidBeta);
+    Ok(beta)
+}
+
 #[derive(Accounts)]
 pub struct UpdateDefaults<'info> {
     #[account(
@@ -112,3 +109,25 @@ pub struct UpdateDefaults<'info> {
     /// CHECK: Program data account validated in handler
     pub program_data: AccountInfo<'info>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_pool::curve::{ICBSCurve, Q96};
+
+    #[test]
+    fn test_unreduced_beta_normalizes_for_deploy() {
+        // 2 / 4 is stored as 1 / 2, which the curve (and so deploy_market) accepts
+        let (num, den) = normalized_beta(2, 4).unwrap();
+        assert_eq!((num, den), (1, 2));
+        assert!(ICBSCurve::cost_function(1_000, 1_000, Q96, 1, num, den).is_ok());
+        assert!(ICBSCurve::cost_function(1_000, 1_000, Q96, 1, 2, 4).is_err());
+
+        // Any other ratio is rejected, reduced or not
+        assert_eq!(normalized_beta(3, 10).unwrap_err(), FactoryError::InvalidBeta.into());
+        assert_eq!(normalized_beta(6, 20).unwrap_err(), FactoryError::InvalidBeta.into());
+        assert!(normalized_beta(0, 2).is_err());
+        assert!(normalized_beta(2, 0).is_err());
+        assert!(normalized_beta(19, 20).is_err());
+    }
+}
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
 
 use crate::pool_factory::{
-    state::{PoolFactory, FACTORY_SEED},
+    state::{PoolFactory, FACTORY_SEED, MAX_POL_BPS},
     events::FeeConfigUpdatedEvent,
     errors::FactoryError,
 };
+use crate::utils::require_upgrade_authority;
 use crate::program::VeritasCuration;
 
 /// Update fee configuration
@@ -13,32 +14,23 @@ pub fn update_fee_config(
     ctx: Context<UpdateFeeConfig>,
     new_total_fee_bps: Option<u16>,
     new_creator_split_bps: Option<u16>,
+    new_pol_bps: Option<u16>,
+    new_creator_fee_floor: Option<bool>,
     update_treasury: bool,
 ) -> Result<()> {
     // Validate upgrade authority
-    let program_data_bytes = ctx.accounts.program_data.try_borrow_data()?;
-    if program_data_bytes.len() < 45 {
-        return Err(FactoryError::InvalidProgramData.into());
-    }
-
-    // Deserialize: first 4 bytes = discriminator, next 8 = slot, next 1 = Option tag, next 32 = Pubkey
-    let upgrade_authority_option = if program_data_bytes[12] == 0 {
-        None
-    } else {
-        let mut pubkey_bytes = [0u8; 32];
-        pubkey_bytes.copy_from_slice(&program_data_bytes[13..45]);
-        Some(Pubkey::new_from_array(pubkey_bytes))
-    };
-
-    require!(
-        upgrade_authority_option == Some(ctx.accounts.upgrade_authority.key()),
-        FactoryError::InvalidUpgradeAuthority
-    );
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
 
     let factory = &mut ctx.accounts.factory;
 
     // Update total fee if provided
     if let Some(fee) = new_total_fee_bps {
+        require!(PoolFactory::total_fee_allowed(fee), FactoryError::InvalidFeeConfiguration);
         factory.total_fee_bps = fee;
     }
 
@@ -48,6 +40,17 @@ pub fn update_fee_config(
         factory.creator_split_bps = split;
     }
 
+    // Update protocol-owned liquidity share if provided (0 disables)
+    if let Some(pol) = new_pol_bps {
+        require!(pol <= MAX_POL_BPS, FactoryError::InvalidPolBps);
+        factory.pol_bps = pol;
+    }
+
+    // Update creator fee floor policy if provided
+    if let Some(floor) = new_creator_fee_floor {
+        factory.creator_fee_floor = floor;
+    }
+
     // Update treasury if requested
     if update_treasury {
         factory.protocol_treasury = ctx.accounts.new_protocol_treasury.key();
@@ -57,6 +60,8 @@ pub fn update_fee_config(
         factory: factory.key(),
         total_fee_bps: factory.total_fee_bps,
         creator_split_bps: factory.creator_split_bps,
+        pol_bps: factory.pol_bps,
+        creator_fee_floor: factory.creator_fee_floor,
         protocol_treasury: factory.protocol_treasury,
         updated_by: ctx.accounts.upgrade_authority.key(),
         timestamp: Clock::get()?.unix_timestamp,
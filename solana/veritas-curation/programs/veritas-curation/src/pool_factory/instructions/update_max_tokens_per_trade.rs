@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::MaxTokensPerTradeUpdatedEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Set the most display tokens a single trade may mint (buy) or burn (sell)
+/// Bounds per-transaction supply change where token prices make USDC limits too loose;
+/// 0 removes the cap
+/// Only callable by upgrade authority (governance)
+pub fn update_max_tokens_per_trade(
+    ctx: Context<UpdateMaxTokensPerTrade>,
+    max_tokens_per_trade: u64,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    let factory = &mut ctx.accounts.factory;
+    let old_max_tokens_per_trade = factory.max_tokens_per_trade;
+    factory.max_tokens_per_trade = max_tokens_per_trade;
+
+    emit!(MaxTokensPerTradeUpdatedEvent {
+        factory: factory.key(),
+        old_max_tokens_per_trade,
+        new_max_tokens_per_trade: max_tokens_per_trade,
+        updated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMaxTokensPerTrade<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED, MAX_MIN_SELL_VALUE_MICRO},
+    events::MinSellValueUpdatedEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Set the smallest sell (valued at the pool's current price) any trade may make
+/// Dust sells still cost a full curve computation and an event; 0 disables the floor
+/// Only callable by upgrade authority (governance)
+pub fn update_min_sell_value(
+    ctx: Context<UpdateMinSellValue>,
+    min_sell_value_micro: u64,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    require!(
+        min_sell_value_micro <= MAX_MIN_SELL_VALUE_MICRO,
+        FactoryError::InvalidMinSellValue
+    );
+
+    let factory = &mut ctx.accounts.factory;
+    let old_min_sell_value_micro = factory.min_sell_value_micro;
+    factory.min_sell_value_micro = min_sell_value_micro;
+
+    emit!(MinSellValueUpdatedEvent {
+        factory: factory.key(),
+        old_min_sell_value_micro,
+        new_min_sell_value_micro: min_sell_value_micro,
+        updated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMinSellValue<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
@@ -6,33 +6,25 @@ use crate::pool_factory::{
     events::ProtocolAuthorityUpdatedEvent,
     errors::FactoryError,
 };
+use crate::utils::require_upgrade_authority;
 use crate::program::VeritasCuration;
 
-/// Updates protocol authority used by all pools for operations
+/// Updates protocol authority used by all pools for operations, in one step
+/// Cancels any pending propose_protocol_authority transfer. A mistyped key cannot lock
+/// governance out, since the upgrade authority can set it again; planned rotations should
+/// still use propose_protocol_authority / accept_protocol_authority.
 /// Only callable by upgrade authority (governance)
 pub fn update_protocol_authority(
     ctx: Context<UpdateProtocolAuthority>,
     new_authority: Pubkey,
 ) -> Result<()> {
     // Validate upgrade authority
-    let program_data_bytes = ctx.accounts.program_data.try_borrow_data()?;
-    if program_data_bytes.len() < 45 {
-        return Err(FactoryError::InvalidProgramData.into());
-    }
-
-    // Deserialize: first 4 bytes = discriminator, next 8 = slot, next 1 = Option tag, next 32 = Pubkey
-    let upgrade_authority_option = if program_data_bytes[12] == 0 {
-        None
-    } else {
-        let mut pubkey_bytes = [0u8; 32];
-        pubkey_bytes.copy_from_slice(&program_data_bytes[13..45]);
-        Some(Pubkey::new_from_array(pubkey_bytes))
-    };
-
-    require!(
-        upgrade_authority_option == Some(ctx.accounts.upgrade_authority.key()),
-        FactoryError::InvalidUpgradeAuthority
-    );
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
 
     let factory = &mut ctx.accounts.factory;
     let clock = Clock::get()?;
@@ -46,8 +38,7 @@ pub fn update_protocol_authority(
         FactoryError::InvalidAuthority
     );
 
-    let old_authority = factory.protocol_authority;
-    factory.protocol_authority = new_authority;
+    let old_authority = factory.set_protocol_authority(new_authority);
 
     emit!(ProtocolAuthorityUpdatedEvent {
         factory: factory.key(),
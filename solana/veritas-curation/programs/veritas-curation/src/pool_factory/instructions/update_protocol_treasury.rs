@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::ProtocolTreasuryUpdatedEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Rotate the protocol treasury without touching fee parameters
+/// update_fee_config's update_treasury flag still works; this is the focused path
+/// Only callable by upgrade authority (governance)
+pub fn update_protocol_treasury(
+    ctx: Context<UpdateProtocolTreasury>,
+    new_treasury: Pubkey,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    require!(
+        PoolFactory::treasury_allowed(&new_treasury),
+        FactoryError::InvalidTreasury
+    );
+
+    let factory = &mut ctx.accounts.factory;
+    let old_treasury = factory.protocol_treasury;
+    factory.protocol_treasury = new_treasury;
+
+    emit!(ProtocolTreasuryUpdatedEvent {
+        factory: factory.key(),
+        old_treasury,
+        new_treasury,
+        updated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::SettleIntervalFloorUpdatedEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Set the global floor for settlement cooldowns
+/// create_pool and update_defaults reject any min_settle_interval below it
+/// Only callable by upgrade authority (governance)
+pub fn update_settle_interval_floor(
+    ctx: Context<UpdateSettleIntervalFloor>,
+    global_min_settle_interval: i64,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    let factory = &mut ctx.accounts.factory;
+    require!(global_min_settle_interval > 0, FactoryError::InvalidSettleInterval);
+
+    // The current default must stay valid under the new floor
+    require!(
+        factory.min_settle_interval >= global_min_settle_interval,
+        FactoryError::InvalidSettleInterval
+    );
+
+    factory.global_min_settle_interval = global_min_settle_interval;
+
+    emit!(SettleIntervalFloorUpdatedEvent {
+        factory: factory.key(),
+        global_min_settle_interval,
+        updated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateSettleIntervalFloor<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::pool_factory::{
+    state::{PoolFactory, FACTORY_SEED},
+    events::SettlementAuthorityUpdatedEvent,
+    errors::FactoryError,
+};
+use crate::utils::require_upgrade_authority;
+use crate::program::VeritasCuration;
+
+/// Set the key allowed to run settle_epoch alongside the protocol authority
+/// Lets a settlement bot crank epochs without the protocol authority's trade co-signing power
+/// Pubkey::default() revokes the delegation
+/// Only callable by upgrade authority (governance)
+pub fn update_settlement_authority(
+    ctx: Context<UpdateSettlementAuthority>,
+    new_settlement_authority: Pubkey,
+) -> Result<()> {
+    // Validate upgrade authority
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        FactoryError::InvalidProgramData,
+        FactoryError::InvalidUpgradeAuthority,
+    )?;
+
+    require!(
+        new_settlement_authority != system_program::ID,
+        FactoryError::InvalidAuthority
+    );
+
+    let factory = &mut ctx.accounts.factory;
+    let old_authority = factory.settlement_authority;
+    factory.settlement_authority = new_settlement_authority;
+
+    emit!(SettlementAuthorityUpdatedEvent {
+        factory: factory.key(),
+        old_authority,
+        new_authority: new_settlement_authority,
+        updated_by: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateSettlementAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump
+    )]
+    pub factory: Account<'info, PoolFactory>,
+
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, VeritasCuration>,
+
+    /// CHECK: Program data account validated in handler
+    pub program_data: AccountInfo<'info>,
+}
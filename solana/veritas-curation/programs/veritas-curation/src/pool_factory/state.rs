@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::content_pool::state::{MIN_PRICE_MICRO, MAX_PRICE_MICRO};
 
 #[account]
 pub struct PoolFactory {
@@ -12,7 +13,7 @@ pub struct PoolFactory {
     pub total_fee_bps: u16,           // Total trading fee in basis points (2 bytes)
     pub creator_split_bps: u16,       // % of fees to creator in basis points (2 bytes)
     pub protocol_treasury: Pubkey,    // Protocol treasury address (32 bytes)
-    pub _padding_fee: [u8; 2],        // Alignment padding (2 bytes)
+    pub pol_bps: u16,                 // Share of buy after_skim retained as protocol-owned liquidity (2 bytes)
 
     // Default ICBS Parameters (14 bytes)
     pub default_f: u16,               // Default growth exponent (2 bytes)
@@ -29,14 +30,150 @@ pub struct PoolFactory {
 
     // PDA (1 byte)
     pub bump: u8,                     // PDA bump seed (1 byte)
+
+    // Governance (1 byte)
+    pub creation_paused: bool,        // Blocks create_pool/deploy_market; trading unaffected (1 byte)
+
+    // Settlement Floor (8 bytes)
+    pub global_min_settle_interval: i64, // No pool may use a shorter cooldown (8 bytes)
+
+    // Fee Policy (1 byte)
+    pub creator_fee_floor: bool,      // Give the creator 1 µUSDC when their share rounds to zero (1 byte)
+
+    // Settlement Delegation (32 bytes)
+    pub settlement_authority: Pubkey, // May settle_epoch besides protocol_authority; default = none (32 bytes)
+
+    // Layout Version (1 byte)
+    pub version: u8,                  // Account layout version; absent before v2 (1 byte)
+
+    // Early Exit (1 byte) - v3
+    pub early_exit_fee_enabled: bool, // Master switch for per-pool early-exit fees; default off (1 byte)
+
+    // Test Mode (1 byte) - v4
+    pub test_mode: bool,              // Cuts every pool's settle cooldown to 1s; NEVER on in production (1 byte)
+
+    // Sell Floor (8 bytes) - v5
+    pub min_sell_value_micro: u64,    // Sells worth less than this at the current price revert; 0 = off (8 bytes)
+
+    // Token Cap (8 bytes) - v6
+    pub max_tokens_per_trade: u64,    // Display tokens one trade may mint or burn; 0 = unlimited (8 bytes)
+
+    // Authority Transfer (32 bytes) - v7
+    pub pending_protocol_authority: Pubkey, // Proposed protocol_authority awaiting acceptance; default = none (32 bytes)
 }
 
 impl PoolFactory {
-    // protocol_authority(32) + total_pools(8) + total_fee_bps(2) + creator_split_bps(2) +
-    // protocol_treasury(32) + _padding_fee(2) + default_f(2) + default_beta_num(2) +
-    // default_beta_den(2) + default_p0(8) + min_initial_deposit(8) + min_settle_interval(8) +
-    // custodian(32) + bump(1)
-    pub const LEN: usize = 32 + 8 + 2 + 2 + 32 + 2 + 2 + 2 + 2 + 8 + 8 + 8 + 32 + 1; // 141 bytes
+    // protocol_authority(32) + total_pools(8) + total_fee_bps(2) +
+    // creator_split_bps(2) + protocol_treasury(32) + pol_bps(2) + default_f(2) +
+    // default_beta_num(2) + default_beta_den(2) + default_p0(8) + min_initial_deposit(8) +
+    // min_settle_interval(8) + custodian(32) + bump(1) + creation_paused(1) +
+    // global_min_settle_interval(8) + creator_fee_floor(1) + settlement_authority(32) + version(1) +
+    // early_exit_fee_enabled(1) + test_mode(1) + min_sell_value_micro(8) + max_tokens_per_trade(8) +
+    // pending_protocol_authority(32)
+    pub const LEN: usize = 32 + 8 + 2 + 2 + 32 + 2 + 2 + 2 + 2 + 8 + 8 + 8 + 32 + 1 + 1 + 8 + 1 + 32 + 1 + 1 + 1 + 8 + 8 + 32; // 234 bytes
+
+    /// Current layout version; migrate_account brings v1 factories up to it
+    /// v1 is the deployed 141-byte layout ending at `bump`; every later field is appended
+    /// after it. v2 added `version`, v3 added `early_exit_fee_enabled`, v4 added `test_mode`,
+    /// v5 added `min_sell_value_micro`, v6 added `max_tokens_per_trade`,
+    /// v7 added `pending_protocol_authority`
+    pub const VERSION: u8 = 7;
+
+    /// Whether a per-pool settlement cooldown is allowed under the factory floor
+    pub fn settle_interval_allowed(&self, interval: i64) -> bool {
+        interval > 0 && interval >= self.global_min_settle_interval
+    }
+
+    /// Settlement cooldown actually enforced for a pool
+    ///
+    /// Test mode overrides every pool's min_settle_interval with TEST_MODE_SETTLE_INTERVAL
+    /// so integration suites and devnet can settle back-to-back.
+    pub fn effective_settle_interval(&self, pool_interval: i64) -> i64 {
+        if self.test_mode {
+            TEST_MODE_SETTLE_INTERVAL
+        } else {
+            pool_interval
+        }
+    }
+
+    /// Whether a sell worth `value_micro` at the current price clears the dust floor
+    pub fn sell_value_allowed(&self, value_micro: u64) -> bool {
+        value_micro >= self.min_sell_value_micro
+    }
+
+    /// Whether a trade minting or burning `display_tokens` fits under the per-trade token cap
+    pub fn tokens_per_trade_allowed(&self, display_tokens: u64) -> bool {
+        self.max_tokens_per_trade == 0 || display_tokens <= self.max_tokens_per_trade
+    }
+
+    /// Whether `p0` is usable as the deploy price default
+    ///
+    /// deploy_market divides by p0 to size the initial supplies, so values outside the
+    /// curve's price bounds overflow S_DISPLAY_CAP or round supplies to nothing.
+    pub fn default_p0_allowed(p0: u64) -> bool {
+        (MIN_PRICE_MICRO..=MAX_PRICE_MICRO).contains(&p0)
+    }
+
+    /// Whether `total_fee_bps` is usable as a trading fee (factory-wide or per-pool override)
+    ///
+    /// calc_fees only breaks past 10000, but anything near that leaves a buyer with
+    /// nothing, so the cap sits well below.
+    pub fn total_fee_allowed(total_fee_bps: u16) -> bool {
+        total_fee_bps <= MAX_TOTAL_FEE_BPS
+    }
+
+    /// Whether `custodian` is this factory's custodian (pools take their stake vault from it)
+    pub fn uses_custodian(&self, custodian: &Pubkey) -> bool {
+        *custodian == self.custodian
+    }
+
+    /// Whether `treasury` may receive protocol fees (not the default key or system program)
+    pub fn treasury_allowed(treasury: &Pubkey) -> bool {
+        *treasury != Pubkey::default() && *treasury != anchor_lang::system_program::ID
+    }
+
+    /// Whether `key` may sign settle_epoch: the protocol authority, or the delegated
+    /// settlement authority when one is set
+    pub fn can_settle(&self, key: &Pubkey) -> bool {
+        *key == self.protocol_authority
+            || (self.settlement_authority != Pubkey::default() && *key == self.settlement_authority)
+    }
+
+    /// Whether a protocol authority transfer is waiting to be accepted
+    pub fn authority_transfer_pending(&self) -> bool {
+        self.pending_protocol_authority != Pubkey::default()
+    }
+
+    /// Complete a proposed transfer if `signer` is the proposed key
+    ///
+    /// Returns the replaced authority, or None (state untouched) when nothing is pending
+    /// or `signer` is not the proposed key.
+    pub fn accept_protocol_authority(&mut self, signer: &Pubkey) -> Option<Pubkey> {
+        if !self.authority_transfer_pending() || *signer != self.pending_protocol_authority {
+            return None;
+        }
+        let old_authority = self.protocol_authority;
+        self.protocol_authority = self.pending_protocol_authority;
+        self.pending_protocol_authority = Pubkey::default();
+        Some(old_authority)
+    }
+
+    /// Replace the protocol authority directly, returning the old one
+    ///
+    /// Also drops any pending proposal, so a key proposed before the direct update
+    /// cannot accept afterwards and take over.
+    pub fn set_protocol_authority(&mut self, new_authority: Pubkey) -> Pubkey {
+        self.pending_protocol_authority = Pubkey::default();
+        std::mem::replace(&mut self.protocol_authority, new_authority)
+    }
+
+    /// Drop a proposed transfer, returning the key that was proposed (None if nothing was)
+    pub fn cancel_protocol_authority_transfer(&mut self) -> Option<Pubkey> {
+        if !self.authority_transfer_pending() {
+            return None;
+        }
+        Some(std::mem::take(&mut self.pending_protocol_authority))
+    }
 }
 
 #[account]
@@ -70,9 +207,211 @@ pub const DEFAULT_P0: u64 = 1_000_000;  // 1.0 USDC per token (in micro-USDC, 6
 // Default Limits
 pub const DEFAULT_MIN_INITIAL_DEPOSIT: u64 = 50_000_000;  // 50 USDC
 pub const DEFAULT_MIN_SETTLE_INTERVAL: i64 = 7200;         // 2 hours (increased from 5 minutes)
+pub const DEFAULT_GLOBAL_MIN_SETTLE_INTERVAL: i64 = 300;   // 5 minutes - floor for any pool's cooldown
+pub const TEST_MODE_SETTLE_INTERVAL: i64 = 1;              // Cooldown for every pool while test_mode is on
+pub const DEFAULT_MIN_SELL_VALUE_MICRO: u64 = 10_000;      // 0.01 USDC - smallest sell worth a curve computation
+pub const MAX_MIN_SELL_VALUE_MICRO: u64 = 100_000;         // 0.1 USDC (MIN_TRADE_SIZE) - closing sells skip the floor entirely
+
+// Trading Fees
+pub const MAX_TOTAL_FEE_BPS: u16 = 1000; // 10% - policy cap so a misconfigured fee can't eat a trade
+
+// Protocol-Owned Liquidity
+pub const DEFAULT_POL_BPS: u16 = 0;     // Disabled by default
+pub const MAX_POL_BPS: u16 = 5000;      // At most half of after_skim bypasses the curve
+
+// Validation Bounds: the ICBS curve implements exactly F = 1, β = 1/2 (see curve.rs)
+pub const SUPPORTED_F: u16 = 1;
+pub const SUPPORTED_BETA: (u16, u16) = (1, 2);    // (num, den) in lowest terms
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_factory() -> PoolFactory {
+        PoolFactory {
+            protocol_authority: Pubkey::default(),
+            total_pools: 0,
+            total_fee_bps: 0,
+            creator_split_bps: 0,
+            protocol_treasury: Pubkey::default(),
+            pol_bps: 0,
+            default_f: DEFAULT_F,
+            default_beta_num: DEFAULT_BETA_NUM,
+            default_beta_den: DEFAULT_BETA_DEN,
+            default_p0: DEFAULT_P0,
+            min_initial_deposit: DEFAULT_MIN_INITIAL_DEPOSIT,
+            min_settle_interval: DEFAULT_MIN_SETTLE_INTERVAL,
+            custodian: Pubkey::default(),
+            bump: 0,
+            creation_paused: false,
+            global_min_settle_interval: DEFAULT_GLOBAL_MIN_SETTLE_INTERVAL,
+            creator_fee_floor: false,
+            settlement_authority: Pubkey::default(),
+            version: PoolFactory::VERSION,
+            early_exit_fee_enabled: false,
+            test_mode: false,
+            min_sell_value_micro: 0,
+            max_tokens_per_trade: 0,
+            pending_protocol_authority: Pubkey::default(),
+        }
+    }
 
-// Validation Bounds
-pub const MIN_F: u16 = 1;
-pub const MAX_F: u16 = 10;
-pub const MIN_BETA: f64 = 0.1;
-pub const MAX_BETA: f64 = 0.9;
\ No newline at end of file
+    #[test]
+    fn test_can_settle() {
+        let mut factory = empty_factory();
+        let protocol = Pubkey::new_unique();
+        let bot = Pubkey::new_unique();
+        factory.protocol_authority = protocol;
+
+        // No delegation: only the protocol authority, and never the default key
+        assert!(factory.can_settle(&protocol));
+        assert!(!factory.can_settle(&bot));
+        assert!(!factory.can_settle(&Pubkey::default()));
+
+        factory.settlement_authority = bot;
+        assert!(factory.can_settle(&protocol));
+        assert!(factory.can_settle(&bot));
+        assert!(!factory.can_settle(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_protocol_authority_propose_then_accept() {
+        let mut factory = empty_factory();
+        let old = Pubkey::new_unique();
+        let new = Pubkey::new_unique();
+        factory.protocol_authority = old;
+
+        // Nothing proposed: nobody can accept
+        assert!(!factory.authority_transfer_pending());
+        assert_eq!(factory.accept_protocol_authority(&new), None);
+        assert_eq!(factory.accept_protocol_authority(&Pubkey::default()), None);
+
+        // Proposed: only the proposed key can accept, and the old key keeps control until then
+        factory.pending_protocol_authority = new;
+        assert!(factory.authority_transfer_pending());
+        assert_eq!(factory.accept_protocol_authority(&old), None);
+        assert_eq!(factory.accept_protocol_authority(&Pubkey::new_unique()), None);
+        assert_eq!(factory.protocol_authority, old);
+
+        assert_eq!(factory.accept_protocol_authority(&new), Some(old));
+        assert_eq!(factory.protocol_authority, new);
+        assert!(!factory.authority_transfer_pending());
+
+        // Accepting twice is not possible
+        assert_eq!(factory.accept_protocol_authority(&new), None);
+    }
+
+    #[test]
+    fn test_protocol_authority_propose_then_cancel() {
+        let mut factory = empty_factory();
+        let old = Pubkey::new_unique();
+        let typo = Pubkey::new_unique();
+        factory.protocol_authority = old;
+
+        // A mistyped key is proposed, then cancelled before anyone accepts
+        factory.pending_protocol_authority = typo;
+        assert_eq!(factory.cancel_protocol_authority_transfer(), Some(typo));
+
+        assert!(!factory.authority_transfer_pending());
+        assert_eq!(factory.cancel_protocol_authority_transfer(), None);
+        assert_eq!(factory.accept_protocol_authority(&typo), None);
+        assert_eq!(factory.protocol_authority, old);
+    }
+
+    #[test]
+    fn test_direct_update_drops_pending_transfer() {
+        let mut factory = empty_factory();
+        let (old, stale, new) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        factory.protocol_authority = old;
+
+        // A proposal is outstanding when governance switches authority directly
+        factory.pending_protocol_authority = stale;
+        assert_eq!(factory.set_protocol_authority(new), old);
+        assert_eq!(factory.protocol_authority, new);
+        assert!(!factory.authority_transfer_pending());
+
+        // The stale key can no longer take over
+        assert_eq!(factory.accept_protocol_authority(&stale), None);
+        assert_eq!(factory.protocol_authority, new);
+    }
+
+    #[test]
+    fn test_default_p0_bounds() {
+        assert!(PoolFactory::default_p0_allowed(DEFAULT_P0));
+        assert!(PoolFactory::default_p0_allowed(MIN_PRICE_MICRO));
+        assert!(PoolFactory::default_p0_allowed(MAX_PRICE_MICRO));
+        assert!(!PoolFactory::default_p0_allowed(0));
+        assert!(!PoolFactory::default_p0_allowed(MAX_PRICE_MICRO + 1));
+    }
+
+    #[test]
+    fn test_total_fee_cap() {
+        assert!(PoolFactory::total_fee_allowed(0));
+        assert!(PoolFactory::total_fee_allowed(MAX_TOTAL_FEE_BPS));
+        assert!(!PoolFactory::total_fee_allowed(MAX_TOTAL_FEE_BPS + 1));
+        assert!(!PoolFactory::total_fee_allowed(10_001));
+    }
+
+    #[test]
+    fn test_uses_custodian() {
+        let mut factory = empty_factory();
+        let custodian = Pubkey::new_unique();
+        factory.custodian = custodian;
+
+        assert!(factory.uses_custodian(&custodian));
+        assert!(!factory.uses_custodian(&Pubkey::new_unique()));
+        assert!(!factory.uses_custodian(&Pubkey::default()));
+    }
+
+    #[test]
+    fn test_treasury_allowed() {
+        assert!(PoolFactory::treasury_allowed(&Pubkey::new_unique()));
+        assert!(!PoolFactory::treasury_allowed(&Pubkey::default()));
+        assert!(!PoolFactory::treasury_allowed(&anchor_lang::system_program::ID));
+    }
+
+    #[test]
+    fn test_settle_interval_floor() {
+        let mut factory = empty_factory();
+        factory.global_min_settle_interval = 3600;
+
+        assert!(!factory.settle_interval_allowed(0));
+        assert!(!factory.settle_interval_allowed(3599));
+        assert!(factory.settle_interval_allowed(3600));
+        assert!(factory.settle_interval_allowed(DEFAULT_MIN_SETTLE_INTERVAL));
+
+        // A zero floor still rejects non-positive intervals
+        factory.global_min_settle_interval = 0;
+        assert!(!factory.settle_interval_allowed(-1));
+        assert!(factory.settle_interval_allowed(1));
+    }
+
+    #[test]
+    fn test_test_mode_overrides_settle_interval() {
+        let mut factory = empty_factory();
+        assert_eq!(factory.effective_settle_interval(DEFAULT_MIN_SETTLE_INTERVAL), DEFAULT_MIN_SETTLE_INTERVAL);
+
+        factory.test_mode = true;
+        assert_eq!(factory.effective_settle_interval(DEFAULT_MIN_SETTLE_INTERVAL), TEST_MODE_SETTLE_INTERVAL);
+        assert_eq!(factory.effective_settle_interval(86_400), TEST_MODE_SETTLE_INTERVAL);
+    }
+
+    #[test]
+    fn test_sell_value_floor() {
+        let mut factory = empty_factory();
+        assert!(factory.sell_value_allowed(0));
+
+        factory.min_sell_value_micro = DEFAULT_MIN_SELL_VALUE_MICRO;
+        assert!(!factory.sell_value_allowed(DEFAULT_MIN_SELL_VALUE_MICRO - 1));
+        assert!(factory.sell_value_allowed(DEFAULT_MIN_SELL_VALUE_MICRO));
+    }
+
+    #[test]
+    fn test_tokens_per_trade_cap() {
+        let mut factory = empty_factory();
+        assert!(factory.tokens_per_trade_allowed(u64::MAX));
+
+        factory.max_tokens_per_trade = 5_000;
+        assert!(factory.tokens_per_trade_allowed(5_000));
+        assert!(!factory.tokens_per_trade_allowed(5_001));
+    }
+}
@@ -1,32 +1,44 @@
 use anchor_lang::prelude::*;
 use crate::errors::ErrorCode;
+use crate::content_pool::math::isqrt_u128;
 
-/// Integer square root using Newton's method
-pub fn integer_sqrt(n: u128) -> Result<u128> {
-    if n == 0 {
-        return Ok(0);
+/// Require `signer` to be the upgrade authority recorded in `program_data`
+///
+/// Governance instructions pass their module's errors: `invalid_data` when the account
+/// is too short to be ProgramData, `invalid_authority` when the signer doesn't match
+/// (including when the program is immutable).
+pub fn require_upgrade_authority(
+    program_data: &AccountInfo,
+    signer: &Pubkey,
+    invalid_data: impl Into<Error>,
+    invalid_authority: impl Into<Error>,
+) -> Result<()> {
+    let upgrade_authority = upgrade_authority(&program_data.try_borrow_data()?)
+        .ok_or_else(|| invalid_data.into())?;
+    if upgrade_authority != Some(*signer) {
+        return Err(invalid_authority.into());
     }
+    Ok(())
+}
 
-    // Newton's method for integer square root
-    // Critical: Use checked operations to prevent overflow
-    let mut x = n;
-    let mut y = x.checked_add(1)
-        .ok_or(ErrorCode::NumericalOverflow)?
-        .checked_div(2)
-        .ok_or(ErrorCode::NumericalOverflow)?;
-
-    while y < x {
-        x = y;
-        // y = (x + n/x) / 2
-        let n_div_x = n.checked_div(x)
-            .ok_or(ErrorCode::NumericalOverflow)?;
-        y = x.checked_add(n_div_x)
-            .ok_or(ErrorCode::NumericalOverflow)?
-            .checked_div(2)
-            .ok_or(ErrorCode::NumericalOverflow)?;
+/// Upgrade authority in raw ProgramData, `None` if the data is too short
+///
+/// Layout: first 4 bytes = discriminator, next 8 = slot, next 1 = Option tag, next 32 = Pubkey
+fn upgrade_authority(program_data: &[u8]) -> Option<Option<Pubkey>> {
+    if program_data.len() < 45 {
+        return None;
+    }
+    if program_data[12] == 0 {
+        return Some(None);
     }
+    let mut pubkey_bytes = [0u8; 32];
+    pubkey_bytes.copy_from_slice(&program_data[13..45]);
+    Some(Some(Pubkey::new_from_array(pubkey_bytes)))
+}
 
-    Ok(x)
+/// Integer square root (floor), bounded to MAX_SQRT_ITERATIONS Newton steps
+pub fn integer_sqrt(n: u128) -> Result<u128> {
+    isqrt_u128(n)
 }
 
 /// Integer cube root using binary search
@@ -96,3 +108,25 @@ pub fn integer_cbrt(n: u128) -> Result<u128> {
 
     Ok(hi) // Return floor(cbrt(n))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_authority_layout() {
+        let authority = Pubkey::new_unique();
+        let mut data = vec![0u8; 45];
+        data[0] = 3; // UpgradeableLoaderState::ProgramData
+        data[12] = 1;
+        data[13..45].copy_from_slice(authority.as_ref());
+        assert_eq!(upgrade_authority(&data), Some(Some(authority)));
+
+        // Immutable program: no authority can pass
+        data[12] = 0;
+        assert_eq!(upgrade_authority(&data), Some(None));
+
+        // Too short to be ProgramData
+        assert_eq!(upgrade_authority(&data[..44]), None);
+    }
+}
@@ -1,11 +1,13 @@
 pub mod initialize_custodian;
 pub mod deposit;
 pub mod withdraw;
+pub mod withdraw_authorized;
 pub mod update_protocol_authority;
 pub mod toggle_emergency_pause;
 
 pub use initialize_custodian::*;
 pub use deposit::*;
 pub use withdraw::*;
+pub use withdraw_authorized::*;
 pub use update_protocol_authority::*;  // Now exports UpdateCustodianProtocolAuthority struct
 pub use toggle_emergency_pause::*;
\ No newline at end of file
@@ -3,6 +3,7 @@ use anchor_lang::system_program;
 
 use crate::veritas_custodian::state::{VeritasCustodian, CUSTODIAN_SEED};
 use crate::errors::ErrorCode;
+use crate::utils::require_upgrade_authority;
 use crate::program::VeritasCuration;
 
 /// Upgrade authority updates the protocol authority that can execute withdrawals
@@ -12,24 +13,12 @@ pub fn update_protocol_authority(
     new_protocol_authority: Pubkey,
 ) -> Result<()> {
     // Validate upgrade authority
-    let program_data_bytes = ctx.accounts.program_data.try_borrow_data()?;
-    if program_data_bytes.len() < 45 {
-        return Err(ErrorCode::InvalidProgramData.into());
-    }
-
-    // Deserialize: first 4 bytes = discriminator, next 8 = slot, next 1 = Option tag, next 32 = Pubkey
-    let upgrade_authority_option = if program_data_bytes[12] == 0 {
-        None
-    } else {
-        let mut pubkey_bytes = [0u8; 32];
-        pubkey_bytes.copy_from_slice(&program_data_bytes[13..45]);
-        Some(Pubkey::new_from_array(pubkey_bytes))
-    };
-
-    require!(
-        upgrade_authority_option == Some(ctx.accounts.upgrade_authority.key()),
-        ErrorCode::InvalidUpgradeAuthority
-    );
+    require_upgrade_authority(
+        &ctx.accounts.program_data,
+        ctx.accounts.upgrade_authority.key,
+        ErrorCode::InvalidProgramData,
+        ErrorCode::InvalidUpgradeAuthority,
+    )?;
 
     let custodian = &mut ctx.accounts.custodian;
 
@@ -13,14 +13,34 @@ pub fn withdraw(
     amount: u64,
     recipient: Pubkey,
 ) -> Result<()> {
-    let custodian = &mut ctx.accounts.custodian;
+    let authority = ctx.accounts.authority.key();
+    execute_withdrawal(
+        &mut ctx.accounts.custodian,
+        &ctx.accounts.custodian_usdc_vault,
+        &ctx.accounts.recipient_usdc_account,
+        &ctx.accounts.token_program,
+        authority,
+        amount,
+        recipient,
+    )
+}
 
+/// Checks, accounting, transfer and event shared by `withdraw` and `withdraw_authorized`
+pub(crate) fn execute_withdrawal<'info>(
+    custodian: &mut Account<'info, VeritasCustodian>,
+    custodian_usdc_vault: &Account<'info, TokenAccount>,
+    recipient_usdc_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    authority: Pubkey,
+    amount: u64,
+    recipient: Pubkey,
+) -> Result<()> {
     // Emergency pause check
     require!(!custodian.emergency_pause, ErrorCode::SystemPaused);
 
     // Only protocol authority can withdraw
     require!(
-        ctx.accounts.authority.key() == custodian.protocol_authority,
+        authority == custodian.protocol_authority,
         ErrorCode::Unauthorized
     );
 
@@ -28,7 +48,7 @@ pub fn withdraw(
 
     // Verify vault has sufficient USDC
     require!(
-        amount <= ctx.accounts.custodian_usdc_vault.amount,
+        amount <= custodian_usdc_vault.amount,
         ErrorCode::InsufficientVaultBalance
     );
 
@@ -45,10 +65,10 @@ pub fn withdraw(
     let signer = &[&seeds[..]];
 
     let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
+        token_program.to_account_info(),
         Transfer {
-            from: ctx.accounts.custodian_usdc_vault.to_account_info(),
-            to: ctx.accounts.recipient_usdc_account.to_account_info(),
+            from: custodian_usdc_vault.to_account_info(),
+            to: recipient_usdc_account.to_account_info(),
             authority: custodian.to_account_info(),
         },
         signer,
@@ -59,7 +79,7 @@ pub fn withdraw(
     emit!(WithdrawEvent {
         recipient,
         amount,
-        authority: ctx.accounts.authority.key(),
+        authority,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
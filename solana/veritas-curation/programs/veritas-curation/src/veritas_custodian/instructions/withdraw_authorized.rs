@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::veritas_custodian::state::{
+    VeritasCustodian, WithdrawNonce,
+    CUSTODIAN_SEED, WITHDRAW_NONCE_SEED, WITHDRAW_AUTH_DOMAIN
+};
+use crate::veritas_custodian::instructions::withdraw::execute_withdrawal;
+use crate::errors::ErrorCode;
+
+// Ed25519 program instruction layout: [num_signatures u8, padding u8, offsets...]
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Protocol authority withdraws USDC with a recipient-signed authorization
+///
+/// The transaction must include an Ed25519 program instruction immediately before
+/// this one, verifying the recipient's signature over `withdraw_auth_message`.
+/// Nonces must strictly increase per recipient, so each authorization is single-use.
+pub fn withdraw_authorized(
+    ctx: Context<WithdrawAuthorized>,
+    amount: u64,
+    recipient: Pubkey,
+    nonce: u64,
+) -> Result<()> {
+    // Verify the recipient signed (custodian, amount, recipient, nonce)
+    let current_index = load_current_index_checked(&ctx.accounts.instructions_sysvar)? as usize;
+    require!(current_index > 0, ErrorCode::InvalidSignature);
+    let ed25519_ix = load_instruction_at_checked(current_index - 1, &ctx.accounts.instructions_sysvar)?;
+    require!(
+        ed25519_ix.program_id == ed25519_program::ID,
+        ErrorCode::InvalidSignature
+    );
+
+    let message = withdraw_auth_message(&ctx.accounts.custodian.key(), amount, &recipient, nonce);
+    verify_ed25519_ix_data(&ed25519_ix.data, &recipient, &message)?;
+
+    // Replay protection
+    let withdraw_nonce = &mut ctx.accounts.withdraw_nonce;
+    require!(nonce > withdraw_nonce.last_nonce, ErrorCode::NonceAlreadyUsed);
+    withdraw_nonce.recipient = recipient;
+    withdraw_nonce.last_nonce = nonce;
+    withdraw_nonce.bump = ctx.bumps.withdraw_nonce;
+
+    let authority = ctx.accounts.authority.key();
+    execute_withdrawal(
+        &mut ctx.accounts.custodian,
+        &ctx.accounts.custodian_usdc_vault,
+        &ctx.accounts.recipient_usdc_account,
+        &ctx.accounts.token_program,
+        authority,
+        amount,
+        recipient,
+    )?;
+
+    msg!("Authorized withdrawal: recipient={}, nonce={}", recipient, nonce);
+    Ok(())
+}
+
+/// Message the recipient signs off-chain
+/// domain || custodian (32) || amount (u64 LE) || recipient (32) || nonce (u64 LE)
+pub fn withdraw_auth_message(
+    custodian: &Pubkey,
+    amount: u64,
+    recipient: &Pubkey,
+    nonce: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(WITHDRAW_AUTH_DOMAIN.len() + 32 + 8 + 32 + 8);
+    message.extend_from_slice(WITHDRAW_AUTH_DOMAIN);
+    message.extend_from_slice(custodian.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(recipient.as_ref());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+/// Check an Ed25519 program instruction verified exactly one signature by
+/// `expected_signer` over `expected_message`, with all data inline
+///
+/// The Ed25519 program itself fails the transaction on a bad signature; this only
+/// confirms it was asked to verify the right key and message.
+fn verify_ed25519_ix_data(
+    data: &[u8],
+    expected_signer: &Pubkey,
+    expected_message: &[u8],
+) -> Result<()> {
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidSignature
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = ED25519_HEADER_LEN;
+    let signature_offset = read_u16(offsets) as usize;
+    let signature_ix_index = read_u16(offsets + 2);
+    let pubkey_offset = read_u16(offsets + 4) as usize;
+    let pubkey_ix_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+    let message_ix_index = read_u16(offsets + 12);
+
+    // u16::MAX = data lives in the Ed25519 instruction itself
+    require!(
+        signature_ix_index == u16::MAX
+            && pubkey_ix_index == u16::MAX
+            && message_ix_index == u16::MAX,
+        ErrorCode::InvalidSignature
+    );
+    require!(
+        signature_offset + ED25519_SIGNATURE_LEN <= data.len(),
+        ErrorCode::InvalidSignature
+    );
+
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + ED25519_PUBKEY_LEN)
+        .ok_or(ErrorCode::InvalidSignature)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidSignature)?;
+
+    require!(pubkey == expected_signer.as_ref(), ErrorCode::InvalidSignature);
+    require!(message == expected_message, ErrorCode::InvalidSignature);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, recipient: Pubkey)]
+pub struct WithdrawAuthorized<'info> {
+    #[account(
+        mut,
+        seeds = [CUSTODIAN_SEED],
+        bump = custodian.bump
+    )]
+    pub custodian: Account<'info, VeritasCustodian>,
+
+    #[account(
+        mut,
+        constraint = custodian_usdc_vault.key() == custodian.usdc_vault @ ErrorCode::InvalidVault
+    )]
+    pub custodian_usdc_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = recipient_usdc_account.owner == recipient @ ErrorCode::InvalidRecipient,
+        constraint = recipient_usdc_account.mint == custodian_usdc_vault.mint @ ErrorCode::InvalidMint
+    )]
+    pub recipient_usdc_account: Account<'info, TokenAccount>,
+
+    /// Highest nonce consumed for this recipient
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + WithdrawNonce::LEN,
+        seeds = [WITHDRAW_NONCE_SEED, recipient.as_ref()],
+        bump
+    )]
+    pub withdraw_nonce: Account<'info, WithdrawNonce>,
+
+    /// CHECK: Instructions sysvar, used to read the preceding Ed25519 instruction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build Ed25519 program instruction data with signature, pubkey and message inline
+    fn ed25519_ix_data(signer: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let pubkey_offset = ED25519_HEADER_LEN + ED25519_OFFSETS_LEN;
+        let signature_offset = pubkey_offset + ED25519_PUBKEY_LEN;
+        let message_offset = signature_offset + ED25519_SIGNATURE_LEN;
+
+        let mut data = vec![1u8, 0u8];
+        for value in [
+            signature_offset as u16, u16::MAX,
+            pubkey_offset as u16, u16::MAX,
+            message_offset as u16, message.len() as u16, u16::MAX,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[7u8; ED25519_SIGNATURE_LEN]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_accepts_matching_signer_and_message() {
+        let custodian = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let message = withdraw_auth_message(&custodian, 5_000_000, &recipient, 1);
+        let data = ed25519_ix_data(&recipient, &message);
+        assert!(verify_ed25519_ix_data(&data, &recipient, &message).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_wrong_signer() {
+        let custodian = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let message = withdraw_auth_message(&custodian, 5_000_000, &recipient, 1);
+        let data = ed25519_ix_data(&Pubkey::new_unique(), &message);
+        assert!(verify_ed25519_ix_data(&data, &recipient, &message).is_err());
+    }
+
+    #[test]
+    fn test_rejects_altered_amount_or_nonce() {
+        let custodian = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let signed = withdraw_auth_message(&custodian, 5_000_000, &recipient, 1);
+        let data = ed25519_ix_data(&recipient, &signed);
+
+        let bigger = withdraw_auth_message(&custodian, 50_000_000, &recipient, 1);
+        assert!(verify_ed25519_ix_data(&data, &recipient, &bigger).is_err());
+
+        let replayed = withdraw_auth_message(&custodian, 5_000_000, &recipient, 2);
+        assert!(verify_ed25519_ix_data(&data, &recipient, &replayed).is_err());
+    }
+
+    #[test]
+    fn test_rejects_data_in_other_instruction() {
+        let custodian = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let message = withdraw_auth_message(&custodian, 5_000_000, &recipient, 1);
+        let mut data = ed25519_ix_data(&recipient, &message);
+        // Point the message at instruction 0 instead of inline data
+        data[14..16].copy_from_slice(&0u16.to_le_bytes());
+        assert!(verify_ed25519_ix_data(&data, &recipient, &message).is_err());
+    }
+
+    #[test]
+    fn test_rejects_truncated_data() {
+        let recipient = Pubkey::new_unique();
+        assert!(verify_ed25519_ix_data(&[1, 0, 0], &recipient, b"msg").is_err());
+    }
+}
@@ -7,6 +7,7 @@ pub use instructions::{
     InitializeCustodian,
     Deposit,
     Withdraw,
+    WithdrawAuthorized,
     UpdateCustodianProtocolAuthority,
     ToggleEmergencyPause,
 };
\ No newline at end of file
@@ -16,6 +16,19 @@ impl VeritasCustodian {
 }
 // Total: 98 bytes + 8 discriminator = 106 bytes
 
+/// Per-recipient replay protection for signature-gated withdrawals
+#[account]
+pub struct WithdrawNonce {
+    pub recipient: Pubkey,          // User whose signature authorizes withdrawals (32 bytes)
+    pub last_nonce: u64,            // Highest nonce consumed so far (8 bytes)
+    pub bump: u8,                   // PDA bump seed (1 byte)
+}
+
+impl WithdrawNonce {
+    // recipient(32) + last_nonce(8) + bump(1)
+    pub const LEN: usize = 32 + 8 + 1; // 41 bytes
+}
+
 // Events for off-chain indexing
 #[event]
 pub struct DepositEvent {
@@ -34,6 +47,10 @@ pub struct WithdrawEvent {
 
 // Seeds
 pub const CUSTODIAN_SEED: &[u8] = b"custodian";
+pub const WITHDRAW_NONCE_SEED: &[u8] = b"withdraw_nonce";
+
+// Domain prefix for user-signed withdrawal authorizations
+pub const WITHDRAW_AUTH_DOMAIN: &[u8] = b"veritas:withdraw";
 
 // Minimums (in USDC with 6 decimals)
 pub const MIN_DEPOSIT: u64 = 1_000_000;     // 1 USDC
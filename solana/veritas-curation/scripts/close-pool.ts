@@ -113,6 +113,8 @@ async function main() {
         pool: poolPda,
         factory: factoryPda,
         vault: pool.vault,
+        longMint: pool.longMint,
+        shortMint: pool.shortMint,
         receiverUsdc: authorityUsdcAccount,
         receiver: authority.publicKey,
         signer: authority.publicKey,
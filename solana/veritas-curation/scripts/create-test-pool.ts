@@ -161,7 +161,7 @@ async function main() {
     );
 
     const deployTx = await program.methods
-      .deployMarket(initialDeposit, longAllocation)
+      .deployMarket({ initialDeposit, longAllocation })
       .accounts({
         pool: poolPda,
         longMint: longMint,
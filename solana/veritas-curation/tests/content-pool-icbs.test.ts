@@ -280,6 +280,75 @@ describe("ContentPool ICBS Tests", () => {
         assert.equal(pool.sShort.toString(), "0");
         assert.equal(pool.rLong.toString(), "0");
         assert.equal(pool.rShort.toString(), "0");
+        assert.isAbove(pool.createdAt.toNumber(), 0);
+        assert.equal(pool.deployedAt.toString(), "0");
+      });
+
+      it("rejects add_liquidity on a created-but-undeployed pool", async () => {
+        const contentId = Keypair.generate().publicKey;
+        const [poolPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("content_pool"), contentId.toBuffer()],
+          program.programId
+        );
+        const [registryPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("registry"), contentId.toBuffer()],
+          program.programId
+        );
+        const [longMint] = PublicKey.findProgramAddressSync(
+          [Buffer.from("long_mint"), contentId.toBuffer()],
+          program.programId
+        );
+        const [shortMint] = PublicKey.findProgramAddressSync(
+          [Buffer.from("short_mint"), contentId.toBuffer()],
+          program.programId
+        );
+        const [vault] = PublicKey.findProgramAddressSync(
+          [Buffer.from("vault"), contentId.toBuffer()],
+          program.programId
+        );
+
+        await program.methods
+          .createPool(contentId)
+          .accounts({
+            factory: factoryPda,
+            pool: poolPda,
+            registry: registryPda,
+            custodian: custodianPda,
+            creator: testUser1.publicKey,
+            postCreator: testUser1.publicKey,
+            payer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([testUser1])
+          .rpc();
+
+        try {
+          await program.methods
+            .addLiquidity(new BN(10_000_000))
+            .accounts({
+              pool: poolPda,
+              factory: factoryPda,
+              longTokenMint: longMint,
+              shortTokenMint: shortMint,
+              poolReserve: vault,
+              userUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+              userLongAccount: getAssociatedTokenAddressSync(longMint, testUser1.publicKey),
+              userShortAccount: getAssociatedTokenAddressSync(shortMint, testUser1.publicKey),
+              user: testUser1.publicKey,
+              tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([testUser1])
+            .rpc();
+          assert.fail("add_liquidity should fail before deploy_market");
+        } catch (e: any) {
+          // Mints and vault only exist after deploy_market, so Anchor's account
+          // checks may reject first; the handler guard returns MarketNotDeployed
+          assert.ok(
+            e.toString().includes("MarketNotDeployed") ||
+              e.toString().includes("AccountNotInitialized"),
+            e.toString()
+          );
+        }
       });
     });
 
@@ -444,7 +513,7 @@ describe("ContentPool ICBS Tests", () => {
         );
 
         await program.methods
-          .deployMarket(initialDeposit, longAllocation)
+          .deployMarket({ initialDeposit, longAllocation })
           .accounts({
             pool: deploymentPoolPda,
             factory: factoryPda,
@@ -476,6 +545,7 @@ describe("ContentPool ICBS Tests", () => {
         assert.equal(pool.longMint.toBase58(), deploymentLongMint.toBase58());
         assert.equal(pool.shortMint.toBase58(), deploymentShortMint.toBase58());
         assert.equal(pool.vault.toBase58(), deploymentVault.toBase58());
+        assert.isAtLeast(pool.deployedAt.toNumber(), pool.createdAt.toNumber());
 
         // Verify initial_q ≈ 0.6 (60% LONG)
         const initialQ = pool.initialQ.toNumber() / Q32_ONE.toNumber();
@@ -627,7 +697,7 @@ describe("ContentPool ICBS Tests", () => {
 
         try {
           await program.methods
-            .deployMarket(new BN(50_000_000), new BN(25_000_000))
+            .deployMarket({ initialDeposit: new BN(50_000_000), longAllocation: new BN(25_000_000) })
             .accounts({
               pool: poolPda2,
               longMint: longMint2,
@@ -678,7 +748,7 @@ describe("ContentPool ICBS Tests", () => {
 
         try {
           await program.methods
-            .deployMarket(new BN(100_000_000), new BN(50_000_000))
+            .deployMarket({ initialDeposit: new BN(100_000_000), longAllocation: new BN(50_000_000) })
             .accounts({
               pool: poolPda,
             factory: factoryPda,              longMint: longMint,
@@ -704,6 +774,264 @@ describe("ContentPool ICBS Tests", () => {
           assert.ok(e.toString().includes("6003") || e.toString().includes("MarketAlreadyDeployed") || e.message);
         }
       });
+
+      it("mints the initial tokens to a token_recipient other than the deployer", async () => {
+        const recipientContentId = Keypair.generate().publicKey;
+        const seed = (prefix: string) => PublicKey.findProgramAddressSync(
+          [Buffer.from(prefix), recipientContentId.toBuffer()],
+          program.programId
+        )[0];
+        const recipientPool = seed("content_pool");
+        const recipientLongMint = seed("long_mint");
+        const recipientShortMint = seed("short_mint");
+        const recipientVault = seed("vault");
+        const recipient = Keypair.generate().publicKey;
+
+        await program.methods
+          .createPool(recipientContentId)
+          .accounts({
+            factory: factoryPda,
+            pool: recipientPool,
+            registry: seed("registry"),
+            custodian: custodianPda,
+            creator: testUser1.publicKey,
+            postCreator: testUser1.publicKey,
+            payer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([testUser1])
+          .rpc();
+
+        const recipientLong = getAssociatedTokenAddressSync(recipientLongMint, recipient);
+        const recipientShort = getAssociatedTokenAddressSync(recipientShortMint, recipient);
+
+        await program.methods
+          .deployMarket({ initialDeposit: new BN(100_000_000), longAllocation: new BN(50_000_000) })
+          .accounts({
+            pool: recipientPool,
+            factory: factoryPda,
+            longMint: recipientLongMint,
+            shortMint: recipientShortMint,
+            vault: recipientVault,
+            deployerUsdc: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+            deployerLong: recipientLong,
+            deployerShort: recipientShort,
+            usdcMint: usdcMint,
+            deployer: testUser1.publicKey,
+            payer: payer.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            tokenRecipient: recipient,
+          })
+          .signers([testUser1])
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_200_000 })
+          ])
+          .rpc();
+
+        const pool = await program.account.contentPool.fetch(recipientPool);
+        assert.equal(pool.marketDeployer.toString(), testUser1.publicKey.toString());
+
+        const longAccount = await getAccount(provider.connection, recipientLong);
+        const shortAccount = await getAccount(provider.connection, recipientShort);
+        assert.equal(longAccount.owner.toString(), recipient.toString());
+        assert.equal(longAccount.amount, BigInt(pool.sLong.toString()) * 1_000_000n);
+        assert.equal(shortAccount.amount, BigInt(pool.sShort.toString()) * 1_000_000n);
+      });
+
+      it("deploys with only initialDeposit set (even split by default)", async () => {
+        const defaultsContentId = Keypair.generate().publicKey;
+        const seed = (prefix: string) => PublicKey.findProgramAddressSync(
+          [Buffer.from(prefix), defaultsContentId.toBuffer()],
+          program.programId
+        )[0];
+        const defaultsPool = seed("content_pool");
+        const defaultsLongMint = seed("long_mint");
+        const defaultsShortMint = seed("short_mint");
+
+        await program.methods
+          .createPool(defaultsContentId)
+          .accounts({
+            factory: factoryPda,
+            pool: defaultsPool,
+            registry: seed("registry"),
+            custodian: custodianPda,
+            creator: testUser1.publicKey,
+            postCreator: testUser1.publicKey,
+            payer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([testUser1])
+          .rpc();
+
+        await program.methods
+          .deployMarket({ initialDeposit: new BN(100_000_000), longAllocation: null })
+          .accounts({
+            pool: defaultsPool,
+            factory: factoryPda,
+            longMint: defaultsLongMint,
+            shortMint: defaultsShortMint,
+            vault: seed("vault"),
+            deployerUsdc: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+            deployerLong: getAssociatedTokenAddressSync(defaultsLongMint, testUser1.publicKey),
+            deployerShort: getAssociatedTokenAddressSync(defaultsShortMint, testUser1.publicKey),
+            usdcMint: usdcMint,
+            deployer: testUser1.publicKey,
+            payer: payer.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            tokenRecipient: null,
+          })
+          .signers([testUser1])
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_200_000 })
+          ])
+          .rpc();
+
+        const pool = await program.account.contentPool.fetch(defaultsPool);
+        assert.equal(pool.marketDeployer.toString(), testUser1.publicKey.toString());
+        assert.equal(pool.sLong.toString(), pool.sShort.toString());
+        assert.equal(pool.vaultBalance.toNumber(), 100_000_000);
+      });
+    });
+
+    describe("2.2 Batch Deployment", () => {
+      it("deploys two markets in one deploy_market_batch transaction", async () => {
+        const pda = (seed: string, id: PublicKey) =>
+          PublicKey.findProgramAddressSync([Buffer.from(seed), id.toBuffer()], program.programId)[0];
+
+        const contentIds = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+        const remainingAccounts = [];
+
+        for (const contentId of contentIds) {
+          await program.methods
+            .createPool(contentId)
+            .accounts({
+              factory: factoryPda,
+              pool: pda("content_pool", contentId),
+              registry: pda("registry", contentId),
+              custodian: custodianPda,
+              creator: testUser2.publicKey,
+              postCreator: testUser2.publicKey,
+              payer: payer.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([testUser2])
+            .rpc();
+
+          const longMint = pda("long_mint", contentId);
+          const shortMint = pda("short_mint", contentId);
+          for (const pubkey of [
+            pda("content_pool", contentId),
+            longMint,
+            shortMint,
+            pda("vault", contentId),
+            getAssociatedTokenAddressSync(longMint, testUser2.publicKey),
+            getAssociatedTokenAddressSync(shortMint, testUser2.publicKey),
+          ]) {
+            remainingAccounts.push({ pubkey, isSigner: false, isWritable: true });
+          }
+        }
+
+        await program.methods
+          .deployMarketBatch([
+            { initialDeposit: new BN(100_000_000), longAllocation: null },
+            { initialDeposit: new BN(100_000_000), longAllocation: new BN(70_000_000) },
+          ])
+          .accounts({
+            factory: factoryPda,
+            deployerUsdc: getAssociatedTokenAddressSync(usdcMint, testUser2.publicKey),
+            usdcMint: usdcMint,
+            deployer: testUser2.publicKey,
+            payer: payer.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(remainingAccounts)
+          .signers([testUser2])
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 })
+          ])
+          .rpc();
+
+        for (const contentId of contentIds) {
+          const pool = await program.account.contentPool.fetch(pda("content_pool", contentId));
+          assert.equal(pool.marketDeployer.toBase58(), testUser2.publicKey.toBase58());
+          assert.equal(pool.vault.toBase58(), pda("vault", contentId).toBase58());
+          assert.ok(pool.sLong.gtn(0) && pool.sShort.gtn(0));
+        }
+
+        // No long_allocation: even split, as deploy_market defaults
+        const evenPool = await program.account.contentPool.fetch(pda("content_pool", contentIds[0]));
+        assert.equal(evenPool.sLong.toString(), evenPool.sShort.toString());
+      });
+
+      it("deploys when a mint PDA was pre-funded with lamports", async () => {
+        const pda = (seed: string, id: PublicKey) =>
+          PublicKey.findProgramAddressSync([Buffer.from(seed), id.toBuffer()], program.programId)[0];
+
+        const contentId = Keypair.generate().publicKey;
+        await program.methods
+          .createPool(contentId)
+          .accounts({
+            factory: factoryPda,
+            pool: pda("content_pool", contentId),
+            registry: pda("registry", contentId),
+            custodian: custodianPda,
+            creator: testUser2.publicKey,
+            postCreator: testUser2.publicKey,
+            payer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([testUser2])
+          .rpc();
+
+        // Anyone can send lamports to the not-yet-created mint address
+        const longMint = pda("long_mint", contentId);
+        const shortMint = pda("short_mint", contentId);
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: longMint, lamports: 1_000_000 })
+          )
+        );
+
+        await program.methods
+          .deployMarketBatch([
+            { initialDeposit: new BN(100_000_000), longAllocation: null },
+          ])
+          .accounts({
+            factory: factoryPda,
+            deployerUsdc: getAssociatedTokenAddressSync(usdcMint, testUser2.publicKey),
+            usdcMint: usdcMint,
+            deployer: testUser2.publicKey,
+            payer: payer.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            [
+              pda("content_pool", contentId),
+              longMint,
+              shortMint,
+              pda("vault", contentId),
+              getAssociatedTokenAddressSync(longMint, testUser2.publicKey),
+              getAssociatedTokenAddressSync(shortMint, testUser2.publicKey),
+            ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+          )
+          .signers([testUser2])
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 })
+          ])
+          .rpc();
+
+        const pool = await program.account.contentPool.fetch(pda("content_pool", contentId));
+        assert.equal(pool.longMint.toBase58(), longMint.toBase58());
+        assert.ok(pool.sLong.gtn(0));
+      });
     });
   });
 
@@ -1270,7 +1598,7 @@ describe("ContentPool ICBS Tests", () => {
       );
 
       await program.methods
-        .deployMarket(new BN(200_000_000), new BN(100_000_000)) // 200 USDC, 50/50 split
+        .deployMarket({ initialDeposit: new BN(200_000_000), longAllocation: new BN(100_000_000) }) // 200 USDC, 50/50 split
         .accounts({
           pool: tradingPoolPda,
             factory: factoryPda,          longMint: tradingLongMint,
@@ -1359,6 +1687,9 @@ describe("ContentPool ICBS Tests", () => {
         assert.ok(poolAfter.sLong.gt(sLongBefore));
         assert.ok(poolAfter.rLong.gt(rLongBefore));
 
+        // One trade, one sequence number
+        assert.equal(poolAfter.tradeSeq.toNumber(), poolBefore.tradeSeq.toNumber() + 1);
+
         // Verify user received tokens
         assert.ok(longAccountAfter.amount > 0);
 
@@ -1428,6 +1759,64 @@ describe("ContentPool ICBS Tests", () => {
         assert.equal(vaultIncrease.toString(), "90000000");
       });
 
+      it("rejects a token account as skim beneficiary", async () => {
+        const traderUsdcAccount = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          payer.payer,
+          usdcMint,
+          testUser2.publicKey
+        );
+
+        const traderShortAccount = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          payer.payer,
+          tradingShortMint,
+          testUser2.publicKey
+        );
+
+        try {
+          await program.methods
+            .trade(
+              { short: {} },
+              { buy: {} },
+              new BN(10_000_000),
+              new BN(1_000_000),
+              new BN(0),
+              new BN(0)
+            )
+            .accounts({
+              pool: tradingPoolPda,
+              factory: factoryPda,
+              traderUsdc: traderUsdcAccount.address,
+              vault: tradingVault,
+              stakeVault: stakeVault,
+              traderTokens: traderShortAccount.address,
+              tokenMint: tradingShortMint,
+              usdcMint: usdcMint,
+              trader: testUser2.publicKey,
+              protocolAuthority: TEST_POOL_AUTHORITY.publicKey,
+              payer: payer.publicKey,
+              postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+              protocolTreasuryUsdcAccount: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+              skimBeneficiary: traderUsdcAccount.address, // token-program owned
+            })
+            .preInstructions([
+              anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 })
+            ])
+            .signers([testUser2, TEST_POOL_AUTHORITY])
+            .rpc();
+          assert.fail("Should have failed with InvalidSkimBeneficiary");
+        } catch (e: any) {
+          assert.ok(
+            e.toString().includes("InvalidSkimBeneficiary"),
+            `Expected InvalidSkimBeneficiary error, got: ${e.toString()}`
+          );
+        }
+      });
+
       it("enforces slippage protection on buys", async () => {
         const buyAmount = new BN(10_000_000); // 10 USDC
         const minTokensOut = new BN(1_000_000_000_000); // Impossibly high
@@ -1485,33 +1874,189 @@ describe("ContentPool ICBS Tests", () => {
           );
         }
       });
-    });
-
-    describe("4.2 Sell Operations", () => {
-      it("executes LONG sell with correct USDC output", async () => {
-        // First, buy some LONG tokens to sell
-        const buyAmount = new BN(50_000_000); // 50 USDC
-        const stakeSkimAmount = new BN(5_000_000); // 5 USDC skim
 
-        const traderUsdcAccount = await getOrCreateAssociatedTokenAccount(
+      it("rejects a wrong-mint treasury account before any transfer", async () => {
+        const otherMint = await createMint(
           provider.connection,
           payer.payer,
-          usdcMint,
-          testUser1.publicKey
+          payer.publicKey,
+          null,
+          6
         );
-
-        const traderLongAccount = await getOrCreateAssociatedTokenAccount(
+        const wrongTreasury = await getOrCreateAssociatedTokenAccount(
           provider.connection,
           payer.payer,
-          tradingLongMint,
-          testUser1.publicKey
+          otherMint,
+          protocolTreasury.publicKey
         );
+        const traderUsdc = getAssociatedTokenAddressSync(usdcMint, testUser2.publicKey);
+        const usdcBefore = (await getAccount(provider.connection, traderUsdc)).amount;
+        const stakeVaultBefore = (await getAccount(provider.connection, stakeVault)).amount;
 
-        // Buy LONG tokens
-        await program.methods
-          .trade(
-            { long: {} },
-            { buy: {} },
+        try {
+          await program.methods
+            .trade({ long: {} }, { buy: {} }, new BN(10_000_000), new BN(1_000_000), new BN(0), new BN(0))
+            .accounts({
+              pool: tradingPoolPda,
+              factory: factoryPda,
+              traderUsdc,
+              vault: tradingVault,
+              stakeVault: stakeVault,
+              traderTokens: getAssociatedTokenAddressSync(tradingLongMint, testUser2.publicKey),
+              tokenMint: tradingLongMint,
+              usdcMint: usdcMint,
+              trader: testUser2.publicKey,
+              protocolAuthority: TEST_POOL_AUTHORITY.publicKey,
+              payer: payer.publicKey,
+              postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+              protocolTreasuryUsdcAccount: wrongTreasury.address,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .preInstructions([
+              anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 })
+            ])
+            .signers([testUser2, TEST_POOL_AUTHORITY])
+            .rpc();
+          assert.fail("Should have failed with InvalidMint");
+        } catch (e: any) {
+          assert.include(e.toString(), "InvalidMint");
+        }
+
+        assert.equal((await getAccount(provider.connection, traderUsdc)).amount, usdcBefore);
+        assert.equal((await getAccount(provider.connection, stakeVault)).amount, stakeVaultBefore);
+      });
+
+      it("keeps trading while pool creation is paused", async () => {
+        const programData = PublicKey.findProgramAddressSync(
+          [program.programId.toBuffer()],
+          new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+        )[0];
+
+        await program.methods
+          .toggleCreationPause(true)
+          .accounts({
+            factory: factoryPda,
+            upgradeAuthority: upgradeAuthority.publicKey,
+            program: program.programId,
+            programData,
+          })
+          .signers([upgradeAuthority])
+          .rpc();
+
+        try {
+          // New pools are blocked
+          const pausedContentId = Keypair.generate().publicKey;
+          try {
+            await program.methods
+              .createPool(pausedContentId)
+              .accounts({
+                factory: factoryPda,
+                pool: PublicKey.findProgramAddressSync(
+                  [Buffer.from("content_pool"), pausedContentId.toBuffer()],
+                  program.programId
+                )[0],
+                registry: PublicKey.findProgramAddressSync(
+                  [Buffer.from("registry"), pausedContentId.toBuffer()],
+                  program.programId
+                )[0],
+                custodian: custodianPda,
+                creator: testUser1.publicKey,
+                postCreator: testUser1.publicKey,
+                payer: payer.publicKey,
+                systemProgram: SystemProgram.programId,
+              })
+              .signers([testUser1])
+              .rpc();
+            assert.fail("create_pool should fail while creation is paused");
+          } catch (e: any) {
+            assert.ok(e.toString().includes("CreationPaused"), `Expected CreationPaused, got: ${e.toString()}`);
+          }
+
+          // Existing pool still trades
+          const poolBefore = await program.account.contentPool.fetch(tradingPoolPda);
+          const traderUsdcAccount = await getOrCreateAssociatedTokenAccount(
+            provider.connection,
+            payer.payer,
+            usdcMint,
+            testUser2.publicKey
+          );
+          const traderLongAccount = await getOrCreateAssociatedTokenAccount(
+            provider.connection,
+            payer.payer,
+            tradingLongMint,
+            testUser2.publicKey
+          );
+
+          await program.methods
+            .trade({ long: {} }, { buy: {} }, new BN(10_000_000), new BN(0), new BN(0), new BN(0))
+            .accounts({
+              pool: tradingPoolPda,
+              factory: factoryPda,
+              traderUsdc: traderUsdcAccount.address,
+              vault: tradingVault,
+              stakeVault: stakeVault,
+              traderTokens: traderLongAccount.address,
+              tokenMint: tradingLongMint,
+              usdcMint: usdcMint,
+              trader: testUser2.publicKey,
+              protocolAuthority: TEST_POOL_AUTHORITY.publicKey,
+              payer: payer.publicKey,
+              postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+              protocolTreasuryUsdcAccount: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .preInstructions([
+              anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 })
+            ])
+            .signers([testUser2, TEST_POOL_AUTHORITY])
+            .rpc();
+
+          const poolAfter = await program.account.contentPool.fetch(tradingPoolPda);
+          assert.ok(poolAfter.sLong.gt(poolBefore.sLong), "Trade should succeed while creation is paused");
+        } finally {
+          await program.methods
+            .toggleCreationPause(false)
+            .accounts({
+              factory: factoryPda,
+              upgradeAuthority: upgradeAuthority.publicKey,
+              program: program.programId,
+              programData,
+            })
+            .signers([upgradeAuthority])
+            .rpc();
+        }
+      });
+    });
+
+    describe("4.2 Sell Operations", () => {
+      it("executes LONG sell with correct USDC output", async () => {
+        // First, buy some LONG tokens to sell
+        const buyAmount = new BN(50_000_000); // 50 USDC
+        const stakeSkimAmount = new BN(5_000_000); // 5 USDC skim
+
+        const traderUsdcAccount = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          payer.payer,
+          usdcMint,
+          testUser1.publicKey
+        );
+
+        const traderLongAccount = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          payer.payer,
+          tradingLongMint,
+          testUser1.publicKey
+        );
+
+        // Buy LONG tokens
+        await program.methods
+          .trade(
+            { long: {} },
+            { buy: {} },
             buyAmount,
             stakeSkimAmount,
             new BN(0),
@@ -1597,6 +2142,9 @@ describe("ContentPool ICBS Tests", () => {
         // Pool supply decreased
         assert.ok(poolAfter.sLong.lt(sLongBefore), "Pool LONG supply should decrease");
 
+        // The sell advanced trade_seq by exactly one
+        assert.equal(poolAfter.tradeSeq.toNumber(), poolBefore.tradeSeq.toNumber() + 1);
+
         // Vault balance decreased (USDC paid out)
         assert.ok(vaultBalanceAfter < vaultBalanceBefore, "Vault should pay out USDC");
       });
@@ -2184,6 +2732,42 @@ describe("ContentPool ICBS Tests", () => {
         const finalBalance = (await getAccount(provider.connection, user3LongAta)).amount;
         assert.equal(finalBalance, 0n, "All tokens should be sold");
       });
+
+      it("pre-creates LONG/SHORT ATAs idempotently with prepare_trader_accounts", async () => {
+        const testUser4 = Keypair.generate();
+        const user4LongAta = getAssociatedTokenAddressSync(tradingLongMint, testUser4.publicKey);
+        const user4ShortAta = getAssociatedTokenAddressSync(tradingShortMint, testUser4.publicKey);
+
+        const prepare = () =>
+          program.methods
+            .prepareTraderAccounts()
+            .accounts({
+              pool: tradingPoolPda,
+              longMint: tradingLongMint,
+              shortMint: tradingShortMint,
+              traderLong: user4LongAta,
+              traderShort: user4ShortAta,
+              trader: testUser4.publicKey,
+              payer: payer.publicKey,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Payer covers rent; the trader never signs
+        await prepare();
+
+        const longAta = await getAccount(provider.connection, user4LongAta);
+        const shortAta = await getAccount(provider.connection, user4ShortAta);
+        assert.equal(longAta.owner.toString(), testUser4.publicKey.toString());
+        assert.equal(shortAta.owner.toString(), testUser4.publicKey.toString());
+        assert.equal(longAta.amount, 0n);
+        assert.equal(shortAta.amount, 0n);
+
+        // Second call is a no-op
+        await prepare();
+      });
     });
 
     describe("4.3 Trade Size Limits", () => {
@@ -2331,6 +2915,62 @@ describe("ContentPool ICBS Tests", () => {
         assert.ok(pool.sLong.gt(new BN(0)));
       });
 
+      it("transfers a position off-curve with transfer_position", async () => {
+        const recipient = Keypair.generate().publicKey;
+        const ata = (mint: PublicKey, owner: PublicKey) => getAssociatedTokenAddressSync(mint, owner);
+
+        // Sender needs both ATAs; make sure the SHORT one exists
+        await program.methods
+          .prepareTraderAccounts()
+          .accounts({
+            pool: tradingPoolPda,
+            longMint: tradingLongMint,
+            shortMint: tradingShortMint,
+            traderLong: ata(tradingLongMint, testUser2.publicKey),
+            traderShort: ata(tradingShortMint, testUser2.publicKey),
+            trader: testUser2.publicKey,
+            payer: payer.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        const senderLongBefore = (await getAccount(provider.connection, ata(tradingLongMint, testUser2.publicKey))).amount;
+        assert.ok(senderLongBefore >= 1_000_000n, "sender should hold at least one LONG token");
+        const poolBefore = await program.account.contentPool.fetch(tradingPoolPda);
+
+        await program.methods
+          .transferPosition(new BN(1_000_000), new BN(0))
+          .accounts({
+            pool: tradingPoolPda,
+            longMint: tradingLongMint,
+            shortMint: tradingShortMint,
+            senderLong: ata(tradingLongMint, testUser2.publicKey),
+            senderShort: ata(tradingShortMint, testUser2.publicKey),
+            recipientLong: ata(tradingLongMint, recipient),
+            recipientShort: ata(tradingShortMint, recipient),
+            sender: testUser2.publicKey,
+            recipient,
+            payer: payer.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([testUser2])
+          .rpc();
+
+        const senderLongAfter = (await getAccount(provider.connection, ata(tradingLongMint, testUser2.publicKey))).amount;
+        const recipientLong = (await getAccount(provider.connection, ata(tradingLongMint, recipient))).amount;
+        assert.equal(senderLongAfter, senderLongBefore - 1_000_000n);
+        assert.equal(recipientLong, 1_000_000n);
+
+        // No curve interaction
+        const poolAfter = await program.account.contentPool.fetch(tradingPoolPda);
+        assert.equal(poolAfter.sLong.toString(), poolBefore.sLong.toString());
+        assert.equal(poolAfter.vaultBalance.toString(), poolBefore.vaultBalance.toString());
+      });
+
       it("allows users to burn tokens directly", async () => {
         const user2LongAccount = await getOrCreateAssociatedTokenAccount(
           provider.connection,
@@ -2365,9 +3005,306 @@ describe("ContentPool ICBS Tests", () => {
         )).amount;
         assert.equal(balanceAfter, balanceBefore - burnAmount);
 
-        // Verify pool reserves unchanged
-        const poolAfter = await program.account.contentPool.fetch(tradingPoolPda);
-        assert.equal(poolAfter.rLong.toString(), reserveBefore.toString());
+        // Verify pool reserves unchanged
+        const poolAfter = await program.account.contentPool.fetch(tradingPoolPda);
+        assert.equal(poolAfter.rLong.toString(), reserveBefore.toString());
+      });
+    });
+
+    describe("4.5 Dual Trade", () => {
+      it("buys LONG on one pool and SHORT on another atomically", async () => {
+        const traderUsdc = getAssociatedTokenAddressSync(usdcMint, testUser2.publicKey);
+
+        const leg = (pool: PublicKey, vault: PublicKey, mint: PublicKey) => ({
+          pool,
+          factory: factoryPda,
+          traderUsdc,
+          vault,
+          stakeVault: stakeVault,
+          traderTokens: getAssociatedTokenAddressSync(mint, testUser2.publicKey),
+          tokenMint: mint,
+          usdcMint: usdcMint,
+          trader: testUser2.publicKey,
+          protocolAuthority: protocolAuthority.publicKey,
+          payer: payer.publicKey,
+          postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+          protocolTreasuryUsdcAccount: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        });
+
+        const buy = (side: object) => ({
+          side,
+          tradeType: { buy: {} },
+          amount: new BN(10_000_000),
+          stakeSkim: new BN(1_000_000),
+          minTokensOut: new BN(0),
+          minUsdcOut: new BN(0),
+        });
+
+        const poolABefore = await program.account.contentPool.fetch(deploymentPoolPda);
+        const poolBBefore = await program.account.contentPool.fetch(tradingPoolPda);
+
+        await program.methods
+          .dualTrade(buy({ long: {} }), buy({ short: {} }))
+          .accounts({
+            legA: leg(deploymentPoolPda, deploymentVault, deploymentLongMint),
+            legB: leg(tradingPoolPda, tradingVault, tradingShortMint),
+          })
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 800_000 })
+          ])
+          .signers([testUser2, protocolAuthority])
+          .rpc();
+
+        const poolAAfter = await program.account.contentPool.fetch(deploymentPoolPda);
+        const poolBAfter = await program.account.contentPool.fetch(tradingPoolPda);
+
+        assert.ok(poolAAfter.sLong.gt(poolABefore.sLong), "leg A minted LONG");
+        assert.ok(poolBAfter.sShort.gt(poolBBefore.sShort), "leg B minted SHORT");
+        assert.ok(poolAAfter.vaultBalance.gt(poolABefore.vaultBalance), "leg A funded its vault");
+        assert.ok(poolBAfter.vaultBalance.gt(poolBBefore.vaultBalance), "leg B funded its vault");
+      });
+
+      it("rejects both legs on the same pool", async () => {
+        try {
+          const accounts = {
+            pool: tradingPoolPda,
+            factory: factoryPda,
+            traderUsdc: getAssociatedTokenAddressSync(usdcMint, testUser2.publicKey),
+            vault: tradingVault,
+            stakeVault: stakeVault,
+            traderTokens: getAssociatedTokenAddressSync(tradingLongMint, testUser2.publicKey),
+            tokenMint: tradingLongMint,
+            usdcMint: usdcMint,
+            trader: testUser2.publicKey,
+            protocolAuthority: protocolAuthority.publicKey,
+            payer: payer.publicKey,
+            postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+            protocolTreasuryUsdcAccount: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          };
+          const leg = {
+            side: { long: {} },
+            tradeType: { buy: {} },
+            amount: new BN(10_000_000),
+            stakeSkim: new BN(1_000_000),
+            minTokensOut: new BN(0),
+            minUsdcOut: new BN(0),
+          };
+          await program.methods
+            .dualTrade(leg, leg)
+            .accounts({ legA: accounts, legB: accounts })
+            .signers([testUser2, protocolAuthority])
+            .rpc();
+          assert.fail("Should have rejected duplicate pool");
+        } catch (err: any) {
+          assert.include(err.toString(), "InvalidParameter");
+        }
+      });
+    });
+
+    describe("4.6 Buy Both Sides", () => {
+      it("buys LONG and SHORT split by the reserve ratio", async () => {
+        const poolBefore = await program.account.contentPool.fetch(tradingPoolPda);
+
+        await program.methods
+          .buyBothSides(new BN(20_000_000), new BN(0), new BN(0), new BN(0))
+          .accounts({
+            trade: {
+              pool: tradingPoolPda,
+              factory: factoryPda,
+              traderUsdc: getAssociatedTokenAddressSync(usdcMint, testUser2.publicKey),
+              vault: tradingVault,
+              stakeVault: stakeVault,
+              traderTokens: getAssociatedTokenAddressSync(tradingLongMint, testUser2.publicKey),
+              tokenMint: tradingLongMint,
+              usdcMint: usdcMint,
+              trader: testUser2.publicKey,
+              protocolAuthority: protocolAuthority.publicKey,
+              payer: payer.publicKey,
+              postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+              protocolTreasuryUsdcAccount: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            },
+            shortMint: tradingShortMint,
+            traderShortTokens: getAssociatedTokenAddressSync(tradingShortMint, testUser2.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 800_000 })
+          ])
+          .signers([testUser2, protocolAuthority])
+          .rpc();
+
+        const poolAfter = await program.account.contentPool.fetch(tradingPoolPda);
+
+        assert.ok(poolAfter.sLong.gt(poolBefore.sLong), "LONG minted");
+        assert.ok(poolAfter.sShort.gt(poolBefore.sShort), "SHORT minted");
+
+        // Both buys reach the vault
+        assert.ok(poolAfter.vaultBalance.gt(poolBefore.vaultBalance));
+      });
+
+      it("rejects a LONG leg given the SHORT mint", async () => {
+        try {
+          await program.methods
+            .buyBothSides(new BN(20_000_000), new BN(0), new BN(0), new BN(0))
+            .accounts({
+              trade: {
+                pool: tradingPoolPda,
+                factory: factoryPda,
+                traderUsdc: getAssociatedTokenAddressSync(usdcMint, testUser2.publicKey),
+                vault: tradingVault,
+                stakeVault: stakeVault,
+                traderTokens: getAssociatedTokenAddressSync(tradingShortMint, testUser2.publicKey),
+                tokenMint: tradingShortMint,
+                usdcMint: usdcMint,
+                trader: testUser2.publicKey,
+                protocolAuthority: protocolAuthority.publicKey,
+                payer: payer.publicKey,
+                postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+                protocolTreasuryUsdcAccount: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+                tokenProgram: TOKEN_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+              },
+              shortMint: tradingShortMint,
+              traderShortTokens: getAssociatedTokenAddressSync(tradingShortMint, testUser2.publicKey),
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([testUser2, protocolAuthority])
+            .rpc();
+          assert.fail("Should have rejected SHORT mint on the LONG leg");
+        } catch (err: any) {
+          assert.include(err.toString(), "InvalidMint");
+        }
+      });
+    });
+
+    describe("4.7 Vault Resync", () => {
+      it("re-couples vault_balance and reserves to a donated vault", async () => {
+        // Donate straight to the vault, bypassing the program's accounting
+        await mintTo(
+          provider.connection,
+          payer.payer,
+          usdcMint,
+          tradingVault,
+          payer.publicKey,
+          5_000_000
+        );
+
+        const poolBefore = await program.account.contentPool.fetch(tradingPoolPda);
+        const vaultAmount = (await getAccount(provider.connection, tradingVault)).amount;
+        assert.notEqual(poolBefore.vaultBalance.toString(), vaultAmount.toString());
+
+        await program.methods
+          .resyncVaultBalance()
+          .accounts({
+            pool: tradingPoolPda,
+            factory: factoryPda,
+            vault: tradingVault,
+            protocolAuthority: protocolAuthority.publicKey,
+          })
+          .signers([protocolAuthority])
+          .rpc();
+
+        const poolAfter = await program.account.contentPool.fetch(tradingPoolPda);
+        assert.equal(poolAfter.vaultBalance.toString(), vaultAmount.toString());
+        assert.equal(poolAfter.rLong.add(poolAfter.rShort).toString(), vaultAmount.toString());
+        assert.equal(poolAfter.sLong.toString(), poolBefore.sLong.toString(), "supplies untouched");
+      });
+
+      it("rejects a non-authority signer", async () => {
+        try {
+          await program.methods
+            .resyncVaultBalance()
+            .accounts({
+              pool: tradingPoolPda,
+              factory: factoryPda,
+              vault: tradingVault,
+              protocolAuthority: testUser2.publicKey,
+            })
+            .signers([testUser2])
+            .rpc();
+          assert.fail("Should have rejected non-authority");
+        } catch (err: any) {
+          assert.include(err.toString(), "UnauthorizedProtocol");
+        }
+      });
+    });
+
+    describe("4.8 Early Exit Fee", () => {
+      it("sets the pool's early-exit fee and holding period", async () => {
+        await program.methods
+          .setEarlyExitFee(200, 3600)
+          .accounts({
+            pool: tradingPoolPda,
+            factory: factoryPda,
+            protocolAuthority: protocolAuthority.publicKey,
+          })
+          .signers([protocolAuthority])
+          .rpc();
+
+        const pool = await program.account.contentPool.fetch(tradingPoolPda);
+        assert.equal(pool.earlyExitFeeBps, 200);
+        assert.equal(pool.minHoldSecs, 3600);
+
+        // Reset so later sells are unaffected
+        await program.methods
+          .setEarlyExitFee(0, 0)
+          .accounts({
+            pool: tradingPoolPda,
+            factory: factoryPda,
+            protocolAuthority: protocolAuthority.publicKey,
+          })
+          .signers([protocolAuthority])
+          .rpc();
+      });
+
+      it("rejects a fee above the 10% cap", async () => {
+        try {
+          await program.methods
+            .setEarlyExitFee(1001, 3600)
+            .accounts({
+              pool: tradingPoolPda,
+              factory: factoryPda,
+              protocolAuthority: protocolAuthority.publicKey,
+            })
+            .signers([protocolAuthority])
+            .rpc();
+          assert.fail("Should have rejected fee above cap");
+        } catch (err: any) {
+          assert.include(err.toString(), "InvalidFeeOverride");
+        }
+      });
+    });
+
+    describe("4.9 Fee Override", () => {
+      it("rejects a fee override above MAX_TOTAL_FEE_BPS", async () => {
+        try {
+          await program.methods
+            .setFeeOverride(1001, null)
+            .accounts({
+              pool: tradingPoolPda,
+              factory: factoryPda,
+              protocolAuthority: protocolAuthority.publicKey,
+            })
+            .signers([protocolAuthority])
+            .rpc();
+          assert.fail("Should have rejected fee override above cap");
+        } catch (err: any) {
+          assert.include(err.toString(), "InvalidFeeOverride");
+        }
       });
     });
   });
@@ -2436,7 +3373,7 @@ describe("ContentPool ICBS Tests", () => {
       );
 
       await program.methods
-        .deployMarket(new BN(200_000_000), new BN(80_000_000)) // 40% LONG
+        .deployMarket({ initialDeposit: new BN(200_000_000), longAllocation: new BN(80_000_000) }) // 40% LONG
         .accounts({
           pool: settlementPoolPda,
             factory: factoryPda,          longMint: settlementLongMint,
@@ -2479,7 +3416,7 @@ describe("ContentPool ICBS Tests", () => {
         )[0];
 
         await program.methods
-          .settleEpoch(bdScore)
+          .settleEpoch(bdScore, false, false, null, null)
           .accounts({
             pool: settlementPoolPda,
             factory: factoryPda,
@@ -2517,7 +3454,7 @@ describe("ContentPool ICBS Tests", () => {
         const pool = await program.account.contentPool.fetch(settlementPoolPda);
         try {
           await program.methods
-            .settleEpoch(500_000) // 50% in micro-units
+            .settleEpoch(500_000, false, false, null, null) // 50% in micro-units
             .accounts({
               pool: settlementPoolPda,
             factory: factoryPda,
@@ -2537,6 +3474,132 @@ describe("ContentPool ICBS Tests", () => {
         }
       });
 
+      it("settles against a donated vault using the tracked vault_balance", async () => {
+        const settlementVault = PublicKey.findProgramAddressSync(
+          [Buffer.from("vault"), settlementContentId.toBuffer()],
+          program.programId
+        )[0];
+
+        // Donate straight to the vault so vault.amount > vault_balance
+        await mintTo(
+          provider.connection,
+          payer.payer,
+          usdcMint,
+          settlementVault,
+          payer.publicKey,
+          1_000_000
+        );
+
+        const settle = () =>
+          program.methods
+            .settleEpoch(500_000, false, true, null, null) // forced: the pool was just settled
+            .accounts({
+              pool: settlementPoolPda,
+              factory: factoryPda,
+              protocolAuthority: protocolAuthority.publicKey,
+              settler: testUser1.publicKey,
+              vault: settlementVault,
+            })
+            .signers([protocolAuthority, testUser1])
+            .rpc();
+
+        // The donation stays untracked: reserves are scaled to, and λ derived from, vault_balance
+        const poolBefore = await program.account.contentPool.fetch(settlementPoolPda);
+        await settle();
+        const poolAfter = await program.account.contentPool.fetch(settlementPoolPda);
+        assert.equal(poolAfter.currentEpoch.toString(), poolBefore.currentEpoch.addn(1).toString());
+        assert.equal(poolAfter.vaultBalance.toString(), poolBefore.vaultBalance.toString());
+        assert.equal(
+          poolAfter.rLong.add(poolAfter.rShort).toString(),
+          poolAfter.vaultBalance.toString()
+        );
+
+        await program.methods
+          .resyncVaultBalance()
+          .accounts({
+            pool: settlementPoolPda,
+            factory: factoryPda,
+            vault: settlementVault,
+            protocolAuthority: protocolAuthority.publicKey,
+          })
+          .signers([protocolAuthority])
+          .rpc();
+      });
+
+      it("reverts a q-guarded settlement after a trade moves q", async () => {
+        const pda = (seed: string) =>
+          PublicKey.findProgramAddressSync(
+            [Buffer.from(seed), settlementContentId.toBuffer()],
+            program.programId
+          )[0];
+        const settlementVault = pda("vault");
+        const settlementLongMint = pda("long_mint");
+
+        const settle = (expectedQBps: number) =>
+          program.methods
+            .settleEpoch(500_000, false, true, expectedQBps, 50) // forced, ±0.5%
+            .accounts({
+              pool: settlementPoolPda,
+              factory: factoryPda,
+              protocolAuthority: protocolAuthority.publicKey,
+              settler: testUser1.publicKey,
+              vault: settlementVault,
+            })
+            .signers([protocolAuthority, testUser1])
+            .rpc();
+
+        // The operator observes q and computes its BD score against it
+        const qBps = (pool: any) =>
+          Math.round(
+            (pool.rLong.toNumber() * 10_000) / pool.rLong.add(pool.rShort).toNumber()
+          );
+        const observed = qBps(await program.account.contentPool.fetch(settlementPoolPda));
+
+        // A LONG buy lands first
+        await program.methods
+          .trade({ long: {} }, { buy: {} }, new BN(50_000_000), new BN(5_000_000), new BN(0), new BN(0))
+          .accounts({
+            pool: settlementPoolPda,
+            factory: factoryPda,
+            traderUsdc: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+            vault: settlementVault,
+            stakeVault: stakeVault,
+            traderTokens: getAssociatedTokenAddressSync(settlementLongMint, testUser1.publicKey),
+            tokenMint: settlementLongMint,
+            usdcMint: usdcMint,
+            trader: testUser1.publicKey,
+            protocolAuthority: TEST_POOL_AUTHORITY.publicKey,
+            payer: payer.publicKey,
+            postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+            protocolTreasuryUsdcAccount: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 })
+          ])
+          .signers([testUser1, TEST_POOL_AUTHORITY])
+          .rpc();
+
+        const poolMoved = await program.account.contentPool.fetch(settlementPoolPda);
+        assert.isAbove(qBps(poolMoved) - observed, 50);
+
+        try {
+          await settle(observed);
+          assert.fail("Should have failed with QMovedError");
+        } catch (e: any) {
+          assert.include(e.toString(), "QMovedError");
+        }
+        const poolRejected = await program.account.contentPool.fetch(settlementPoolPda);
+        assert.equal(poolRejected.currentEpoch.toString(), poolMoved.currentEpoch.toString());
+
+        // Recomputed against the live q, the same settlement goes through
+        await settle(qBps(poolMoved));
+        const poolAfter = await program.account.contentPool.fetch(settlementPoolPda);
+        assert.equal(poolAfter.currentEpoch.toString(), poolMoved.currentEpoch.addn(1).toString());
+      });
+
       it("validates BD score bounds", async () => {
         // Try to settle with bd_score > 1_000_000 - should fail
         const extremePoolContentId = Keypair.generate().publicKey;
@@ -2567,7 +3630,7 @@ describe("ContentPool ICBS Tests", () => {
         const extremePool = await program.account.contentPool.fetch(extremePoolPda);
         try {
           await program.methods
-            .settleEpoch(1_500_000) // > 100%
+            .settleEpoch(1_500_000, false, false, null, null) // > 100%
             .accounts({
               pool: extremePoolPda,
             factory: factoryPda,
@@ -2584,6 +3647,49 @@ describe("ContentPool ICBS Tests", () => {
       });
     });
 
+    describe("5.2 Skip Epoch", () => {
+      it("advances the epoch with bit-identical reserves", async () => {
+        const poolBefore = await program.account.contentPool.fetch(settlementPoolPda);
+
+        // Forced: the pool was just settled above, so the cooldown is still running
+        await program.methods
+          .skipEpoch(true)
+          .accounts({
+            pool: settlementPoolPda,
+            factory: factoryPda,
+            protocolAuthority: protocolAuthority.publicKey,
+          })
+          .signers([protocolAuthority])
+          .rpc();
+
+        const poolAfter = await program.account.contentPool.fetch(settlementPoolPda);
+        assert.equal(poolAfter.currentEpoch.toString(), poolBefore.currentEpoch.addn(1).toString());
+        assert.equal(poolAfter.rLong.toString(), poolBefore.rLong.toString());
+        assert.equal(poolAfter.rShort.toString(), poolBefore.rShort.toString());
+        assert.equal(poolAfter.sScaleLongQ64.toString(), poolBefore.sScaleLongQ64.toString());
+        assert.equal(poolAfter.sScaleShortQ64.toString(), poolBefore.sScaleShortQ64.toString());
+        assert.equal(poolAfter.sqrtPriceLongX96.toString(), poolBefore.sqrtPriceLongX96.toString());
+        assert.equal(poolAfter.sqrtPriceShortX96.toString(), poolBefore.sqrtPriceShortX96.toString());
+      });
+
+      it("respects the cooldown unless forced", async () => {
+        try {
+          await program.methods
+            .skipEpoch(false)
+            .accounts({
+              pool: settlementPoolPda,
+              factory: factoryPda,
+              protocolAuthority: protocolAuthority.publicKey,
+            })
+            .signers([protocolAuthority])
+            .rpc();
+          assert.fail("Should have failed with SettlementCooldown");
+        } catch (e: any) {
+          assert.include(e.toString(), "SettlementCooldown");
+        }
+      });
+    });
+
     describe("5.3 Authority Validation", () => {
       it("requires protocol authority for settlement", async () => {
         // Create another pool for this test
@@ -2615,7 +3721,7 @@ describe("ContentPool ICBS Tests", () => {
         // Try to settle without protocol authority
         try {
           await program.methods
-            .settleEpoch(500_000) // 50% in micro-units
+            .settleEpoch(500_000, false, false, null, null) // 50% in micro-units
             .accounts({
               pool: authTestPoolPda,
             factory: factoryPda,
@@ -2637,6 +3743,111 @@ describe("ContentPool ICBS Tests", () => {
           );
         }
       });
+
+      it("lets the delegated settlement authority settle", async () => {
+        const programData = PublicKey.findProgramAddressSync(
+          [program.programId.toBuffer()],
+          new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+        )[0];
+        const settlementBot = Keypair.generate();
+
+        await program.methods
+          .updateSettlementAuthority(settlementBot.publicKey)
+          .accounts({
+            factory: factoryPda,
+            upgradeAuthority: upgradeAuthority.publicKey,
+            program: program.programId,
+            programData,
+          })
+          .signers([upgradeAuthority])
+          .rpc();
+
+        try {
+          // The trading pool has never settled, so its cooldown is satisfied
+          const poolBefore = await program.account.contentPool.fetch(tradingPoolPda);
+
+          await program.methods
+            .settleEpoch(500_000, false, false, null, null)
+            .accounts({
+              pool: tradingPoolPda,
+              factory: factoryPda,
+              protocolAuthority: settlementBot.publicKey,
+              settler: testUser1.publicKey,
+              vault: tradingVault,
+            })
+            .signers([testUser1, settlementBot])
+            .rpc();
+
+          const poolAfter = await program.account.contentPool.fetch(tradingPoolPda);
+          assert.equal(
+            poolAfter.currentEpoch.toNumber(),
+            poolBefore.currentEpoch.toNumber() + 1
+          );
+
+          // Forced (cooldown-skipping) settlement stays with the protocol authority
+          try {
+            await program.methods
+              .settleEpoch(500_000, false, true, null, null)
+              .accounts({
+                pool: tradingPoolPda,
+                factory: factoryPda,
+                protocolAuthority: settlementBot.publicKey,
+                settler: testUser1.publicKey,
+                vault: tradingVault,
+              })
+              .signers([testUser1, settlementBot])
+              .rpc();
+            assert.fail("Settlement authority should not force settlement");
+          } catch (e: any) {
+            assert.include(e.toString(), "UnauthorizedProtocol");
+          }
+        } finally {
+          await program.methods
+            .updateSettlementAuthority(PublicKey.default)
+            .accounts({
+              factory: factoryPda,
+              upgradeAuthority: upgradeAuthority.publicKey,
+              program: program.programId,
+              programData,
+            })
+            .signers([upgradeAuthority])
+            .rpc();
+        }
+      });
+
+      it("rejects emergency_close from anyone but the upgrade authority", async () => {
+        const programData = PublicKey.findProgramAddressSync(
+          [program.programId.toBuffer()],
+          new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+        )[0];
+        const pool = await program.account.contentPool.fetch(settlementPoolPda);
+
+        try {
+          await program.methods
+            .emergencyClose()
+            .accounts({
+              pool: settlementPoolPda,
+              factory: factoryPda,
+              vault: pool.vault,
+              protocolTreasuryUsdc: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+              upgradeAuthority: protocolAuthority.publicKey,
+              program: program.programId,
+              programData,
+              tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([protocolAuthority])
+            .rpc();
+          assert.fail("Protocol authority should not be able to emergency-close");
+        } catch (e: any) {
+          assert.include(e.toString(), "InvalidUpgradeAuthority");
+        }
+
+        const status = await program.methods
+          .getPoolStatus()
+          .accounts({ pool: settlementPoolPda })
+          .view();
+        assert.isFalse(status.isClosed);
+      });
     });
   });
 
@@ -3001,10 +4212,10 @@ describe("ContentPool ICBS Tests", () => {
         );
 
         await program.methods
-          .deployMarket(
-            new BN(100_000_000), // 100 USDC
-            40 // 40% LONG allocation (q = 0.4)
-          )
+          .deployMarket({
+            initialDeposit: new BN(100_000_000), // 100 USDC
+            longAllocation: 40, // 40% LONG allocation (q = 0.4)
+          })
           .accounts({
             pool: settlementPoolPda,
             factory: factoryPda,
@@ -3035,7 +4246,7 @@ describe("ContentPool ICBS Tests", () => {
         // Settle with BD score = 600_000 (60% - different from pool's q=0.4)
         const poolBeforeSettle = await program.account.contentPool.fetch(settlementPoolPda);
         await program.methods
-          .settleEpoch(new BN(600_000)) // 60% BD score
+          .settleEpoch(new BN(600_000), false, false, null, null) // 60% BD score
           .accounts({
             pool: settlementPoolPda,
             factory: factoryPda,
@@ -3142,10 +4353,10 @@ describe("ContentPool ICBS Tests", () => {
 
         // Deploy with extreme allocation (99% LONG)
         await program.methods
-          .deployMarket(
-            new BN(100_000_000),
-            99 // 99% LONG (extreme q)
-          )
+          .deployMarket({
+            initialDeposit: new BN(100_000_000),
+            longAllocation: 99, // 99% LONG (extreme q)
+          })
           .accounts({
             pool: extremePoolPda,
             factory: factoryPda,
@@ -3171,7 +4382,7 @@ describe("ContentPool ICBS Tests", () => {
         // Settle with extreme opposite BD score (1% - extreme opposite)
         const extremePoolForSettle = await program.account.contentPool.fetch(extremePoolPda);
         await program.methods
-          .settleEpoch(new BN(10_000)) // 1% BD score
+          .settleEpoch(new BN(10_000), false, false, null, null) // 1% BD score
           .accounts({
             pool: extremePoolPda,
             factory: factoryPda,
@@ -3194,6 +4405,210 @@ describe("ContentPool ICBS Tests", () => {
         assert.ok(q >= 0.001 && q <= 0.999, `q should be clamped to safe range: ${q}`);
       });
     });
+
+    describe("7.3 Operation Sequences", () => {
+      // Deterministic PRNG so a failing sequence can be replayed from its seed
+      function mulberry32(seed: number): () => number {
+        return () => {
+          seed = (seed + 0x6d2b79f5) | 0;
+          let t = Math.imul(seed ^ (seed >>> 15), 1 | seed);
+          t = (t + Math.imul(t ^ (t >>> 7), 61 | t)) ^ t;
+          return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+        };
+      }
+
+      // Guard rejections leave the pool untouched, so the sequence carries on past them
+      const EXPECTED_REJECTIONS = ["NoLiquidity", "TradeTooSmall", "TradeTooLarge", "InvalidParameter"];
+
+      it("holds reserve, supply and λ invariants under a random sequence of real instructions", async () => {
+        const SEED = 2454;
+        const STEPS = 24;
+        const rand = mulberry32(SEED);
+        const between = (lo: number, hi: number) => lo + Math.floor(rand() * (hi - lo + 1));
+
+        const contentId = Keypair.generate().publicKey;
+        const [poolPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("content_pool"), contentId.toBuffer()],
+          program.programId
+        );
+        const [longMint] = PublicKey.findProgramAddressSync(
+          [Buffer.from("long_mint"), contentId.toBuffer()],
+          program.programId
+        );
+        const [shortMint] = PublicKey.findProgramAddressSync(
+          [Buffer.from("short_mint"), contentId.toBuffer()],
+          program.programId
+        );
+        const [vault] = PublicKey.findProgramAddressSync(
+          [Buffer.from("vault"), contentId.toBuffer()],
+          program.programId
+        );
+        const trader = testUser2;
+        const traderUsdc = getAssociatedTokenAddressSync(usdcMint, trader.publicKey);
+        const traderLong = getAssociatedTokenAddressSync(longMint, trader.publicKey);
+        const traderShort = getAssociatedTokenAddressSync(shortMint, trader.publicKey);
+
+        await program.methods
+          .createPool(contentId)
+          .accounts({
+            factory: factoryPda,
+            pool: poolPda,
+            registry: PublicKey.findProgramAddressSync(
+              [Buffer.from("registry"), contentId.toBuffer()],
+              program.programId
+            )[0],
+            custodian: custodianPda,
+            creator: trader.publicKey,
+            postCreator: testUser1.publicKey,
+            payer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([trader])
+          .rpc();
+
+        await program.methods
+          .deployMarket({ initialDeposit: new BN(100_000_000), longAllocation: null })
+          .accounts({
+            pool: poolPda,
+            factory: factoryPda,
+            longMint,
+            shortMint,
+            vault,
+            deployerUsdc: traderUsdc,
+            deployerLong: traderLong,
+            deployerShort: traderShort,
+            usdcMint,
+            deployer: trader.publicKey,
+            payer: payer.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([trader])
+          .rpc();
+
+        async function checkInvariants(step: string) {
+          const pool = await program.account.contentPool.fetch(poolPda);
+          const vaultAccount = await getAccount(provider.connection, vault);
+
+          assert.equal(
+            pool.rLong.add(pool.rShort).toString(),
+            pool.vaultBalance.toString(),
+            `${step}: r_long + r_short should equal vault_balance`
+          );
+          assert.equal(
+            vaultAccount.amount.toString(),
+            pool.vaultBalance.toString(),
+            `${step}: vault token balance should equal vault_balance`
+          );
+
+          const supply = await program.methods
+            .getSupplyReconciliation()
+            .accounts({ pool: poolPda, longMint, shortMint })
+            .view();
+          assert.isTrue(supply.longMatches, `${step}: s_long should match LONG mint supply`);
+          assert.isTrue(supply.shortMatches, `${step}: s_short should match SHORT mint supply`);
+
+          const lambda = await program.methods.getCurrentLambda().accounts({ pool: poolPda }).view();
+          const lambdaUsdc = lambda.lambdaQ96.shrn(96).toNumber();
+          assert.ok(
+            lambdaUsdc >= 10 && lambdaUsdc <= 100_000_000_000,
+            `${step}: λ out of bounds: ${lambdaUsdc}`
+          );
+        }
+
+        async function trade(side: "long" | "short", type: "buy" | "sell", amount: BN) {
+          await program.methods
+            .trade({ [side]: {} } as any, { [type]: {} } as any, amount, new BN(0), new BN(0), new BN(0))
+            .accounts({
+              pool: poolPda,
+              factory: factoryPda,
+              traderUsdc,
+              vault,
+              stakeVault,
+              traderTokens: side === "long" ? traderLong : traderShort,
+              tokenMint: side === "long" ? longMint : shortMint,
+              usdcMint,
+              trader: trader.publicKey,
+              protocolAuthority: protocolAuthority.publicKey,
+              payer: payer.publicKey,
+              postCreatorUsdcAccount: getAssociatedTokenAddressSync(usdcMint, testUser1.publicKey),
+              protocolTreasuryUsdcAccount: getAssociatedTokenAddressSync(usdcMint, protocolTreasury.publicKey),
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .preInstructions([
+              anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 })
+            ])
+            .signers([trader, protocolAuthority])
+            .rpc();
+        }
+
+        await checkInvariants("after deploy");
+
+        for (let i = 0; i < STEPS; i++) {
+          const op = between(0, 5);
+          const side = rand() < 0.5 ? "long" : "short";
+          let step = `step ${i}`;
+
+          try {
+            if (op <= 1) {
+              const usdc = between(1, 20) * 1_000_000;
+              step = `step ${i}: buy ${side} ${usdc}`;
+              await trade(side, "buy", new BN(usdc));
+            } else if (op <= 3) {
+              const held = (await getAccount(provider.connection, side === "long" ? traderLong : traderShort)).amount;
+              const tokens = (held * BigInt(between(10, 60))) / 100n;
+              step = `step ${i}: sell ${side} ${tokens}`;
+              await trade(side, "sell", new BN(tokens.toString()));
+            } else if (op === 4) {
+              const usdc = between(1, 10) * 1_000_000;
+              step = `step ${i}: add_liquidity ${usdc}`;
+              await program.methods
+                .addLiquidity(new BN(usdc))
+                .accounts({
+                  pool: poolPda,
+                  factory: factoryPda,
+                  longTokenMint: longMint,
+                  shortTokenMint: shortMint,
+                  poolReserve: vault,
+                  userUsdcAccount: traderUsdc,
+                  userLongAccount: traderLong,
+                  userShortAccount: traderShort,
+                  user: trader.publicKey,
+                  tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .signers([trader])
+                .rpc();
+            } else {
+              const bdScore = between(0, 1_000_000);
+              const zeroSum = rand() < 0.5;
+              step = `step ${i}: settle bd=${bdScore} zero_sum=${zeroSum}`;
+              // Forced so the sequence is not throttled by the settlement cooldown
+              await program.methods
+                .settleEpoch(bdScore, zeroSum, true, null, null)
+                .accounts({
+                  pool: poolPda,
+                  factory: factoryPda,
+                  protocolAuthority: protocolAuthority.publicKey,
+                  settler: protocolAuthority.publicKey,
+                  vault,
+                })
+                .signers([protocolAuthority])
+                .rpc();
+            }
+          } catch (e: any) {
+            if (!EXPECTED_REJECTIONS.some((name) => e.toString().includes(name))) {
+              throw new Error(`seed ${SEED}, step ${i}: ${e}`);
+            }
+            step = `step ${i}: rejected (${e.toString().slice(0, 80)})`;
+          }
+
+          await checkInvariants(`seed ${SEED}, ${step}`);
+        }
+      });
+    });
   });
 
   // Additional test categories would continue here...
@@ -3265,10 +4680,10 @@ describe("ContentPool ICBS Tests", () => {
 
       // Deploy market with small liquidity
       await program.methods
-        .deployMarket(
-          new BN(1_000_000), // 1 USDC
-          50 // 50% LONG
-        )
+        .deployMarket({
+          initialDeposit: new BN(1_000_000), // 1 USDC
+          longAllocation: 50, // 50% LONG
+        })
         .accounts({
           pool: closePoolPda,
             factory: factoryPda,
@@ -3339,6 +4754,8 @@ describe("ContentPool ICBS Tests", () => {
           pool: closePoolPda,
             factory: factoryPda,
           vault: closeVault,
+          longMint: closeLongMint,
+          shortMint: closeShortMint,
           creatorUsdc: creatorUsdcAccount.address,
           creator: payer.publicKey,
             postCreator: payer.publicKey,
@@ -3356,6 +4773,13 @@ describe("ContentPool ICBS Tests", () => {
       const vaultAccountInfo = await provider.connection.getAccountInfo(closeVault);
       assert.isNull(vaultAccountInfo, "Vault account should be closed");
 
+      // SPL Token mints cannot be closed; close_pool drops their mint authority instead
+      for (const mint of [closeLongMint, closeShortMint]) {
+        const mintInfo = await getMint(provider.connection, mint);
+        assert.equal(mintInfo.supply.toString(), "0");
+        assert.isNull(mintInfo.mintAuthority, "Mint authority should be revoked");
+      }
+
       // Verify creator received remaining USDC
       if (vaultBalanceBefore.gt(new BN(0))) {
         const creatorUsdcBalanceAfter = await provider.connection.getTokenAccountBalance(
@@ -3435,10 +4859,10 @@ describe("ContentPool ICBS Tests", () => {
 
       // Deploy market to create positions
       await program.methods
-        .deployMarket(
-          new BN(10_000_000), // 10 USDC
-          50 // 50% LONG
-        )
+        .deployMarket({
+          initialDeposit: new BN(10_000_000), // 10 USDC
+          longAllocation: 50, // 50% LONG
+        })
         .accounts({
           pool: positionsPoolPda,
             factory: factoryPda,
@@ -3624,7 +5048,7 @@ describe("ContentPool ICBS Tests", () => {
       it("handles settlement with extreme BD scores", async () => {
         // Test with bd_score = 1 (0.0001%)
         await program.methods
-          .settleEpoch(new BN(1))
+          .settleEpoch(new BN(1), false, false, null, null)
           .accounts({
             pool: tradingPoolPda,
             factory: factoryPda,
@@ -3644,7 +5068,7 @@ describe("ContentPool ICBS Tests", () => {
 
         // Test with bd_score = 999_999 (99.9999%)
         await program.methods
-          .settleEpoch(new BN(999_999))
+          .settleEpoch(new BN(999_999), false, false, null, null)
           .accounts({
             pool: tradingPoolPda,
             factory: factoryPda,
@@ -3672,7 +5096,7 @@ describe("ContentPool ICBS Tests", () => {
         for (const score of scores) {
           await new Promise(resolve => setTimeout(resolve, 301_000)); // Wait for cooldown (5+ minutes)
           await program.methods
-            .settleEpoch(new BN(score))
+            .settleEpoch(new BN(score), false, false, null, null)
             .accounts({
               pool: tradingPoolPda,
               factory: factoryPda,
@@ -3997,10 +5421,10 @@ describe("ContentPool ICBS Tests", () => {
 
       // Deploy market with 60% LONG
       await program.methods
-        .deployMarket(
-          new BN(100_000_000), // 100 USDC
-          new BN(60_000_000) // 60% LONG
-        )
+        .deployMarket({
+          initialDeposit: new BN(100_000_000), // 100 USDC
+          longAllocation: new BN(60_000_000), // 60% LONG
+        })
         .accounts({
           pool: decayPoolPda,
             factory: factoryPda,
@@ -4348,7 +5772,7 @@ describe("ContentPool ICBS Tests", () => {
     it("emits PoolSettled event on settlement", async () => {
       // Execute a settlement
       const tx = await program.methods
-        .settleEpoch(new BN(700_000)) // 70% BD score
+        .settleEpoch(new BN(700_000), false, false, null, null) // 70% BD score
         .accounts({
           pool: tradingPoolPda,
             factory: factoryPda,
@@ -578,99 +578,212 @@ describe("PoolFactory ICBS Tests", () => {
         // DEPRECATED: updateFactoryAuthority removed
       });
     });
-  });
 
-  describe("4. Default Parameter Updates", () => {
-    describe("4.1 Update Default ICBS Parameters", () => {
-      it("allows factory_authority to update default_f", async () => {
-        const factoryBefore = await program.account.poolFactory.fetch(factoryPda);
-        const oldF = factoryBefore.defaultF;
+    describe("3.3 Protocol Authority Two-Step Transfer", () => {
+      const programData = () =>
+        PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0];
 
-        // Update default_f to 3
+      it("transfers protocol_authority on propose then accept", async () => {
         await program.methods
-          .updateDefaults(3, null, null, null, null, null)
+          .proposeProtocolAuthority(newFactoryAuthority.publicKey)
           .accounts({
             factory: factoryPda,
             upgradeAuthority: upgradeAuthority.publicKey,
             program: program.programId,
-            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+            programData: programData(),
           })
           .rpc();
 
-        const factoryAfter = await program.account.poolFactory.fetch(factoryPda);
-        assert.equal(factoryAfter.defaultF, 3, "default_f should be updated to 3");
+        // Old authority keeps control until the proposed key accepts
+        let factory = await program.account.poolFactory.fetch(factoryPda);
+        assert.equal(factory.protocolAuthority.toBase58(), protocolAuthority.publicKey.toBase58());
+        assert.equal(factory.pendingProtocolAuthority.toBase58(), newFactoryAuthority.publicKey.toBase58());
 
-        // Create a new pool and verify it inherits the new default
-        const newContentId = Keypair.generate().publicKey;
-        const [newPoolPda] = PublicKey.findProgramAddressSync(
-          [Buffer.from("content_pool"), newContentId.toBuffer()],
-          program.programId
-        );
-        const [newRegistryPda] = PublicKey.findProgramAddressSync(
-          [Buffer.from("registry"), newContentId.toBuffer()],
-          program.programId
-        );
+        // Only the proposed key can accept
+        try {
+          await program.methods
+            .acceptProtocolAuthority()
+            .accounts({ factory: factoryPda, newAuthority: testUser.publicKey })
+            .signers([testUser])
+            .rpc();
+          assert.fail("Should have failed with NotPendingAuthority");
+        } catch (e: any) {
+          assert.ok(e.toString().includes("NotPendingAuthority"), `Got error: ${e.toString()}`);
+        }
+
+        await program.methods
+          .acceptProtocolAuthority()
+          .accounts({ factory: factoryPda, newAuthority: newFactoryAuthority.publicKey })
+          .signers([newFactoryAuthority])
+          .rpc();
+
+        factory = await program.account.poolFactory.fetch(factoryPda);
+        assert.equal(factory.protocolAuthority.toBase58(), newFactoryAuthority.publicKey.toBase58());
+        assert.equal(factory.pendingProtocolAuthority.toBase58(), PublicKey.default.toBase58());
 
+        // Restore original for other tests
         await program.methods
-          .createPool(newContentId)
+          .updateProtocolAuthority(protocolAuthority.publicKey)
           .accounts({
             factory: factoryPda,
-            pool: newPoolPda,
-            registry: newRegistryPda,
-            custodian: custodianPda,
-            creator: testUser.publicKey,
-            postCreator: testUser.publicKey,
-            payer: payer.publicKey,
-            systemProgram: SystemProgram.programId,
+            upgradeAuthority: upgradeAuthority.publicKey,
+            program: program.programId,
+            programData: programData(),
           })
-          .signers([testUser])
           .rpc();
+      });
 
-        const newPool = await program.account.contentPool.fetch(newPoolPda);
-        assert.equal(newPool.f, 3, "New pool should inherit default_f = 3");
+      it("drops a pending proposal when protocol_authority is updated directly", async () => {
+        const stale = Keypair.generate();
+        const airdropSig = await provider.connection.requestAirdrop(stale.publicKey, LAMPORTS_PER_SOL);
+        await provider.connection.confirmTransaction(airdropSig);
 
-        // Restore original value
         await program.methods
-          .updateDefaults(oldF, null, null, null, null, null)
+          .proposeProtocolAuthority(stale.publicKey)
           .accounts({
             factory: factoryPda,
             upgradeAuthority: upgradeAuthority.publicKey,
             program: program.programId,
-            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+            programData: programData(),
           })
           .rpc();
+
+        // Governance switches authority directly while the proposal is outstanding
+        await program.methods
+          .updateProtocolAuthority(protocolAuthority.publicKey)
+          .accounts({
+            factory: factoryPda,
+            upgradeAuthority: upgradeAuthority.publicKey,
+            program: program.programId,
+            programData: programData(),
+          })
+          .rpc();
+
+        const factory = await program.account.poolFactory.fetch(factoryPda);
+        assert.equal(factory.pendingProtocolAuthority.toBase58(), PublicKey.default.toBase58());
+
+        try {
+          await program.methods
+            .acceptProtocolAuthority()
+            .accounts({ factory: factoryPda, newAuthority: stale.publicKey })
+            .signers([stale])
+            .rpc();
+          assert.fail("Should have failed with NoPendingAuthority");
+        } catch (e: any) {
+          assert.ok(e.toString().includes("NoPendingAuthority"), `Got error: ${e.toString()}`);
+        }
       });
 
-      it("allows factory_authority to update default_beta", async () => {
-        const factoryBefore = await program.account.poolFactory.fetch(factoryPda);
-        const oldBetaNum = factoryBefore.defaultBetaNum;
-        const oldBetaDen = factoryBefore.defaultBetaDen;
+      it("leaves protocol_authority unchanged on propose then cancel", async () => {
+        const typo = Keypair.generate();
 
-        // Update beta to 2/3 (0.667)
         await program.methods
-          .updateDefaults(null, 2, 3, null, null, null)
+          .proposeProtocolAuthority(typo.publicKey)
           .accounts({
             factory: factoryPda,
             upgradeAuthority: upgradeAuthority.publicKey,
             program: program.programId,
-            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+            programData: programData(),
           })
           .rpc();
 
-        const factoryAfter = await program.account.poolFactory.fetch(factoryPda);
-        assert.equal(factoryAfter.defaultBetaNum, 2, "default_beta_num should be 2");
-        assert.equal(factoryAfter.defaultBetaDen, 3, "default_beta_den should be 3");
-
-        // Restore original values
         await program.methods
-          .updateDefaults(null, oldBetaNum, oldBetaDen, null, null, null)
+          .cancelProtocolAuthorityTransfer()
           .accounts({
             factory: factoryPda,
             upgradeAuthority: upgradeAuthority.publicKey,
             program: program.programId,
-            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+            programData: programData(),
           })
           .rpc();
+
+        const factory = await program.account.poolFactory.fetch(factoryPda);
+        assert.equal(factory.protocolAuthority.toBase58(), protocolAuthority.publicKey.toBase58());
+        assert.equal(factory.pendingProtocolAuthority.toBase58(), PublicKey.default.toBase58());
+
+        // The cancelled key can no longer accept
+        try {
+          await program.methods
+            .acceptProtocolAuthority()
+            .accounts({ factory: factoryPda, newAuthority: typo.publicKey })
+            .signers([typo])
+            .rpc();
+          assert.fail("Should have failed with NoPendingAuthority");
+        } catch (e: any) {
+          assert.ok(e.toString().includes("NoPendingAuthority"), `Got error: ${e.toString()}`);
+        }
+      });
+    });
+  });
+
+  describe("4. Default Parameter Updates", () => {
+    describe("4.1 Update Default ICBS Parameters", () => {
+      it("rejects a default_f the curve does not implement", async () => {
+        // The ICBS math implements F = 1 only; any other exponent would fail every deploy
+        try {
+          await program.methods
+            .updateDefaults(3, null, null, null, null, null)
+            .accounts({
+              factory: factoryPda,
+              upgradeAuthority: upgradeAuthority.publicKey,
+              program: program.programId,
+              programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+            })
+            .rpc();
+          assert.fail("Should have rejected f = 3");
+        } catch (e: any) {
+          assert.include(e.toString(), "InvalidF");
+        }
+
+        const factoryAfter = await program.account.poolFactory.fetch(factoryPda);
+        assert.equal(factoryAfter.defaultF, DEFAULT_F, "default_f should be unchanged");
+      });
+
+      it("rejects a default_beta other than 1/2", async () => {
+        // 3/10 is a valid ratio, but the curve only implements β = 1/2
+        try {
+          await program.methods
+            .updateDefaults(null, 3, 10, null, null, null)
+            .accounts({
+              factory: factoryPda,
+              upgradeAuthority: upgradeAuthority.publicKey,
+              program: program.programId,
+              programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+            })
+            .rpc();
+          assert.fail("Should have rejected beta = 3/10");
+        } catch (e: any) {
+          assert.include(e.toString(), "InvalidBeta");
+        }
+
+        const factoryAfter = await program.account.poolFactory.fetch(factoryPda);
+        assert.equal(factoryAfter.defaultBetaNum, DEFAULT_BETA_NUM, "default_beta_num should be unchanged");
+        assert.equal(factoryAfter.defaultBetaDen, DEFAULT_BETA_DEN, "default_beta_den should be unchanged");
+      });
+
+      it("stores an unreduced beta (2/4) as 1/2 so deploys keep working", async () => {
+        const factoryBefore = await program.account.poolFactory.fetch(factoryPda);
+        const accounts = {
+          factory: factoryPda,
+          upgradeAuthority: upgradeAuthority.publicKey,
+          program: program.programId,
+          programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+        };
+
+        await program.methods
+          .updateDefaults(null, 2, 4, null, null, null)
+          .accounts(accounts)
+          .rpc();
+
+        // The curve only accepts exactly 1/2; an unreduced 2/4 would fail every deploy
+        const factoryAfter = await program.account.poolFactory.fetch(factoryPda);
+        assert.equal(factoryAfter.defaultBetaNum, 1);
+        assert.equal(factoryAfter.defaultBetaDen, 2);
+
+        await program.methods
+          .updateDefaults(null, factoryBefore.defaultBetaNum, factoryBefore.defaultBetaDen, null, null, null)
+          .accounts(accounts)
+          .rpc();
       });
 
       it("validates parameter bounds on update", async () => {
@@ -716,6 +829,25 @@ describe("PoolFactory ICBS Tests", () => {
             `Should fail with InvalidBeta error, got: ${e.toString()}`
           );
         }
+
+        // Try to set p0 above MAX_PRICE_MICRO (1M USDC/token)
+        try {
+          await program.methods
+            .updateDefaults(null, null, null, new BN("1000000000001"), null, null)
+            .accounts({
+              factory: factoryPda,
+            upgradeAuthority: upgradeAuthority.publicKey,
+            program: program.programId,
+            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+            })
+            .rpc();
+          assert.fail("Should have failed with p0 above MAX_PRICE_MICRO");
+        } catch (e: any) {
+          assert.include(e.toString(), "InvalidParameters");
+        }
+
+        const factoryAfter = await program.account.poolFactory.fetch(factoryPda);
+        assert.notEqual(factoryAfter.defaultP0.toString(), "1000000000001", "default_p0 should be unchanged");
       });
 
       it("allows update of min_initial_deposit", async () => {
@@ -784,6 +916,49 @@ describe("PoolFactory ICBS Tests", () => {
           .rpc();
       });
 
+      it("rejects min_settle_interval below the global floor", async () => {
+        const factory = await program.account.poolFactory.fetch(factoryPda);
+        const belowFloor = factory.globalMinSettleInterval.sub(new BN(1));
+
+        try {
+          await program.methods
+            .updateDefaults(null, null, null, null, null, belowFloor)
+            .accounts({
+              factory: factoryPda,
+              upgradeAuthority: upgradeAuthority.publicKey,
+              program: program.programId,
+              programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+            })
+            .rpc();
+          assert.fail("Should have rejected interval below floor");
+        } catch (err: any) {
+          assert.include(err.toString(), "InvalidSettleInterval");
+        }
+      });
+
+      it("rejects a total fee above MAX_TOTAL_FEE_BPS", async () => {
+        const before = await program.account.poolFactory.fetch(factoryPda);
+
+        try {
+          await program.methods
+            .updateFeeConfig(10_001, null, null, null, false) // 100.01%: every buy would revert
+            .accounts({
+              factory: factoryPda,
+              upgradeAuthority: upgradeAuthority.publicKey,
+              program: program.programId,
+              programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+              newProtocolTreasury: before.protocolTreasury,
+            })
+            .rpc();
+          assert.fail("Should have rejected an over-cap total fee");
+        } catch (err: any) {
+          assert.include(err.toString(), "InvalidFeeConfiguration");
+        }
+
+        const after = await program.account.poolFactory.fetch(factoryPda);
+        assert.equal(after.totalFeeBps, before.totalFeeBps);
+      });
+
       it("rejects updates from non-factory-authority", async () => {
         // Try to update as testUser (not upgrade authority)
         try {
@@ -806,99 +981,6 @@ describe("PoolFactory ICBS Tests", () => {
           );
         }
       });
-
-      it("existing pools retain original parameters after default update", async () => {
-        // Create pool1 with current defaults
-        const pool1ContentId = Keypair.generate().publicKey;
-        const [pool1Pda] = PublicKey.findProgramAddressSync(
-          [Buffer.from("content_pool"), pool1ContentId.toBuffer()],
-          program.programId
-        );
-        const [pool1RegistryPda] = PublicKey.findProgramAddressSync(
-          [Buffer.from("registry"), pool1ContentId.toBuffer()],
-          program.programId
-        );
-
-        await program.methods
-          .createPool(pool1ContentId)
-          .accounts({
-            factory: factoryPda,
-            pool: pool1Pda,
-            registry: pool1RegistryPda,
-            custodian: custodianPda,
-            creator: testUser.publicKey,
-            postCreator: testUser.publicKey,
-            payer: payer.publicKey,
-            systemProgram: SystemProgram.programId,
-          })
-          .signers([testUser])
-          .rpc();
-
-        const pool1Before = await program.account.contentPool.fetch(pool1Pda);
-        const pool1F = pool1Before.f;
-        const pool1BetaNum = pool1Before.betaNum;
-        const pool1BetaDen = pool1Before.betaDen;
-
-        // Update factory defaults
-        await program.methods
-          .updateDefaults(2, 3, 5, null, null, null) // f=2, beta=3/5=0.6
-          .accounts({
-            factory: factoryPda,
-            upgradeAuthority: upgradeAuthority.publicKey,
-            program: program.programId,
-            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
-          })
-          .rpc();
-
-        // Create pool2 with new defaults
-        const pool2ContentId = Keypair.generate().publicKey;
-        const [pool2Pda] = PublicKey.findProgramAddressSync(
-          [Buffer.from("content_pool"), pool2ContentId.toBuffer()],
-          program.programId
-        );
-        const [pool2RegistryPda] = PublicKey.findProgramAddressSync(
-          [Buffer.from("registry"), pool2ContentId.toBuffer()],
-          program.programId
-        );
-
-        await program.methods
-          .createPool(pool2ContentId)
-          .accounts({
-            factory: factoryPda,
-            pool: pool2Pda,
-            registry: pool2RegistryPda,
-            custodian: custodianPda,
-            creator: testUser.publicKey,
-            postCreator: testUser.publicKey,
-            payer: payer.publicKey,
-            systemProgram: SystemProgram.programId,
-          })
-          .signers([testUser])
-          .rpc();
-
-        // Verify pool1 retained original parameters
-        const pool1After = await program.account.contentPool.fetch(pool1Pda);
-        assert.equal(pool1After.f, pool1F, "pool1.f should be unchanged");
-        assert.equal(pool1After.betaNum, pool1BetaNum, "pool1.beta_num should be unchanged");
-        assert.equal(pool1After.betaDen, pool1BetaDen, "pool1.beta_den should be unchanged");
-
-        // Verify pool2 has new defaults
-        const pool2 = await program.account.contentPool.fetch(pool2Pda);
-        assert.equal(pool2.f, 2, "pool2.f should be 2");
-        assert.equal(pool2.betaNum, 3, "pool2.beta_num should be 3");
-        assert.equal(pool2.betaDen, 5, "pool2.beta_den should be 5");
-
-        // Restore factory defaults
-        await program.methods
-          .updateDefaults(DEFAULT_F, DEFAULT_BETA_NUM, DEFAULT_BETA_DEN, null, null, null)
-          .accounts({
-            factory: factoryPda,
-            upgradeAuthority: upgradeAuthority.publicKey,
-            program: program.programId,
-            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
-          })
-          .rpc();
-      });
     });
   });
 
@@ -1023,6 +1105,38 @@ describe("PoolFactory ICBS Tests", () => {
           assert.ok(e);
         }
       });
+
+      it("rejects a custodian other than the factory's", async () => {
+        const contentId = Keypair.generate().publicKey;
+
+        try {
+          await program.methods
+            .createPool(contentId)
+            .accounts({
+              factory: factoryPda,
+              pool: PublicKey.findProgramAddressSync(
+                [Buffer.from("content_pool"), contentId.toBuffer()],
+                program.programId
+              )[0],
+              registry: PublicKey.findProgramAddressSync(
+                [Buffer.from("registry"), contentId.toBuffer()],
+                program.programId
+              )[0],
+              custodian: factoryPda, // a real program account, but not the custodian PDA
+              creator: testUser.publicKey,
+              postCreator: testUser.publicKey,
+              payer: payer.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([testUser])
+            .rpc();
+          assert.fail("Should have rejected the mismatched custodian");
+        } catch (e: any) {
+          // The custodian is pinned to seeds = [CUSTODIAN_SEED], so no other account can
+          // stand in for it; a program account of another type fails when loaded
+          assert.include(e.toString(), "AccountDiscriminatorMismatch");
+        }
+      });
     });
   });
 
@@ -1066,16 +1180,16 @@ describe("PoolFactory ICBS Tests", () => {
 
     it("emits DefaultsUpdated event on parameter change", async () => {
       const factoryBefore = await program.account.poolFactory.fetch(factoryPda);
-      const oldF = factoryBefore.defaultF;
+      const oldMinDeposit = factoryBefore.minInitialDeposit;
 
-      // Update default_f (should emit event)
+      // Update min_initial_deposit (should emit event)
       const tx = await program.methods
-        .updateDefaults(5, null, null, null, null, null)
+        .updateDefaults(null, null, null, null, new BN(300_000_000), null)
         .accounts({
           factory: factoryPda,
-            upgradeAuthority: upgradeAuthority.publicKey,
-            program: program.programId,
-            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+          upgradeAuthority: upgradeAuthority.publicKey,
+          program: program.programId,
+          programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
         })
         .rpc();
 
@@ -1084,18 +1198,18 @@ describe("PoolFactory ICBS Tests", () => {
 
       // Verify factory was updated (transaction succeeded, implying event was emitted)
       const factoryAfter = await program.account.poolFactory.fetch(factoryPda);
-      assert.equal(factoryAfter.defaultF, 5, "default_f should be updated to 5");
+      assert.ok(factoryAfter.minInitialDeposit.eq(new BN(300_000_000)), "min_initial_deposit should be updated");
 
       // Note: Local validator may not store tx history, so we verify state change instead of fetching tx
 
       // Restore original value
       await program.methods
-        .updateDefaults(oldF, null, null, null, null, null)
+        .updateDefaults(null, null, null, null, oldMinDeposit, null)
         .accounts({
           factory: factoryPda,
-            upgradeAuthority: upgradeAuthority.publicKey,
-            program: program.programId,
-            programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
+          upgradeAuthority: upgradeAuthority.publicKey,
+          program: program.programId,
+          programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0],
         })
         .rpc();
     });
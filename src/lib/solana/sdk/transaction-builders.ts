@@ -416,7 +416,7 @@ export async function buildDeployMarketTx(
   // Note: deploy_market instruction uses init_if_needed for deployer ATAs
   // so we don't need to manually create them
   const tx = await program.methods
-    .deployMarket(params.initialDeposit, params.longAllocation)
+    .deployMarket({ initialDeposit: params.initialDeposit, longAllocation: params.longAllocation })
     .accounts({
       pool: poolPda,
       factory: factoryPda,
@@ -493,7 +493,7 @@ export async function buildSettleEpochTx(
   // Build settle_epoch instruction
   // Note: Requires pool, factory, protocol_authority, settler, and vault accounts
   const settleEpochIx = await program.methods
-    .settleEpoch(bdScoreMicro)
+    .settleEpoch(bdScoreMicro, false, false, null, null) // multiplicative, cooldown enforced, no q guard
     .accounts({
       pool: poolPda,
       factory: factoryAddress,
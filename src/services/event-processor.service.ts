@@ -33,6 +33,7 @@ export interface TradeEventData {
   trader: PublicKey;
   side: { long?: object } | { short?: object };
   tradeType: { buy?: object } | { sell?: object };
+  skimBeneficiary: PublicKey; // Credited with usdcToStake (trader unless overridden)
 
   // Trade amounts
   usdcAmount: bigint;       // Total USDC (including skim)
@@ -156,6 +157,7 @@ export class EventProcessor {
     const tokensTraded = Number(event.tokensTraded) / 1_000_000;  // Convert to display units
     const skimAmountMicro = Number(event.usdcToStake);  // Keep in micro-USDC
     const skimAmountDisplay = skimAmountMicro / 1_000_000;  // For display/logging only
+    const skimBeneficiary = event.skimBeneficiary.toString();  // Whose stake the skim credits
 
     // ICBS state snapshots - on-chain stores in DISPLAY units
     const sLongBefore = asDisplay(Number(event.sLongBefore));
@@ -206,7 +208,7 @@ export class EventProcessor {
 
         // Record the skim as a custodian deposit if there is one
         if (skimAmountMicro > 0) {
-          await this.recordSkimDeposit(walletAddress, skimAmountMicro, signature, blockTime, slot, Number(event.timestamp));
+          await this.recordSkimDeposit(skimBeneficiary, skimAmountMicro, signature, blockTime, slot, Number(event.timestamp));
         }
       } else {
         // Server data INCORRECT - overwrite with on-chain truth
@@ -231,7 +233,7 @@ export class EventProcessor {
 
         // Record the skim as a custodian deposit if there is one
         if (skimAmountMicro > 0) {
-          await this.recordSkimDeposit(walletAddress, skimAmountMicro, signature, blockTime, slot, Number(event.timestamp));
+          await this.recordSkimDeposit(skimBeneficiary, skimAmountMicro, signature, blockTime, slot, Number(event.timestamp));
         }
       }
     } else {
@@ -404,7 +406,7 @@ export class EventProcessor {
 
       // Record the skim as a custodian deposit if there is one
       if (skimAmountMicro > 0) {
-        await this.recordSkimDeposit(walletAddress, skimAmountMicro, signature, blockTime, slot, Number(event.timestamp));
+        await this.recordSkimDeposit(skimBeneficiary, skimAmountMicro, signature, blockTime, slot, Number(event.timestamp));
       }
     }
 
@@ -91,7 +91,7 @@ async function settlePool(
 
   // Build settle_epoch transaction
   const tx = await program.methods
-    .settleEpoch(scoreMillionths)
+    .settleEpoch(scoreMillionths, false, false, null, null) // multiplicative, cooldown enforced, no q guard
     .accounts({
       contentPool: poolPubkey,
       protocolAuthority: authority.publicKey,